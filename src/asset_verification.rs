@@ -67,7 +67,7 @@ pub fn get_asset_verification(
     env.storage()
         .persistent()
         .get(&key)
-        .ok_or(ContractError::AssetNotFound)
+        .ok_or(ContractError::KeyNotFound)
 }
 
 /// Checks if an asset has been verified.
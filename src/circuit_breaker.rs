@@ -0,0 +1,170 @@
+use soroban_sdk::{contracttype, Env};
+
+use crate::math::{checked_add_i128, checked_mul_div_i128};
+use crate::migration::{is_legacy_layout, legacy_instance, LegacyDataKey};
+use crate::storage::DataKey;
+use crate::ContractError;
+
+/// Volume circuit breaker configuration stored in instance storage
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Settled principal within a window that trips the breaker (0 = disabled)
+    pub threshold: i128,
+    /// Length of the rolling window in seconds
+    pub window_seconds: u64,
+}
+
+/// Settled volume in the current and previous fixed windows
+#[contracttype]
+#[derive(Clone, Debug)]
+struct VolumeWindow {
+    /// Index of the current window (timestamp / window_seconds)
+    index: u64,
+    /// Volume settled in the current window
+    current: i128,
+    /// Volume settled in the window before it
+    previous: i128,
+}
+
+/// Set the circuit breaker configuration, discarding any tracked volume
+pub fn set_circuit_breaker_config(env: &Env, config: &CircuitBreakerConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CircuitBreakerConfig, config);
+    env.storage().instance().remove(&DataKey::CircuitBreakerVolume);
+    if is_legacy_layout(env) {
+        env.storage().instance().remove(&LegacyDataKey::BreakerVolume);
+    }
+}
+
+/// Get the circuit breaker configuration (defaults to disabled)
+pub fn get_circuit_breaker_config(env: &Env) -> CircuitBreakerConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::CircuitBreakerConfig)
+        .or_else(|| legacy_instance(env, &LegacyDataKey::BreakerConfig))
+        .unwrap_or(CircuitBreakerConfig {
+            threshold: 0,
+            window_seconds: 0,
+        })
+}
+
+/// Volume settled over the last `window_seconds`, estimated from two fixed windows.
+///
+/// The previous window is weighted by how much of it still overlaps the rolling
+/// window, so volume drains away smoothly instead of dropping to zero at each
+/// boundary. A burst straddling a boundary is still counted, and volume from
+/// more than one window ago never contributes.
+pub fn rolling_volume(
+    previous: i128,
+    current: i128,
+    elapsed_in_window: u64,
+    window_seconds: u64,
+) -> Result<i128, ContractError> {
+    let remaining = window_seconds.saturating_sub(elapsed_in_window);
+    let carried = checked_mul_div_i128(previous, remaining as i128, window_seconds as i128)?;
+    checked_add_i128(carried, current)
+}
+
+/// Add settled principal to the rolling volume
+///
+/// Returns the rolling volume if it now exceeds the configured threshold,
+/// or None if the breaker is disabled or the volume is within the threshold.
+pub fn record_settlement_volume(env: &Env, amount: i128) -> Result<Option<i128>, ContractError> {
+    let config = get_circuit_breaker_config(env);
+    if config.threshold == 0 {
+        return Ok(None);
+    }
+
+    let now = env.ledger().timestamp();
+    let index = now / config.window_seconds;
+
+    let mut window = env
+        .storage()
+        .instance()
+        .get(&DataKey::CircuitBreakerVolume)
+        .or_else(|| legacy_instance(env, &LegacyDataKey::BreakerVolume))
+        .unwrap_or(VolumeWindow {
+            index,
+            current: 0,
+            previous: 0,
+        });
+
+    // Roll the buckets forward; a gap of two or more windows clears both
+    if window.index.checked_add(1) == Some(index) {
+        window.previous = window.current;
+        window.current = 0;
+    } else if index != window.index {
+        window.previous = 0;
+        window.current = 0;
+    }
+    window.index = index;
+    window.current = checked_add_i128(window.current, amount)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CircuitBreakerVolume, &window);
+
+    let volume = rolling_volume(
+        window.previous,
+        window.current,
+        now % config.window_seconds,
+        config.window_seconds,
+    )?;
+    if volume > config.threshold {
+        return Ok(Some(volume));
+    }
+    Ok(None)
+}
+
+/// Remove reversed principal from the window it was settled in
+///
+/// Volume settled more than one window ago no longer counts, so it is left alone.
+pub fn release_settlement_volume(env: &Env, amount: i128, settled_at: u64) {
+    let config = get_circuit_breaker_config(env);
+    if config.threshold == 0 {
+        return;
+    }
+
+    let stored: Option<VolumeWindow> = env
+        .storage()
+        .instance()
+        .get(&DataKey::CircuitBreakerVolume)
+        .or_else(|| legacy_instance(env, &LegacyDataKey::BreakerVolume));
+    let Some(mut window) = stored else {
+        return;
+    };
+
+    let index = settled_at / config.window_seconds;
+    if index == window.index {
+        window.current = window.current.saturating_sub(amount).max(0);
+    } else if index.checked_add(1) == Some(window.index) {
+        window.previous = window.previous.saturating_sub(amount).max(0);
+    } else {
+        return;
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CircuitBreakerVolume, &window);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_volume_drains_previous_window() {
+        assert_eq!(rolling_volume(1_000, 0, 0, 100), Ok(1_000));
+        assert_eq!(rolling_volume(1_000, 0, 25, 100), Ok(750));
+        assert_eq!(rolling_volume(1_000, 200, 50, 100), Ok(700));
+        assert_eq!(rolling_volume(1_000, 200, 99, 100), Ok(210));
+    }
+
+    #[test]
+    fn test_rolling_volume_overflow() {
+        assert_eq!(rolling_volume(i128::MAX, 0, 0, 100), Err(ContractError::Overflow));
+        assert_eq!(rolling_volume(0, i128::MAX, 0, 100), Ok(i128::MAX));
+    }
+}
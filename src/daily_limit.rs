@@ -0,0 +1,221 @@
+use soroban_sdk::{Address, Env, Map};
+
+use crate::math::checked_add_i128;
+use crate::migration::{is_legacy_layout, legacy_instance, legacy_persistent, LegacyDataKey};
+use crate::storage::{DataKey, LEDGER_CLOSE_SECONDS};
+use crate::ContractError;
+
+/// Length of a daily send window in seconds
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Days a usage bucket outlives its last write before it expires
+pub const USAGE_RETENTION_DAYS: u64 = 2;
+
+/// Lifetime in ledgers of a usage bucket (temporary storage)
+pub const USAGE_TTL_LEDGERS: u32 = (USAGE_RETENTION_DAYS * SECONDS_PER_DAY / LEDGER_CLOSE_SECONDS) as u32;
+
+/// UTC day bucket for a ledger timestamp.
+///
+/// Usage resets at each UTC midnight, when the bucket increments.
+pub fn day_bucket(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+/// Set the fallback daily limit applied to tokens without an explicit limit
+pub fn set_default_daily_limit(env: &Env, limit: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DefaultDailyLimit, &limit);
+}
+
+/// Get the fallback daily limit (defaults to unlimited)
+pub fn get_default_daily_limit(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DefaultDailyLimit)
+        .or_else(|| legacy_instance(env, &LegacyDataKey::Default))
+        .unwrap_or(i128::MAX)
+}
+
+/// Set an explicit daily limit for a token
+pub fn set_token_daily_limit(env: &Env, token: &Address, limit: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenDailyLimit(token.clone()), &limit);
+}
+
+/// Get the daily limit enforced for a token, falling back to the default
+pub fn get_token_daily_limit(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenDailyLimit(token.clone()))
+        .or_else(|| legacy_persistent(env, &LegacyDataKey::Token(token.clone())))
+        .unwrap_or_else(|| get_default_daily_limit(env))
+}
+
+/// Amounts a user has sent during a UTC day, keyed by token.
+///
+/// Buckets live in temporary storage and expire `USAGE_RETENTION_DAYS` after
+/// their last write, so a missing bucket simply means nothing was sent.
+fn get_usage_bucket(env: &Env, user: &Address, day: u64) -> Map<Address, i128> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::DailyUsage(user.clone(), day))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Write a temporary usage entry and reset its lifetime
+fn store_usage(env: &Env, key: &DataKey, value: &impl soroban_sdk::IntoVal<Env, soroban_sdk::Val>) {
+    env.storage().temporary().set(key, value);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, USAGE_TTL_LEDGERS, USAGE_TTL_LEDGERS);
+}
+
+/// Get the amount a user has sent in a token during the current UTC day
+pub fn get_daily_usage(env: &Env, user: &Address, token: &Address) -> i128 {
+    let day = day_bucket(env.ledger().timestamp());
+    get_usage_bucket(env, user, day)
+        .get(token.clone())
+        .or_else(|| legacy_persistent(env, &LegacyDataKey::Usage(user.clone(), token.clone(), day)))
+        .unwrap_or(0)
+}
+
+/// Check and record a send against the user's daily limit for a token
+/// Returns Ok(()) if within limits, Err(ContractError::DailySendLimitExceeded) if exceeded
+pub fn check_daily_limit(
+    env: &Env,
+    user: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let limit = get_token_daily_limit(env, token);
+    let used = checked_add_i128(get_daily_usage(env, user, token), amount)?;
+
+    if used > limit {
+        return Err(ContractError::DailySendLimitExceeded);
+    }
+
+    let day = day_bucket(env.ledger().timestamp());
+    let mut bucket = get_usage_bucket(env, user, day);
+    bucket.set(token.clone(), used);
+    store_usage(env, &DataKey::DailyUsage(user.clone(), day), &bucket);
+
+    Ok(())
+}
+
+/// Remove an address's sender and agent usage buckets from days before today
+///
+/// Stale buckets already count as zero and expire on their own; pruning just
+/// frees them early. Returns the number of buckets removed.
+pub fn prune_usage(env: &Env, address: &Address) -> u32 {
+    let today = day_bucket(env.ledger().timestamp());
+    let storage = env.storage().temporary();
+    let mut removed = 0;
+
+    for age in 1..=USAGE_RETENTION_DAYS {
+        let Some(day) = today.checked_sub(age) else {
+            break;
+        };
+        for key in [
+            DataKey::DailyUsage(address.clone(), day),
+            DataKey::AgentDailyUsage(address.clone(), day),
+        ] {
+            if storage.has(&key) {
+                storage.remove(&key);
+                removed += 1;
+            }
+        }
+    }
+
+    removed
+}
+
+/// Set an agent's daily settlement cap (0 removes the cap)
+pub fn set_agent_daily_cap(env: &Env, agent: &Address, cap: i128) {
+    let key = DataKey::AgentDailyCap(agent.clone());
+    if cap > 0 {
+        env.storage().persistent().set(&key, &cap);
+    } else {
+        env.storage().persistent().remove(&key);
+        if is_legacy_layout(env) {
+            env.storage()
+                .persistent()
+                .remove(&LegacyDataKey::AgentCap(agent.clone()));
+        }
+    }
+}
+
+/// Get an agent's daily settlement cap (defaults to unlimited)
+pub fn get_agent_daily_cap(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentDailyCap(agent.clone()))
+        .or_else(|| legacy_persistent(env, &LegacyDataKey::AgentCap(agent.clone())))
+        .unwrap_or(i128::MAX)
+}
+
+/// Get the principal an agent has settled during the current UTC day
+pub fn get_agent_daily_usage(env: &Env, agent: &Address) -> i128 {
+    get_agent_usage_on(env, agent, day_bucket(env.ledger().timestamp()))
+}
+
+/// Get the principal an agent settled during a UTC day
+fn get_agent_usage_on(env: &Env, agent: &Address, day: u64) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::AgentDailyUsage(agent.clone(), day))
+        .or_else(|| legacy_persistent(env, &LegacyDataKey::AgentUsage(agent.clone(), day)))
+        .unwrap_or(0)
+}
+
+/// Check a settlement against the agent's daily cap without recording it
+/// Returns the agent's usage including `amount`, or Err(ContractError::AgentDailyCapExceeded) if exceeded
+pub fn ensure_within_agent_daily_cap(env: &Env, agent: &Address, amount: i128) -> Result<i128, ContractError> {
+    let cap = get_agent_daily_cap(env, agent);
+    let used = checked_add_i128(get_agent_daily_usage(env, agent), amount)?;
+
+    if used > cap {
+        return Err(ContractError::AgentDailyCapExceeded);
+    }
+
+    Ok(used)
+}
+
+/// Check and record a settlement against the agent's daily cap
+/// Returns Ok(()) if within the cap, Err(ContractError::AgentDailyCapExceeded) if exceeded
+pub fn check_agent_daily_cap(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let used = ensure_within_agent_daily_cap(env, agent, amount)?;
+
+    let day = day_bucket(env.ledger().timestamp());
+    store_agent_usage(env, agent, day, used);
+
+    Ok(())
+}
+
+/// Remove reversed principal from the agent's usage for the day it was settled
+pub fn release_agent_daily_usage(env: &Env, agent: &Address, settled_at: u64, amount: i128) {
+    let day = day_bucket(settled_at);
+    let used = get_agent_usage_on(env, agent, day);
+    if used > 0 {
+        store_agent_usage(env, agent, day, used.saturating_sub(amount).max(0));
+    }
+}
+
+/// Record the principal an agent has settled during a UTC day
+pub(crate) fn store_agent_usage(env: &Env, agent: &Address, day: u64, used: i128) {
+    store_usage(env, &DataKey::AgentDailyUsage(agent.clone(), day), &used);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_bucket_rolls_over_at_midnight() {
+        assert_eq!(day_bucket(0), 0);
+        assert_eq!(day_bucket(SECONDS_PER_DAY - 1), 0);
+        assert_eq!(day_bucket(SECONDS_PER_DAY), 1);
+        assert_eq!(day_bucket(5 * SECONDS_PER_DAY + 42), 5);
+    }
+}
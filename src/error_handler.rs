@@ -197,7 +197,7 @@ impl ErrorHandler {
             // Token Whitelist Errors (19-20)
             ContractError::TokenNotWhitelisted => (
                 19,
-                SorobanString::from_str(env, "Token is not whitelisted"),
+                SorobanString::from_str(env, "Token is not whitelisted or not accepted by the recipient"),
                 ErrorCategory::Resource,
                 ErrorSeverity::Low,
             ),
@@ -250,7 +250,7 @@ impl ErrorHandler {
                 ErrorSeverity::High,
             ),
             
-            // Data Integrity Errors (27-30)
+            // Data Integrity Errors (27-29)
             ContractError::NetSettlementValidationFailed => (
                 27,
                 SorobanString::from_str(env, "Net settlement validation failed"),
@@ -269,20 +269,8 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Low,
             ),
-            ContractError::DataCorruption => (
-                30,
-                SorobanString::from_str(env, "Data corruption detected"),
-                ErrorCategory::System,
-                ErrorSeverity::High,
-            ),
             
-            // Collection Errors (31-33)
-            ContractError::IndexOutOfBounds => (
-                31,
-                SorobanString::from_str(env, "Index out of bounds"),
-                ErrorCategory::Validation,
-                ErrorSeverity::Low,
-            ),
+            // Collection Errors (32-33)
             ContractError::EmptyCollection => (
                 32,
                 SorobanString::from_str(env, "Collection is empty"),
@@ -296,13 +284,7 @@ impl ErrorHandler {
                 ErrorSeverity::Low,
             ),
             
-            // String/Symbol Errors (34-35)
-            ContractError::StringConversionFailed => (
-                34,
-                SorobanString::from_str(env, "String conversion failed"),
-                ErrorCategory::Validation,
-                ErrorSeverity::Low,
-            ),
+            // Symbol Errors (35)
             ContractError::InvalidSymbol => (
                 35,
                 SorobanString::from_str(env, "Symbol is invalid or malformed"),
@@ -321,6 +303,102 @@ impl ErrorHandler {
                 ErrorCategory::Validation,
                 ErrorSeverity::Medium,
             ),
+            ContractError::RemittanceNotExpired => (
+                38,
+                SorobanString::from_str(env, "Remittance has not expired yet"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AgentSuspended => (
+                39,
+                SorobanString::from_str(env, "Agent is suspended"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::NoPendingAdmin => (
+                40,
+                SorobanString::from_str(env, "No admin proposal is pending"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::AdminProposalExpired => (
+                41,
+                SorobanString::from_str(env, "Admin proposal has expired"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::MemoTooLong => (
+                42,
+                SorobanString::from_str(env, "Memo exceeds maximum length"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::OperatorNotFound => (
+                43,
+                SorobanString::from_str(env, "Address is not an operator"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::ActionNotFound => (
+                44,
+                SorobanString::from_str(env, "Queued action not found"),
+                ErrorCategory::Resource,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TimelockNotElapsed => (
+                45,
+                SorobanString::from_str(env, "Timelock delay has not elapsed"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TimelockRequired => (
+                46,
+                SorobanString::from_str(env, "Operation must be queued through the timelock"),
+                ErrorCategory::Authorization,
+                ErrorSeverity::Low,
+            ),
+            ContractError::InsufficientLiquidity => (
+                47,
+                SorobanString::from_str(env, "Insufficient token balance to cover settlement"),
+                ErrorCategory::State,
+                ErrorSeverity::High,
+            ),
+            ContractError::AgentDailyCapExceeded => (
+                48,
+                SorobanString::from_str(env, "Agent daily settlement cap exceeded"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::NotYetSettleable => (
+                49,
+                SorobanString::from_str(env, "Remittance is not yet settleable"),
+                ErrorCategory::State,
+                ErrorSeverity::Low,
+            ),
+            ContractError::RemittanceOnHold => (
+                50,
+                SorobanString::from_str(env, "Remittance is on hold"),
+                ErrorCategory::State,
+                ErrorSeverity::Medium,
+            ),
+            ContractError::SlippageExceeded => (
+                51,
+                SorobanString::from_str(env, "Converted payout below minimum"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::TokenMismatch => (
+                52,
+                SorobanString::from_str(env, "Token is not the recipient's preferred token"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
+            ContractError::BatchTooLarge => (
+                53,
+                SorobanString::from_str(env, "Batch exceeds maximum size"),
+                ErrorCategory::Validation,
+                ErrorSeverity::Low,
+            ),
         }
     }
     
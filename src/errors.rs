@@ -69,14 +69,6 @@ pub enum ContractError {
     /// Settlement has already been executed.
     /// Cause: Attempting to settle the same remittance twice (duplicate prevention).
     DuplicateSettlement = 12,
- feature/asset-verification-system
-    /// Asset verification record not found
-    AssetNotFound = 13,
-    /// Reputation score must be between 0 and 100
-    InvalidReputationScore = 14,
-    /// Asset has been flagged as suspicious
-    SuspiciousAsset = 15,
-
     
     /// Contract is paused. Settlements are temporarily disabled.
     ContractPaused = 13,
@@ -152,7 +144,7 @@ pub enum ContractError {
     Underflow = 26,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // Data Integrity Errors (27-30)
+    // Data Integrity Errors (27-29)
     // ═══════════════════════════════════════════════════════════════════════════
     
     /// Net settlement validation failed.
@@ -164,21 +156,13 @@ pub enum ContractError {
     SettlementCounterOverflow = 28,
     
     /// Invalid batch size.
-    /// Cause: Batch size is zero or exceeds maximum allowed.
+    /// Cause: A list argument exceeds a per-entrypoint limit tighter than `MAX_BATCH_SIZE`.
     InvalidBatchSize = 29,
     
-    /// Data corruption detected.
-    /// Cause: Storage data is corrupted or inconsistent.
-    DataCorruption = 30,
-    
     // ═══════════════════════════════════════════════════════════════════════════
-    // Collection Errors (31-33)
+    // Collection Errors (32-33)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// Index out of bounds.
-    /// Cause: Attempting to access collection element at invalid index.
-    IndexOutOfBounds = 31,
-    
     /// Collection is empty.
     /// Cause: Attempting operation on empty collection that requires elements.
     EmptyCollection = 32,
@@ -188,13 +172,9 @@ pub enum ContractError {
     KeyNotFound = 33,
     
     // ═══════════════════════════════════════════════════════════════════════════
-    // String/Symbol Errors (34-35)
+    // Symbol Errors (35)
     // ═══════════════════════════════════════════════════════════════════════════
     
-    /// String conversion failed.
-    /// Cause: Unable to convert between string types or invalid string format.
-    StringConversionFailed = 34,
-    
     /// Symbol is invalid or malformed.
     /// Cause: Symbol contains invalid characters or exceeds length limits.
     InvalidSymbol = 35,
@@ -210,5 +190,121 @@ pub enum ContractError {
     /// Invalid escrow status for this operation.
     /// Cause: Attempting operation on escrow in wrong status.
     InvalidEscrowStatus = 37,
- main
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Refund Errors (38)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance has not expired yet.
+    /// Cause: Attempting to refund a remittance before its expiry timestamp has passed.
+    RemittanceNotExpired = 38,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Errors (39)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent is suspended.
+    /// Cause: A suspended agent attempting to settle a remittance.
+    AgentSuspended = 39,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Admin Transfer Errors (40-41)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No admin proposal is pending.
+    /// Cause: Calling accept_admin() without a prior propose_admin().
+    NoPendingAdmin = 40,
+
+    /// Pending admin proposal has expired.
+    /// Cause: Calling accept_admin() after the proposal's acceptance window closed.
+    AdminProposalExpired = 41,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Memo Errors (42)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance memo is too long.
+    /// Cause: Memo passed to create_remittance exceeds 64 bytes.
+    MemoTooLong = 42,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Operator Errors (43)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Address is not an operator.
+    /// Cause: Calling remove_operator() for an address without the operator role.
+    OperatorNotFound = 43,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Timelock Errors (44-46)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Queued action does not exist.
+    /// Cause: Executing or cancelling an action ID that was never queued, or was already executed or cancelled.
+    ActionNotFound = 44,
+
+    /// Queued action is still locked.
+    /// Cause: Calling execute_action() before the action's unlock timestamp.
+    TimelockNotElapsed = 45,
+
+    /// Operation must go through the timelock queue.
+    /// Cause: Calling update_fee(), withdraw_treasury() or remove_admin() directly while a timelock delay is set.
+    TimelockRequired = 46,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Liquidity Errors (47)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Token balance does not cover a settlement.
+    /// Cause: An agent converting a payout with confirm_payout_in_token() holds less of the
+    /// out token than the recipient is owed, or a payee cannot return a reversed settlement.
+    InsufficientLiquidity = 47,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Limit Errors (48)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent's daily settlement cap exceeded.
+    /// Cause: Settling more principal in a UTC day than the cap set with set_agent_daily_cap().
+    AgentDailyCapExceeded = 48,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Scheduling Errors (49)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance is not yet released for settlement.
+    /// Cause: Settling or claiming a remittance before its not_before timestamp.
+    NotYetSettleable = 49,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Compliance Errors (50)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance is on hold pending review.
+    /// Cause: Settling, claiming, refunding, cancelling or modifying a remittance after place_hold().
+    RemittanceOnHold = 50,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Conversion Errors (51)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Converted payout is below the caller's minimum.
+    /// Cause: confirm_payout_in_token() would pay the recipient less than `min_out`.
+    SlippageExceeded = 51,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Recipient Preference Errors (52)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Token is not the recipient's preferred token.
+    /// Cause: Paying or redirecting a remittance to a recipient who set a different token with set_preferred_token().
+    TokenMismatch = 52,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Batch Errors (53)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Batch exceeds the global size limit.
+    /// Cause: Passing more than `MAX_BATCH_SIZE` entries to an entrypoint that takes a list.
+    BatchTooLarge = 53,
 }
@@ -4,7 +4,9 @@
 //! contract operations. Events include schema versioning and ledger metadata
 //! for comprehensive audit trails.
 
-use soroban_sdk::{symbol_short, Address, Env};
+use soroban_sdk::{symbol_short, Address, Bytes, Env, Symbol};
+
+use crate::{AdminAction, FeeConfig, RemittanceStatus, SettlementReceipt};
 
 // ============================================================================
 // Event Schema Version
@@ -13,133 +15,932 @@ use soroban_sdk::{symbol_short, Address, Env};
 // SCHEMA_VERSION: Event schema version for tracking event format changes
 // - This constant is included in all emitted events to help indexers and
 //   off-chain systems understand the event structure
-// - Current value: 1 (initial schema)
+// - Current value: 3 (settlement fee added to settlement receipts)
 // - When to increment: Increment this value whenever the structure of any
 //   event changes (e.g., adding/removing fields, changing field types)
 // - This allows event consumers to handle different schema versions gracefully
 //   and perform migrations when the event format evolves
 // ============================================================================
 
-const SCHEMA_VERSION: u32 = 1;
+const SCHEMA_VERSION: u32 = 3;
+
+// ============================================================================
+// Indexed Topics
+// ============================================================================
+//
+// Creation and settlement events carry the remittance's recipient and token as
+// topic segments so Soroban RPC `getEvents` topic filters can select them
+// without decoding event data. Every such event uses the same ordering:
+//
+//   (namespace, action, recipient, token)
+//
+// e.g. `["remit", "*", <recipient>, "*"]` matches all creation and settlement
+// events for one recipient, and `["*", "*", "*", <token>]` all events for one
+// token. Amounts and other fields stay in the event data.
+// ============================================================================
+
+/// Builds the `(namespace, action, recipient, token)` topics shared by
+/// creation and settlement events.
+fn indexed_topics(
+    namespace: Symbol,
+    action: Symbol,
+    recipient: &Address,
+    token: &Address,
+) -> (Symbol, Symbol, Address, Address) {
+    (namespace, action, recipient.clone(), token.clone())
+}
 
 // ── Admin Events ───────────────────────────────────────────────────
 
-/// Emits an event when the contract is paused by an admin.
+/// Emits an event when the contract is initialized.
+///
+/// The event's ledger timestamp is the initialization time stored by `initialize`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the initializing admin
+pub fn emit_contract_initialized(env: &Env, admin: Address) {
+    env.events().publish(
+        (symbol_short!("contract"), Symbol::new(env, "initialized")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when a migration advances the contract's schema version.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who ran the migration
+/// * `from_version` - Schema version before the migration
+/// * `to_version` - Schema version after the migration
+pub fn emit_contract_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    env.events().publish(
+        (symbol_short!("contract"), symbol_short!("migrated")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            from_version,
+            to_version,
+        ),
+    );
+}
+
+/// Emits an event when an admin abandons a batched migration import.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who aborted the import
+/// * `next_batch` - Batch number a restarted import resumes from
+pub fn emit_migration_aborted(env: &Env, admin: Address, next_batch: u32) {
+    env.events().publish(
+        (symbol_short!("migration"), symbol_short!("aborted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            next_batch,
+        ),
+    );
+}
+
+/// Emits an event when the storage layout is migrated to a new data version.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Admin who ran the migration
+/// * `from_version` - Layout version before the migration
+/// * `to_version` - Layout version after the migration
+pub fn emit_data_layout_migrated(env: &Env, admin: Address, from_version: u32, to_version: u32) {
+    env.events().publish(
+        (symbol_short!("contract"), symbol_short!("layout")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            from_version,
+            to_version,
+        ),
+    );
+}
+
+/// Emits an event when the settlement hook could not be notified.
+///
+/// The settlement itself has already completed; this only records that the
+/// downstream contract missed the notification.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance that was settled
+/// * `hook` - Notifier contract whose call failed
+pub fn emit_settlement_hook_failed(env: &Env, remittance_id: u64, hook: Address) {
+    env.events().publish(
+        (symbol_short!("hook"), symbol_short!("failed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            hook,
+        ),
+    );
+}
+
+/// Emits an event when a settlement surge trips the circuit breaker and pauses the contract.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `volume` - Rolling settled volume that crossed the threshold
+/// * `threshold` - Configured threshold
+pub fn emit_circuit_tripped(env: &Env, volume: i128, threshold: i128) {
+    env.events().publish(
+        (symbol_short!("contract"), Symbol::new(env, "circuit_tripped")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            volume,
+            threshold,
+        ),
+    );
+}
+
+/// Emits an event when an admin switches the token whitelist requirement on or off.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who changed the flag
+/// * `enabled` - Whether new remittances must now use a whitelisted token
+pub fn emit_token_whitelist_required(env: &Env, admin: Address, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("whitelist"), symbol_short!("required")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+            enabled,
+        ),
+    );
+}
+
+/// Emits an event when the contract is paused by an admin.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who paused the contract
+pub fn emit_paused(env: &Env, admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("paused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when the contract is unpaused by an admin.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `admin` - Address of the admin who unpaused the contract
+pub fn emit_unpaused(env: &Env, admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("unpaused")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            admin,
+        ),
+    );
+}
+
+/// Emits an event when an admin proposes a new admin.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `proposed_by` - Admin who made the proposal
+/// * `new_admin` - Address proposed to join the admin set
+/// * `expires_at` - Timestamp after which the proposal can no longer be accepted
+pub fn emit_admin_proposed(env: &Env, proposed_by: Address, new_admin: Address, expires_at: u64) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("proposed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            proposed_by,
+            new_admin,
+            expires_at,
+        ),
+    );
+}
+
+/// Emits an event when a proposed admin accepts and joins the admin set.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `new_admin` - Address that joined the admin set
+pub fn emit_admin_accepted(env: &Env, new_admin: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("accepted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            new_admin,
+        ),
+    );
+}
+
+/// Emits an event when an admin is removed from the admin set.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin who performed the removal
+/// * `removed` - Address removed from the admin set
+pub fn emit_admin_removed(env: &Env, caller: Address, removed: Address) {
+    env.events().publish(
+        (symbol_short!("admin"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            removed,
+        ),
+    );
+}
+
+/// Emits an event when an admin grants the operator role.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin who granted the role
+/// * `operator` - Address that became an operator
+pub fn emit_operator_added(env: &Env, caller: Address, operator: Address) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            operator,
+        ),
+    );
+}
+
+/// Emits an event when an admin revokes the operator role.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `caller` - Admin who revoked the role
+/// * `operator` - Address that is no longer an operator
+pub fn emit_operator_removed(env: &Env, caller: Address, operator: Address) {
+    env.events().publish(
+        (symbol_short!("operator"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            caller,
+            operator,
+        ),
+    );
+}
+
+/// Emits an event when an admin queues a sensitive action behind the timelock.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `action_id` - ID of the queued action
+/// * `queued_by` - Admin who queued the action
+/// * `action` - Operation that was queued
+/// * `unlock_at` - Timestamp from which the action can be executed
+pub fn emit_action_queued(env: &Env, action_id: u64, queued_by: Address, action: AdminAction, unlock_at: u64) {
+    env.events().publish(
+        (symbol_short!("timelock"), symbol_short!("queued")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            action_id,
+            queued_by,
+            action,
+            unlock_at,
+        ),
+    );
+}
+
+/// Emits an event when a queued action is executed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `action_id` - ID of the executed action
+/// * `caller` - Admin who executed the action
+pub fn emit_action_executed(env: &Env, action_id: u64, caller: Address) {
+    env.events().publish(
+        (symbol_short!("timelock"), symbol_short!("executed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            action_id,
+            caller,
+        ),
+    );
+}
+
+/// Emits an event when a queued action is cancelled.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `action_id` - ID of the cancelled action
+/// * `caller` - Admin who cancelled the action
+pub fn emit_action_cancelled(env: &Env, action_id: u64, caller: Address) {
+    env.events().publish(
+        (symbol_short!("timelock"), symbol_short!("cancelled")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            action_id,
+            caller,
+        ),
+    );
+}
+
+// ── Relayer Events ─────────────────────────────────────────────────
+
+/// Emits an event when a sender approves a relayer.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender granting the approval
+/// * `relayer` - Relayer allowed to submit on the sender's behalf
+pub fn emit_relayer_approved(env: &Env, sender: Address, relayer: Address) {
+    env.events().publish(
+        (symbol_short!("relayer"), symbol_short!("approved")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            relayer,
+        ),
+    );
+}
+
+/// Emits an event when a sender revokes a relayer.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender revoking the approval
+/// * `relayer` - Relayer no longer allowed to submit on the sender's behalf
+pub fn emit_relayer_revoked(env: &Env, sender: Address, relayer: Address) {
+    env.events().publish(
+        (symbol_short!("relayer"), symbol_short!("revoked")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            relayer,
+        ),
+    );
+}
+
+/// Emits an event when a sender adds a recipient to their allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender owning the allowlist
+/// * `recipient` - Recipient now allowed
+pub fn emit_recipient_allowed(env: &Env, sender: Address, recipient: Address) {
+    env.events().publish(
+        (symbol_short!("allowlist"), symbol_short!("added")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            recipient,
+        ),
+    );
+}
+
+/// Emits an event when a sender removes a recipient from their allowlist.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender owning the allowlist
+/// * `recipient` - Recipient no longer allowed
+pub fn emit_recipient_disallowed(env: &Env, sender: Address, recipient: Address) {
+    env.events().publish(
+        (symbol_short!("allowlist"), symbol_short!("removed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            recipient,
+        ),
+    );
+}
+
+/// Emits an event when a recipient sets or clears their preferred token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `recipient` - Recipient whose preference changed
+/// * `token` - New preferred token, or None if cleared
+pub fn emit_preferred_token_set(env: &Env, recipient: Address, token: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("recipient"), symbol_short!("token")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            recipient,
+            token,
+        ),
+    );
+}
+
+/// Emits an event when a sender turns their recipient allowlist on or off.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Sender owning the allowlist
+/// * `enabled` - Whether the allowlist is now enforced
+pub fn emit_recipient_allowlist_toggled(env: &Env, sender: Address, enabled: bool) {
+    env.events().publish(
+        (symbol_short!("allowlist"), symbol_short!("toggled")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            sender,
+            enabled,
+        ),
+    );
+}
+
+// ── Remittance Events ──────────────────────────────────────────────
+
+/// Emits an event when a new remittance is created.
+///
+/// Topic: `("remit", "created", recipient, token)`, where the recipient is the
+/// agent or claimant the remittance pays out to.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Unique ID of the created remittance
+/// * `sender` - Address of the sender
+/// * `agent` - Address of the assigned agent
+/// * `token` - Token the remittance is escrowed in
+/// * `amount` - Total remittance amount
+/// * `fee` - Platform fee deducted
+/// * `memo` - Optional sender-supplied payment reference
+#[allow(clippy::too_many_arguments)]
+pub fn emit_remittance_created(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    token: Address,
+    amount: i128,
+    fee: i128,
+    memo: Option<Bytes>,
+) {
+    env.events().publish(
+        indexed_topics(symbol_short!("remit"), symbol_short!("created"), &agent, &token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+            agent,
+            amount,
+            fee,
+            memo,
+        ),
+    );
+}
+
+/// Emits a standardized event for every remittance status transition.
+///
+/// Every state-mutating path publishes this event with the same topic shape so that
+/// indexers can reconstruct a remittance's full lifecycle with a single handler.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance that changed status
+/// * `old_status` - Status before the transition (`None` on creation)
+/// * `new_status` - Status after the transition
+///
+/// # Event Structure
+///
+/// Topic: `("remittance", "status_changed")`
+/// Data: `(remittance_id, old_status, new_status, ledger_timestamp)`
+pub fn emit_status_changed(
+    env: &Env,
+    remittance_id: u64,
+    old_status: Option<RemittanceStatus>,
+    new_status: RemittanceStatus,
+) {
+    env.events().publish(
+        (Symbol::new(env, "remittance"), Symbol::new(env, "status_changed")),
+        (
+            remittance_id,
+            old_status,
+            new_status,
+            env.ledger().timestamp(),
+        ),
+    );
+}
+
+/// Emits an event when an admin force-settles a remittance past its agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the force-settled remittance
+/// * `admin` - Admin who forced the settlement
+/// * `original_agent` - Agent the remittance was assigned to
+/// * `recipient` - Recipient recorded on the remittance
+/// * `token` - Token the payout was made in
+/// * `to` - Address that received the payout
+/// * `amount` - Payout amount (after fee deduction)
+///
+/// Topic: `("remittance", "force_settled", recipient, token)`
+#[allow(clippy::too_many_arguments)]
+pub fn emit_remittance_force_settled(
+    env: &Env,
+    remittance_id: u64,
+    admin: Address,
+    original_agent: Address,
+    recipient: &Address,
+    token: &Address,
+    to: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        indexed_topics(Symbol::new(env, "remittance"), Symbol::new(env, "force_settled"), recipient, token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            admin,
+            original_agent,
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a remittance payout is completed.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the completed remittance
+/// * `recipient` - Recipient recorded on the remittance
+/// * `token` - Token the payout was made in
+/// * `agent` - Address of the agent who received the payout
+/// * `amount` - Payout amount (after fee deduction)
+///
+/// Topic: `("remit", "complete", recipient, token)`
+pub fn emit_remittance_completed(
+    env: &Env,
+    remittance_id: u64,
+    recipient: &Address,
+    token: &Address,
+    agent: Address,
+    amount: i128,
+) {
+    env.events().publish(
+        indexed_topics(symbol_short!("remit"), symbol_short!("complete"), recipient, token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event for each tranche paid out on a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance being settled
+/// * `recipient` - Recipient recorded on the remittance
+/// * `token` - Token the tranche was paid in
+/// * `agent` - Address of the agent disbursing the tranche
+/// * `tranche` - Principal settled by this call
+/// * `settled_total` - Cumulative principal settled so far, including this tranche
+/// * `remaining` - Principal still outstanding after this tranche
+///
+/// Topic: `("remit", "partial", recipient, token)`
+#[allow(clippy::too_many_arguments)]
+pub fn emit_remittance_partially_settled(
+    env: &Env,
+    remittance_id: u64,
+    recipient: &Address,
+    token: &Address,
+    agent: Address,
+    tranche: i128,
+    settled_total: i128,
+    remaining: i128,
+) {
+    env.events().publish(
+        indexed_topics(symbol_short!("remit"), symbol_short!("partial"), recipient, token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            tranche,
+            settled_total,
+            remaining,
+        ),
+    );
+}
+
+/// Emits an event for each recipient paid by a split remittance settlement.
+///
+/// Topic: `("remit", "split_paid", recipient, token)`
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the split remittance
+/// * `recipient` - Recipient paid by this transfer
+/// * `token` - Token the share was paid in
+/// * `amount` - Recipient's share after fees
+pub fn emit_split_paid(env: &Env, remittance_id: u64, recipient: &Address, token: &Address, amount: i128) {
+    env.events().publish(
+        indexed_topics(symbol_short!("remit"), Symbol::new(env, "split_paid"), recipient, token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a remittance is assigned to a specific settling agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `caller` - Sender or admin who made the assignment
+/// * `agent` - Agent now exclusively allowed to settle
+pub fn emit_agent_assigned(env: &Env, remittance_id: u64, caller: Address, agent: Address) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("assigned")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            caller,
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when the sender adds funds to a pending remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `admin` - Address of the admin who paused the contract
-pub fn emit_paused(env: &Env, admin: Address) {
+/// * `remittance_id` - ID of the remittance
+/// * `sender` - Sender who added the funds
+/// * `additional_amount` - Principal added by this top-up
+/// * `new_amount` - Remittance amount after the top-up
+pub fn emit_remittance_topped_up(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    additional_amount: i128,
+    new_amount: i128,
+) {
     env.events().publish(
-        (symbol_short!("admin"), symbol_short!("paused")),
+        (symbol_short!("remit"), symbol_short!("topped_up")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            admin,
+            remittance_id,
+            sender,
+            additional_amount,
+            new_amount,
         ),
     );
 }
 
-/// Emits an event when the contract is unpaused by an admin.
+/// Emits an event when the sender redirects a remittance to a new recipient.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `admin` - Address of the admin who unpaused the contract
-pub fn emit_unpaused(env: &Env, admin: Address) {
+/// * `remittance_id` - ID of the remittance
+/// * `old_recipient` - Payout address before the update
+/// * `new_recipient` - Payout address after the update
+pub fn emit_recipient_updated(
+    env: &Env,
+    remittance_id: u64,
+    old_recipient: Address,
+    new_recipient: Address,
+) {
     env.events().publish(
-        (symbol_short!("admin"), symbol_short!("unpaused")),
+        (symbol_short!("remit"), symbol_short!("recipient")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
-            admin,
+            remittance_id,
+            old_recipient,
+            new_recipient,
         ),
     );
 }
 
-// ── Remittance Events ──────────────────────────────────────────────
+/// Emits an event when a stale pending remittance is swept to `Expired`.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the expired remittance
+/// * `sender` - Address of the sender whose funds remain refundable
+pub fn emit_remittance_expired(env: &Env, remittance_id: u64, sender: Address) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("expired")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            sender,
+        ),
+    );
+}
 
-/// Emits an event when a new remittance is created.
+/// Emits an event when a remittance is cancelled.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - Unique ID of the created remittance
-/// * `sender` - Address of the sender
-/// * `agent` - Address of the assigned agent
-/// * `amount` - Total remittance amount
-/// * `fee` - Platform fee deducted
-pub fn emit_remittance_created(
+/// * `remittance_id` - ID of the cancelled remittance
+/// * `sender` - Address of the sender who received the refund
+/// * `amount` - Refunded amount
+pub fn emit_remittance_cancelled(
     env: &Env,
     remittance_id: u64,
     sender: Address,
-    agent: Address,
     amount: i128,
-    fee: i128,
-    integrator_fee: i128,
 ) {
     env.events().publish(
-        (symbol_short!("remit"), symbol_short!("created")),
+        (symbol_short!("remit"), symbol_short!("cancel")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
             sender,
-            agent,
             amount,
-            fee,
-            integrator_fee,
         ),
     );
 }
 
-/// Emits an event when a remittance payout is completed.
+/// Emits an event when an admin reclaims an abandoned remittance's escrow.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - ID of the completed remittance
-/// * `agent` - Address of the agent who received the payout
-/// * `amount` - Payout amount (after fee deduction)
-pub fn emit_remittance_completed(
-    env: &Env,
-    remittance_id: u64,
-    agent: Address,
-    amount: i128,
-) {
+/// * `remittance_id` - ID of the reclaimed remittance
+/// * `admin` - Admin who reclaimed the escrow
+/// * `to` - Address that received the reclaimed funds
+/// * `amount` - Reclaimed amount, including reserved settlement fees
+pub fn emit_remittance_reclaimed(env: &Env, remittance_id: u64, admin: Address, to: Address, amount: i128) {
     env.events().publish(
-        (symbol_short!("remit"), symbol_short!("complete")),
+        (symbol_short!("remit"), symbol_short!("reclaimed")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
             env.ledger().timestamp(),
             remittance_id,
-            agent,
+            admin,
+            to,
             amount,
         ),
     );
 }
 
-/// Emits an event when a remittance is cancelled.
+/// Emits an event when a compliance hold is placed on a remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `remittance_id` - ID of the cancelled remittance
+/// * `remittance_id` - ID of the held remittance
+/// * `caller` - Admin or operator who placed the hold
+pub fn emit_hold_placed(env: &Env, remittance_id: u64, caller: Address) {
+    env.events().publish(
+        (symbol_short!("hold"), symbol_short!("placed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            caller,
+        ),
+    );
+}
+
+/// Emits an event when a compliance hold is released.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the released remittance
+/// * `caller` - Admin or operator who released the hold
+pub fn emit_hold_released(env: &Env, remittance_id: u64, caller: Address) {
+    env.events().publish(
+        (symbol_short!("hold"), symbol_short!("released")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            caller,
+        ),
+    );
+}
+
+/// Emits an event when an expired remittance is refunded to its sender.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the refunded remittance
 /// * `sender` - Address of the sender who received the refund
 /// * `amount` - Refunded amount
-pub fn emit_remittance_cancelled(
+pub fn emit_remittance_refunded(
     env: &Env,
     remittance_id: u64,
     sender: Address,
     amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("remit"), symbol_short!("cancel")),
+        (symbol_short!("remit"), symbol_short!("refund")),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
@@ -171,6 +972,26 @@ pub fn emit_agent_registered(env: &Env, agent: Address) {
     );
 }
 
+/// Emits an event when an agent sets or clears its payout address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent's authorizing address
+/// * `payout` - New payout address, or None if funds go to the agent itself again
+pub fn emit_agent_payout_updated(env: &Env, agent: Address, payout: Option<Address>) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("payout")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+            payout,
+        ),
+    );
+}
+
 /// Emits an event when an agent is removed.
 ///
 /// # Arguments
@@ -189,6 +1010,42 @@ pub fn emit_agent_removed(env: &Env, agent: Address) {
     );
 }
 
+/// Emits an event when an agent is suspended.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the suspended agent
+pub fn emit_agent_suspended(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("suspended")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
+/// Emits an event when a suspended agent is reinstated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Address of the reinstated agent
+pub fn emit_agent_reinstated(env: &Env, agent: Address) {
+    env.events().publish(
+        (symbol_short!("agent"), symbol_short!("reinstate")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            agent,
+        ),
+    );
+}
+
 // ── Fee Events ─────────────────────────────────────────────────────
 
 /// Emits an event when the platform fee is updated.
@@ -209,6 +1066,120 @@ pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
     );
 }
 
+/// Emits an event when the settlement fee split is updated.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `treasury_bps` - New treasury share in basis points
+/// * `agent_bps` - New agent share in basis points
+pub fn emit_fee_config_updated(env: &Env, treasury_bps: u32, agent_bps: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("config")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            treasury_bps,
+            agent_bps,
+        ),
+    );
+}
+
+/// Emits an event when an admin reverses a settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance restored to Pending
+/// * `payee` - Address the payout was clawed back from
+/// * `amount` - Amount clawed back
+pub fn emit_settlement_reversed(env: &Env, remittance_id: u64, payee: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("settle"), symbol_short!("reversed")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            payee,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when a token's settlement fee override is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token whose override changed
+/// * `config` - New override, or None if the token reverted to the global split
+pub fn emit_token_fee_updated(env: &Env, token: Address, config: Option<FeeConfig>) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("token")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            config,
+        ),
+    );
+}
+
+/// Emits an event when a payout conversion rate is set or cleared.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `from_token` - Escrow token
+/// * `to_token` - Payout token
+/// * `rate_bps` - New rate in basis points (0 when cleared)
+pub fn emit_conversion_rate_updated(env: &Env, from_token: Address, to_token: Address, rate_bps: u32) {
+    env.events().publish(
+        (symbol_short!("fee"), symbol_short!("conv_rate")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            from_token,
+            to_token,
+            rate_bps,
+        ),
+    );
+}
+
+/// Emits an event when an agent pays a recipient in a converted token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the settled remittance
+/// * `agent` - Agent who paid the recipient
+/// * `out_token` - Token the recipient was paid in
+/// * `out_amount` - Amount of `out_token` delivered
+pub fn emit_payout_converted(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    out_token: Address,
+    out_amount: i128,
+) {
+    env.events().publish(
+        (symbol_short!("remit"), symbol_short!("converted")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            remittance_id,
+            agent,
+            out_token,
+            out_amount,
+        ),
+    );
+}
+
 /// Emits an event when accumulated fees are withdrawn.
 ///
 /// # Arguments
@@ -229,6 +1200,50 @@ pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
     );
 }
 
+/// Emits an event when an admin withdraws from the treasury balance of a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token withdrawn
+/// * `to` - Address receiving the withdrawal
+/// * `amount` - Amount withdrawn
+pub fn emit_treasury_withdrawn(env: &Env, token: Address, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("treasury"), symbol_short!("withdraw")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            to,
+            amount,
+        ),
+    );
+}
+
+/// Emits an event when an admin recovers tokens sent to the contract outside any remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token recovered
+/// * `to` - Address receiving the recovered tokens
+/// * `amount` - Amount recovered
+pub fn emit_stray_tokens_recovered(env: &Env, token: Address, to: Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("tokens"), symbol_short!("recovered")),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            token,
+            to,
+            amount,
+        ),
+    );
+}
+
 // ── Settlement Events ──────────────────────────────────────────────
 
 /// Emits a structured completion event when a settlement is finalized.
@@ -256,7 +1271,7 @@ pub fn emit_fees_withdrawn(env: &Env, to: Address, amount: i128) {
 ///
 /// # Event Structure
 ///
-/// Topic: `("settle", "complete")`
+/// Topic: `("settle", "complete", receiver, asset)`
 /// Data: `(schema_version, ledger_sequence, timestamp, remittance_id, sender, receiver, asset, amount)`
 ///
 /// # Usage
@@ -277,7 +1292,7 @@ pub fn emit_settlement_completed(
     amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("settle"), symbol_short!("complete")),
+        indexed_topics(symbol_short!("settle"), symbol_short!("complete"), &receiver, &asset),
         (
             SCHEMA_VERSION,
             env.ledger().sequence(),
@@ -292,6 +1307,29 @@ pub fn emit_settlement_completed(
 }
 
 
+/// Emits the receipt of a single settlement call.
+///
+/// Topic: `("settle", "receipt", recipient, token)`
+/// Data: `(schema_version, ledger_sequence, timestamp, receipt)`
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `recipient` - Recipient recorded on the remittance
+/// * `token` - Token the settlement was made in
+/// * `receipt` - Amounts settled, exactly as returned to the caller
+pub fn emit_settlement_receipt(env: &Env, recipient: &Address, token: &Address, receipt: &SettlementReceipt) {
+    env.events().publish(
+        indexed_topics(symbol_short!("settle"), symbol_short!("receipt"), recipient, token),
+        (
+            SCHEMA_VERSION,
+            env.ledger().sequence(),
+            env.ledger().timestamp(),
+            receipt.clone(),
+        ),
+    );
+}
+
 // ── Escrow Events ──────────────────────────────────────────────────
 
 /// Emits an event when escrow is created
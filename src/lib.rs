@@ -6,13 +6,19 @@
 #![no_std]
 
 mod asset_verification;
+mod circuit_breaker;
+mod daily_limit;
+mod debug;
 mod errors;
 mod events;
 mod hashing;
+mod math;
 mod migration;
 mod netting;
 mod rate_limit;
+mod stats;
 mod storage;
+mod transitions;
 mod types;
 mod validation;
 #[cfg(test)]
@@ -27,22 +33,160 @@ mod test_transfer_state;
 mod test_protocol_fee;
 #[cfg(test)]
 mod test_property; 
+#[cfg(test)]
+mod test_partial_settlement;
+#[cfg(test)]
+mod test_remittance_queries;
+#[cfg(test)]
+mod test_settlement_fee;
+#[cfg(test)]
+mod test_refund;
+#[cfg(test)]
+mod test_token_whitelist;
+#[cfg(test)]
+mod test_daily_limit;
+#[cfg(test)]
+mod test_cancel;
+#[cfg(test)]
+mod test_remittance_listing;
+#[cfg(test)]
+mod test_status_events;
+#[cfg(test)]
+mod test_agent_stats;
+#[cfg(test)]
+mod test_agent_suspension;
+#[cfg(test)]
+mod test_rate_limit_config;
+#[cfg(test)]
+mod test_admin_transfer;
+#[cfg(test)]
+mod test_update_recipient;
+#[cfg(test)]
+mod test_max_amount;
+#[cfg(test)]
+mod test_pause;
+#[cfg(test)]
+mod test_migration_dry_run;
+#[cfg(test)]
+mod test_memo;
+#[cfg(test)]
+mod test_assign_agent;
+#[cfg(test)]
+mod test_sweep_expired;
+#[cfg(test)]
+mod test_reentrancy;
+#[cfg(test)]
+mod test_default_expiry;
+#[cfg(test)]
+mod test_remove_token;
+#[cfg(test)]
+mod test_config;
+#[cfg(test)]
+mod test_operators;
+#[cfg(test)]
+mod test_idempotency;
+#[cfg(test)]
+mod test_settle_batch;
+#[cfg(test)]
+mod test_timelock;
+#[cfg(test)]
+mod test_claim;
+#[cfg(test)]
+mod test_init_info;
+#[cfg(test)]
+mod test_relayer;
+#[cfg(test)]
+mod test_tvl;
+#[cfg(test)]
+mod test_recipient_allowlist;
+#[cfg(test)]
+mod test_top_up;
+#[cfg(test)]
+mod test_version;
+#[cfg(test)]
+mod test_force_settle;
+#[cfg(test)]
+mod test_ttl;
+#[cfg(test)]
+mod test_conversion;
+#[cfg(test)]
+mod test_circuit_breaker;
+#[cfg(test)]
+mod test_settlement_receipt;
+#[cfg(test)]
+mod test_agent_daily_cap;
+#[cfg(test)]
+mod test_not_before;
+#[cfg(test)]
+mod test_admin_reclaim;
+#[cfg(test)]
+mod test_register_agents;
+#[cfg(test)]
+mod test_split_remittance;
+#[cfg(test)]
+mod test_data_layout;
+#[cfg(test)]
+mod test_settlement_hook;
+#[cfg(test)]
+mod test_fee_rounding;
+#[cfg(test)]
+mod test_get_agent;
+#[cfg(test)]
+mod test_abort_migration;
+#[cfg(test)]
+mod test_hold;
+#[cfg(test)]
+mod test_preferred_token;
+#[cfg(test)]
+mod test_global_stats;
+#[cfg(test)]
+mod test_settlement_grace;
+#[cfg(test)]
+mod test_status_index;
+#[cfg(test)]
+mod test_stray_tokens;
+#[cfg(test)]
+mod test_whitelist_bypass;
+#[cfg(test)]
+mod test_quote;
+#[cfg(test)]
+mod test_token_fee;
+#[cfg(test)]
+mod test_sender_stats;
+#[cfg(test)]
+mod test_init_config;
+#[cfg(test)]
+mod test_batch_limits;
+#[cfg(test)]
+mod test_settlement_reversal;
+#[cfg(test)]
+mod test_agent_payout;
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, InvokeError, String, Symbol, Val, Vec};
+
+use math::{checked_add_i128, checked_mul_bps, checked_mul_div_i128, checked_sub_i128, BPS_DENOMINATOR};
 
 pub use asset_verification::*;
+pub use circuit_breaker::*;
+pub use daily_limit::*;
+pub use debug::*;
 pub use errors::ContractError;
 pub use events::*;
 pub use hashing::*;
 pub use migration::*;
 pub use netting::*;
 pub use rate_limit::*;
+pub use stats::*;
 pub use storage::*;
+pub use transitions::*;
 pub use types::*;
 pub use validation::*;
 
-/// Maximum number of remittances that can be settled in a single batch
-const MAX_BATCH_SIZE: u32 = 100;
+/// Maximum number of remittance IDs returned by a single listing call
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Time in seconds a proposed admin has to accept before the proposal lapses (7 days)
+const ADMIN_PROPOSAL_TTL: u64 = 7 * 24 * 60 * 60;
 
 /// The main SwiftRemit contract for managing cross-border remittances.
 ///
@@ -82,12 +226,654 @@ pub struct SwiftRemitContract;
 // - fee_bps: Can be updated by admin via update_fee()
 // ============================================================================
 
+// ============================================================================
+// Settlement Internals
+// ============================================================================
+
+/// Escrows funds from an already validated and authenticated sender and records
+/// the new remittance.
+///
+/// `payee` is the settling agent in agent mode and the recipient in claim mode.
+#[allow(clippy::too_many_arguments)]
+fn open_remittance(
+    env: &Env,
+    sender: Address,
+    payee: Address,
+    token: Address,
+    amount: i128,
+    expiry: Option<u64>,
+    not_before: Option<u64>,
+    memo: Option<soroban_sdk::Bytes>,
+    mode: RemittanceMode,
+) -> Result<u64, ContractError> {
+    // Enforce the sender's daily limit for this token
+    check_daily_limit(env, &sender, &token, amount)?;
+
+    let counter = get_remittance_counter(env)?;
+    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+    let remittance = new_remittance(
+        env,
+        remittance_id,
+        sender.clone(),
+        payee.clone(),
+        token.clone(),
+        amount,
+        expiry,
+        not_before,
+        memo.clone(),
+        mode,
+    )?;
+    let total_due = checked_add_i128(checked_add_i128(amount, remittance.treasury_fee)?, remittance.agent_fee)?;
+
+    let token_client = token::Client::new(env, &token);
+    token_client.transfer(&sender, &env.current_contract_address(), &total_due);
+    set_tvl(env, &token, checked_add_i128(get_tvl(env, &token), total_due)?);
+
+    // Set initial transfer state first so storing the record extends both TTLs
+    set_transfer_state(env, remittance_id, TransferState::Initiated)?;
+
+    set_remittance(env, remittance_id, &remittance);
+    set_remittance_counter(env, remittance_id);
+    add_sender_remittance(env, &sender, remittance_id);
+    emit_remittance_created(env, remittance_id, sender, payee, token, amount, remittance.fee, memo);
+    record_transition(env, &remittance, None)?;
+
+    Ok(remittance_id)
+}
+
+/// Builds the record of a new pending remittance, with fees at the current configuration.
+///
+/// Shared by `open_remittance` and `quote`, so a quote uses exactly the record a
+/// settlement would see.
+#[allow(clippy::too_many_arguments)]
+fn new_remittance(
+    env: &Env,
+    id: u64,
+    sender: Address,
+    payee: Address,
+    token: Address,
+    amount: i128,
+    expiry: Option<u64>,
+    not_before: Option<u64>,
+    memo: Option<soroban_sdk::Bytes>,
+    mode: RemittanceMode,
+) -> Result<Remittance, ContractError> {
+    let (fee, treasury_fee, agent_fee) = remittance_fees(env, &token, amount, &mode)?;
+
+    Ok(Remittance {
+        id,
+        sender,
+        agent: payee.clone(),
+        original_recipient: payee.clone(),
+        recipient: payee,
+        assigned_agent: None,
+        token,
+        amount,
+        fee,
+        treasury_fee,
+        agent_fee,
+        remaining: amount,
+        status: RemittanceStatus::Pending,
+        expiry,
+        not_before,
+        on_hold: false,
+        created_at: env.ledger().timestamp(),
+        memo,
+        mode,
+    })
+}
+
+/// Returns at most `limit` (capped at `MAX_PAGE_SIZE`) IDs starting at `start_index`.
+fn paginate(env: &Env, ids: Vec<u64>, start_index: u32, limit: u32) -> Vec<u64> {
+    if start_index >= ids.len() {
+        return Vec::new(env);
+    }
+
+    let end = start_index
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(ids.len());
+    ids.slice(start_index..end)
+}
+
+/// Records a remittance's move to its current status and announces it.
+///
+/// Every status transition goes through here so the lifetime statistics in
+/// `get_global_stats` and the status indexes behind `list_remittances_by_status`
+/// stay in step with the `status_changed` events.
+fn record_transition(env: &Env, remittance: &Remittance, old_status: Option<RemittanceStatus>) -> Result<(), ContractError> {
+    record_status_change(env, remittance, &old_status)?;
+    move_status_index(env, remittance.id, &old_status, &remittance.status);
+    emit_status_changed(env, remittance.id, old_status, remittance.status.clone());
+    Ok(())
+}
+
+/// Platform fee, treasury share and agent share charged on `amount` of principal.
+fn remittance_fees(
+    env: &Env,
+    token: &Address,
+    amount: i128,
+    mode: &RemittanceMode,
+) -> Result<(i128, i128, i128), ContractError> {
+    let fee_bps = get_platform_fee_bps(env)?;
+    let rounding = get_fee_rounding(env);
+    let fee = checked_mul_bps(amount, fee_bps, rounding)?;
+
+    // Settlement fee is charged on top of the principal and held until settlement
+    let fee_config = get_effective_fee_config(env, token);
+    let treasury_fee = checked_mul_bps(amount, fee_config.treasury_bps, rounding)?;
+    // Claimed remittances have no agent, so no agent share is reserved
+    let agent_fee = match mode {
+        RemittanceMode::Agent | RemittanceMode::Split => checked_mul_bps(amount, fee_config.agent_bps, rounding)?,
+        RemittanceMode::Claim => 0,
+    };
+
+    Ok((fee, treasury_fee, agent_fee))
+}
+
+/// Protocol fee owed at settlement on a remittance of `amount` principal.
+fn protocol_fee(env: &Env, amount: i128) -> Result<i128, ContractError> {
+    checked_mul_bps(amount, get_protocol_fee_bps(env), get_fee_rounding(env))
+}
+
+/// Platform and protocol fee attributable to settling `tranche` of a remittance's
+/// remaining principal.
+fn tranche_fees(env: &Env, remittance: &Remittance, tranche: i128) -> Result<(i128, i128), ContractError> {
+    let settled_before = checked_sub_i128(remittance.amount, remittance.remaining)?;
+    let settled_after = checked_add_i128(settled_before, tranche)?;
+
+    // Protocol fee is calculated on the full amount, and was already taken from
+    // a remittance restored by a settlement reversal
+    let protocol_fee_total = if is_settlement_reversed(env, remittance.id) {
+        0
+    } else {
+        protocol_fee(env, remittance.amount)?
+    };
+
+    let fee = pro_rata_share(remittance.fee, remittance.amount, settled_before, settled_after)?;
+    let protocol_fee = pro_rata_share(protocol_fee_total, remittance.amount, settled_before, settled_after)?;
+    Ok((fee, protocol_fee))
+}
+
+/// Amounts released by settling a tranche of a remittance.
+#[derive(Clone, Copy)]
+struct TrancheAmounts {
+    fee: i128,
+    protocol_fee: i128,
+    agent_fee: i128,
+    treasury_fee: i128,
+    recipient_amount: i128,
+}
+
+/// Splits `tranche` of a remittance's remaining principal into fees and the
+/// recipient's share, releasing the matching part of the reserved settlement fee.
+fn tranche_amounts(env: &Env, remittance: &Remittance, tranche: i128) -> Result<TrancheAmounts, ContractError> {
+    let settled_before = checked_sub_i128(remittance.amount, remittance.remaining)?;
+    let settled_after = checked_add_i128(settled_before, tranche)?;
+
+    let (fee, protocol_fee) = tranche_fees(env, remittance, tranche)?;
+
+    // Settlement fee reserved at creation: the agent's cut rides with the payout,
+    // the treasury's cut stays in the contract
+    let agent_fee = pro_rata_share(remittance.agent_fee, remittance.amount, settled_before, settled_after)?;
+    let treasury_fee = pro_rata_share(remittance.treasury_fee, remittance.amount, settled_before, settled_after)?;
+
+    // Principal left for the recipient after platform and protocol fees
+    let recipient_amount = checked_sub_i128(checked_sub_i128(tranche, fee)?, protocol_fee)?;
+
+    Ok(TrancheAmounts { fee, protocol_fee, agent_fee, treasury_fee, recipient_amount })
+}
+
+/// Receipt for settling `tranche` of remittance `remittance_id` by `agent`.
+fn settlement_receipt(
+    remittance_id: u64,
+    tranche: i128,
+    amounts: &TrancheAmounts,
+    agent: Address,
+    settled_at: u64,
+) -> Result<SettlementReceipt, ContractError> {
+    Ok(SettlementReceipt {
+        remittance_id,
+        gross: tranche,
+        fee: checked_add_i128(amounts.fee, amounts.protocol_fee)?,
+        net: amounts.recipient_amount,
+        settlement_fee: checked_add_i128(amounts.agent_fee, amounts.treasury_fee)?,
+        agent,
+        settled_at,
+    })
+}
+
+/// Runs every check `confirm_payout` makes before `settle_tranche`, without side effects.
+///
+/// Lets `settle_batch` report a per-ID error while leaving that remittance untouched.
+fn validate_batch_settlement(env: &Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+    let remittance = validate_confirm_payout_request(env, remittance_id)?;
+    require_role_settler(env, &remittance.settling_agent())?;
+    check_settlement_rate_limit(env, &remittance.sender)?;
+
+    // Usage already includes the IDs settled earlier in the batch, so an agent
+    // reaching the cap mid-batch fails only the IDs past it
+    if remittance.mode != RemittanceMode::Claim {
+        ensure_within_agent_daily_cap(env, &remittance.settling_agent(), remittance.remaining)?;
+    }
+
+    Ok(remittance)
+}
+
+/// Whether `holder`'s balance of `token` is at least `amount`.
+fn has_token_balance(env: &Env, holder: &Address, token: &Address, amount: i128) -> bool {
+    token::Client::new(env, token).balance(holder) >= amount
+}
+
+/// Pays out `tranche` of a remittance's remaining principal to its agent.
+///
+/// Platform and protocol fees are taken pro rata to the principal settled, so a
+/// remittance settled across several tranches is charged exactly the same fees as
+/// one settled in a single call. Callers are responsible for validation and auth.
+///
+/// State is updated before any token transfer, and a settlement lock rejects
+/// re-entrant settlement with `DuplicateSettlement`.
+///
+/// Returns the amount transferred to the agent by this call.
+fn settle_tranche(env: &Env, remittance: &mut Remittance, tranche: i128) -> Result<i128, ContractError> {
+    let payee = remittance.recipient.clone();
+    let (payout_amount, _) = settle_tranche_to(env, remittance, tranche, &payee)?;
+    Ok(payout_amount)
+}
+
+/// Settles a tranche like `settle_tranche`, paying the recipient's share to `payee`.
+///
+/// Returns the total sent to the settler and a receipt of the amounts settled,
+/// which is also published as a `("settle", "receipt")` event.
+fn settle_tranche_to(
+    env: &Env,
+    remittance: &mut Remittance,
+    tranche: i128,
+    payee: &Address,
+) -> Result<(i128, SettlementReceipt), ContractError> {
+    let payees = Vec::from_array(env, [(payee.clone(), 1)]);
+    settle_tranche_among(env, remittance, tranche, &payees)
+}
+
+/// Settles a tranche like `settle_tranche_to`, dividing the recipient's share
+/// among `payees` in proportion to their weights.
+///
+/// The last payee also receives any rounding remainder, so the shares always
+/// add up to exactly the recipient's share.
+fn settle_tranche_among(
+    env: &Env,
+    remittance: &mut Remittance,
+    tranche: i128,
+    payees: &Vec<(Address, i128)>,
+) -> Result<(i128, SettlementReceipt), ContractError> {
+    // Reject re-entry from a token contract called during an ongoing settlement
+    if is_settlement_locked(env) {
+        return Err(ContractError::DuplicateSettlement);
+    }
+
+    // Check rate limit for sender
+    check_settlement_rate_limit(env, &remittance.sender)?;
+
+    let settler = remittance.settling_agent();
+
+    // Enforce the agent's daily settlement cap; claimants are not agents
+    if remittance.mode != RemittanceMode::Claim {
+        check_agent_daily_cap(env, &settler, tranche)?;
+    }
+    let agent_payout = match remittance.mode {
+        RemittanceMode::Claim => settler.clone(),
+        RemittanceMode::Agent | RemittanceMode::Split => get_agent_payout(env, &settler),
+    };
+
+    set_settlement_lock(env, true);
+
+    // Transition to Processing state
+    set_transfer_state(env, remittance.id, TransferState::Processing)?;
+
+    let settled_before = checked_sub_i128(remittance.amount, remittance.remaining)?;
+    let settled_after = checked_add_i128(settled_before, tranche)?;
+
+    let amounts = tranche_amounts(env, remittance, tranche)?;
+    let TrancheAmounts { fee, protocol_fee, agent_fee, treasury_fee, recipient_amount } = amounts;
+    let payout_amount = checked_add_i128(recipient_amount, agent_fee)?;
+
+    // Effects: record the settlement before any external token call, so a
+    // re-entrant call already sees the updated status and remaining balance
+    let current_fees = get_accumulated_fees(env, &remittance.token);
+    let new_fees = checked_add_i128(current_fees, fee)?;
+    set_accumulated_fees(env, &remittance.token, new_fees);
+
+    if treasury_fee > 0 {
+        let treasury_balance = checked_add_i128(get_treasury_balance(env, &remittance.token), treasury_fee)?;
+        set_treasury_balance(env, &remittance.token, treasury_balance);
+    }
+
+    // Everything this tranche releases leaves the locked pool, whether paid out or retained as fees
+    let released = checked_add_i128(checked_add_i128(tranche, agent_fee)?, treasury_fee)?;
+    set_tvl(env, &remittance.token, checked_sub_i128(get_tvl(env, &remittance.token), released)?);
+
+    track_settlement_volume(env, tranche)?;
+
+    remittance.remaining = checked_sub_i128(remittance.remaining, tranche)?;
+    let fully_settled = remittance.remaining == 0;
+    let old_status = remittance.status.clone();
+    transition(
+        remittance,
+        if fully_settled {
+            RemittanceStatus::Completed
+        } else {
+            RemittanceStatus::PartiallySettled
+        },
+    )?;
+    set_remittance(env, remittance.id, remittance);
+
+    // Update last settlement time for rate limiting
+    let current_time = env.ledger().timestamp();
+    set_last_settlement_time(env, &remittance.sender, current_time);
+
+    // Claimed remittances have no agent to credit
+    if remittance.mode != RemittanceMode::Claim {
+        record_agent_settlement(env, &settler, tranche, fully_settled)?;
+    }
+
+    if fully_settled {
+        // Transition to Completed state
+        set_transfer_state(env, remittance.id, TransferState::Completed)?;
+
+        // Mark settlement as executed to prevent duplicates
+        set_settlement_hash(env, remittance.id);
+
+        // Only a payout made in one transfer to a single payee can be clawed back
+        if settled_before == 0
+            && payees.len() == 1
+            && remittance.mode != RemittanceMode::Split
+            && get_settlement_reversal_window(env) > 0
+        {
+            set_reversible_settlement(
+                env,
+                remittance.id,
+                &ReversibleSettlement {
+                    payee: match payees.get_unchecked(0).0 {
+                        payee if payee == settler => agent_payout.clone(),
+                        payee => payee,
+                    },
+                    amount: recipient_amount,
+                    settled_at: current_time,
+                },
+            );
+        }
+    }
+
+    // Interactions: external token transfers
+    let token_client = token::Client::new(env, &remittance.token);
+
+    // Transfer each payee's share. Anything owed to the settling agent, including
+    // the agent fee, goes to its payout address, in one transfer when the agent is
+    // itself a payee
+    let mut total_weight: i128 = 0;
+    for (_, weight) in payees.iter() {
+        total_weight = checked_add_i128(total_weight, weight)?;
+    }
+    let mut distributed: i128 = 0;
+    let mut agent_fee_paid = false;
+    for (i, (payee, weight)) in payees.iter().enumerate() {
+        let share = if i as u32 + 1 == payees.len() {
+            checked_sub_i128(recipient_amount, distributed)?
+        } else {
+            checked_mul_div_i128(recipient_amount, weight, total_weight)?
+        };
+        distributed = checked_add_i128(distributed, share)?;
+
+        let payee = if payee == settler { agent_payout.clone() } else { payee };
+        let mut amount = share;
+        if payee == agent_payout && !agent_fee_paid {
+            amount = checked_add_i128(amount, agent_fee)?;
+            agent_fee_paid = true;
+        }
+        token_client.transfer(
+            &env.current_contract_address(),
+            &payee,
+            &amount,
+        );
+
+        if remittance.mode == RemittanceMode::Split {
+            emit_split_paid(env, remittance.id, &payee, &remittance.token, share);
+        }
+    }
+    if !agent_fee_paid && agent_fee > 0 {
+        token_client.transfer(
+            &env.current_contract_address(),
+            &agent_payout,
+            &agent_fee,
+        );
+    }
+
+    // Transfer protocol fee to treasury
+    if protocol_fee > 0 {
+        let treasury = get_treasury(env)?;
+        token_client.transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &protocol_fee,
+        );
+    }
+
+    record_transition(env, remittance, Some(old_status))?;
+
+    // Event: Tranche settled - Fires for every tranche of a remittance disbursed in pieces
+    // Used by off-chain systems to track cumulative progress towards full settlement
+    if settled_before > 0 || !fully_settled {
+        emit_remittance_partially_settled(
+            env,
+            remittance.id,
+            &remittance.recipient,
+            &remittance.token,
+            settler.clone(),
+            tranche,
+            settled_after,
+            remittance.remaining,
+        );
+    }
+
+    if fully_settled {
+        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
+        // Used by off-chain systems to track successful settlements and update transaction status
+        emit_remittance_completed(env, remittance.id, &remittance.recipient, &remittance.token, settler.clone(), payout_amount);
+
+        // Event: Settlement completed - Fires with final executed settlement values
+        // Used by off-chain systems for reconciliation and audit trails of completed transactions
+        emit_settlement_completed(env, remittance.id, remittance.sender.clone(), settler.clone(), remittance.token.clone(), payout_amount);
+    }
+
+    let receipt = settlement_receipt(remittance.id, tranche, &amounts, settler, current_time)?;
+    emit_settlement_receipt(env, &remittance.recipient, &remittance.token, &receipt);
+
+    notify_settlement_hook(env, remittance.id, &remittance.recipient, recipient_amount);
+
+    set_settlement_lock(env, false);
+
+    Ok((payout_amount, receipt))
+}
+
+/// Calls `on_settlement(remittance_id, recipient, amount)` on the registered
+/// settlement hook, if any.
+///
+/// Runs after all state changes and transfers, with the settlement lock still
+/// held. A failing or missing hook emits `("hook", "failed")` instead of
+/// reverting, so a broken notifier cannot block payouts.
+fn notify_settlement_hook(env: &Env, remittance_id: u64, recipient: &Address, amount: i128) {
+    let Some(hook) = get_settlement_hook(env) else {
+        return;
+    };
+
+    let args = (remittance_id, recipient.clone(), amount).into_val(env);
+    let result = env.try_invoke_contract::<Val, InvokeError>(
+        &hook,
+        &Symbol::new(env, "on_settlement"),
+        args,
+    );
+    if !matches!(result, Ok(Ok(_))) {
+        emit_settlement_hook_failed(env, remittance_id, hook);
+    }
+}
+
+/// Adds settled principal to the circuit breaker's rolling volume, pausing the
+/// contract if it crosses the configured threshold.
+///
+/// The settlement that crosses the threshold still completes; later ones are
+/// rejected with `ContractPaused` until an admin unpauses.
+fn track_settlement_volume(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if let Some(volume) = record_settlement_volume(env, amount)? {
+        if !is_paused(env) {
+            set_paused(env, true);
+            emit_circuit_tripped(env, volume, get_circuit_breaker_config(env).threshold);
+        }
+    }
+    Ok(())
+}
+
+/// Adds settled principal to an agent's track record, counting the remittance once
+/// it is fully settled.
+fn record_agent_settlement(
+    env: &Env,
+    agent: &Address,
+    volume: i128,
+    completed: bool,
+) -> Result<(), ContractError> {
+    let mut stats = get_agent_stats(env, agent);
+    stats.total_volume = checked_add_i128(stats.total_volume, volume)?;
+    if completed {
+        stats.settlement_count = stats
+            .settlement_count
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+    }
+    set_agent_stats(env, agent, &stats);
+    Ok(())
+}
+
+/// Removes a reversed settlement from an agent's track record, taking back the
+/// clawed-back principal and the completed remittance.
+fn release_agent_settlement(env: &Env, agent: &Address, volume: i128) -> Result<(), ContractError> {
+    let mut stats = get_agent_stats(env, agent);
+    stats.total_volume = checked_sub_i128(stats.total_volume, volume)?;
+    stats.settlement_count = stats
+        .settlement_count
+        .checked_sub(1)
+        .ok_or(ContractError::Overflow)?;
+    set_agent_stats(env, agent, &stats);
+    Ok(())
+}
+
+/// Rejects direct calls to timelocked operations while a timelock delay is set.
+fn require_no_timelock(env: &Env) -> Result<(), ContractError> {
+    if get_timelock_delay(env) > 0 {
+        return Err(ContractError::TimelockRequired);
+    }
+    Ok(())
+}
+
+/// Sets the platform fee. Callers are responsible for authorization.
+fn apply_update_fee(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
+    validate_update_fee_request(fee_bps)?;
+
+    set_platform_fee_bps(env, fee_bps);
+    emit_fee_updated(env, fee_bps);
+
+    log_update_fee(env, fee_bps);
+
+    Ok(())
+}
+
+/// Pays out part of a token's treasury balance. Callers are responsible for authorization.
+fn apply_withdraw_treasury(env: &Env, token: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+    let balance = validate_withdraw_treasury_request(env, &token, &to, amount)?;
+
+    set_treasury_balance(env, &token, checked_sub_i128(balance, amount)?);
+
+    let token_client = token::Client::new(env, &token);
+    token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+    emit_treasury_withdrawn(env, token, to, amount);
+
+    Ok(())
+}
+
+/// Tokens the contract holds beyond everything it owes: escrowed funds (TVL),
+/// accrued platform fees and the treasury balance. Never negative.
+fn get_recoverable_balance(env: &Env, token: &Address) -> Result<i128, ContractError> {
+    let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+    let owed = checked_add_i128(
+        checked_add_i128(get_tvl(env, token), get_accumulated_fees(env, token))?,
+        get_treasury_balance(env, token),
+    )?;
+    Ok(balance.saturating_sub(owed).max(0))
+}
+
+/// Sends tokens held outside escrow, fees and treasury to `to`. Callers are
+/// responsible for authorization.
+fn apply_recover_stray_tokens(env: &Env, token: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+    validate_recover_stray_tokens_request(env, &token, &to, amount)?;
+
+    let token_client = token::Client::new(env, &token);
+    token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+    emit_stray_tokens_recovered(env, token, to, amount);
+
+    Ok(())
+}
+
+/// Removes `admin` from the admin set on behalf of `caller`, who must already be
+/// an authenticated admin.
+fn apply_remove_admin(env: &Env, caller: &Address, admin: &Address) -> Result<(), ContractError> {
+    if !is_admin(env, admin) {
+        return Err(ContractError::AdminNotFound);
+    }
+
+    let count = get_admin_count(env);
+    if count <= 1 {
+        return Err(ContractError::CannotRemoveLastAdmin);
+    }
+
+    set_admin_role(env, admin, false);
+    set_admin_count(env, count - 1);
+
+    // Keep the legacy admin slot pointing at a live admin
+    if get_admin(env)? == *admin {
+        set_admin(env, caller);
+    }
+
+    emit_admin_removed(env, caller.clone(), admin.clone());
+    log_remove_admin(env, caller, admin);
+
+    Ok(())
+}
+
+/// Portion of `total` attributable to the principal settled between `from` and `to`.
+///
+/// Computed as the difference of cumulative shares so that rounding never leaks:
+/// once `to` reaches `whole`, the shares of all tranches sum to exactly `total`.
+fn pro_rata_share(total: i128, whole: i128, from: i128, to: i128) -> Result<i128, ContractError> {
+    let cumulative = |part: i128| -> Result<i128, ContractError> {
+        if part == whole {
+            return Ok(total);
+        }
+        checked_mul_div_i128(total, part, whole)
+    };
+    checked_sub_i128(cumulative(to)?, cumulative(from)?)
+}
+
 #[contractimpl]
 impl SwiftRemitContract {
     /// Initializes the contract with admin, token, and fee configuration.
     ///
     /// This function can only be called once. It sets up the contract's core parameters
-    /// and initializes all counters and accumulators to zero.
+    /// and initializes all counters and accumulators to zero. An optional `InitConfig`
+    /// additionally applies the fee split, amount bounds, default daily limit,
+    /// rate-limit cooldown, default expiry and tokens to whitelist in the same call.
+    /// Every field is validated before anything is written, so an invalid bundle fails
+    /// the whole call and the contract stays uninitialized. Without one, the
+    /// cooldown is zero and the remaining settings keep their defaults.
     ///
     /// # Arguments
     ///
@@ -95,29 +881,46 @@ impl SwiftRemitContract {
     /// * `admin` - Address that will have administrative privileges
     /// * `usdc_token` - Address of the USDC token contract used for transactions
     /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `protocol_fee_bps` - Protocol fee in basis points sent to `treasury`
+    /// * `treasury` - Address that receives protocol fees
+    /// * `timelock_delay` - Seconds that fee changes, treasury withdrawals and admin removals
+    ///   must wait in the action queue (0 lets admins call them directly)
+    /// * `config` - Optional initial configuration bundle
     ///
     /// # Returns
     ///
     /// * `Ok(())` - Contract successfully initialized
     /// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
     /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::InvalidAmount)` - Fee shares exceed 10000 bps, or an amount
+    ///   bound, daily limit or cooldown in `config` is invalid
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Initial token is neither whitelisted
+    ///   nor listed in `config`
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// contract.initialize(env, admin_addr, usdc_addr, 250); // 2.5% fee
+    /// contract.initialize(env, admin_addr, usdc_addr, 250, 0, treasury, 0, None); // 2.5% fee
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         env: Env,
         admin: Address,
         usdc_token: Address,
         fee_bps: u32,
-        rate_limit_cooldown: u64,
         protocol_fee_bps: u32,
         treasury: Address,
+        timelock_delay: u64,
+        config: Option<InitConfig>,
     ) -> Result<(), ContractError> {
         // Centralized validation before business logic
-        validate_initialize_request(&env, &admin, &usdc_token, fee_bps)?;
+        let initial_tokens = config
+            .as_ref()
+            .map_or_else(|| Vec::new(&env), |config| config.whitelisted_tokens.clone());
+        validate_initialize_request(&env, &admin, &usdc_token, fee_bps, &initial_tokens)?;
+        if let Some(config) = &config {
+            validate_init_config(config)?;
+        }
 
         // Set legacy admin for backward compatibility
         set_admin(&env, &admin);
@@ -132,387 +935,2229 @@ impl SwiftRemitContract {
         set_usdc_token(&env, &usdc_token);
         set_platform_fee_bps(&env, fee_bps);
         set_remittance_counter(&env, 0);
-        set_accumulated_fees(&env, 0);
-        set_rate_limit_cooldown(&env, rate_limit_cooldown);
+        set_accumulated_fees(&env, &usdc_token, 0);
+        set_rate_limit_cooldown(&env, config.as_ref().map_or(0, |config| config.rate_limit_cooldown));
         set_escrow_counter(&env, 0);
         
         // Initialize protocol fee and treasury
         set_protocol_fee_bps(&env, protocol_fee_bps)?;
         set_treasury(&env, &treasury);
+        set_timelock_delay(&env, timelock_delay);
 
         // Initialize rate limiting with default configuration
         init_rate_limit(&env);
 
-        log_initialize(&env, &admin, &usdc_token, fee_bps);
-
-        Ok(())
-    }
+        set_init_info(&env, &admin, env.ledger().timestamp());
+        set_contract_version(&env, INITIAL_CONTRACT_VERSION);
+        set_data_version(&env, CURRENT_DATA_VERSION);
+        emit_contract_initialized(&env, admin.clone());
 
-    /// Registers a new agent authorized to receive remittance payouts.
+        if let Some(config) = config {
+            for token in config.whitelisted_tokens.iter() {
+                if !is_token_whitelisted(&env, &token) {
+                    set_token_whitelisted(&env, &token, true);
+                    log_whitelist_token(&env, &token);
+                }
+            }
+            set_fee_config(
+                &env,
+                &FeeConfig {
+                    treasury_bps: config.treasury_bps,
+                    agent_bps: config.agent_bps,
+                },
+            );
+            set_min_remittance_amount(&env, config.min_remittance_amount);
+            set_max_remittance_amount(&env, config.max_remittance_amount);
+            set_default_daily_limit(&env, config.default_daily_limit);
+            set_default_expiry(&env, config.default_expiry);
+        }
+
+        log_initialize(&env, &admin, &usdc_token, fee_bps);
+
+        Ok(())
+    }
+
+    /// Proposes a new admin, who must accept before joining the admin set.
     ///
-    /// Only the contract admin can register agents. Registered agents can confirm
-    /// payouts for remittances assigned to them.
+    /// Replaces any earlier pending proposal. The proposal lapses after seven days.
+    /// Because the new admin only joins by signing `accept_admin`, an address that
+    /// cannot sign is never added to the admin set.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `agent` - Address to register as an authorized agent
+    /// * `caller` - Existing admin making the proposal
+    /// * `new_admin` - Address proposed to join the admin set
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Agent successfully registered
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Ok(())` - Proposal recorded
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::AdminAlreadyExists)` - Address is already an admin
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn register_agent(env: Env, agent: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn propose_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), ContractError> {
+        validate_admin_operation(&env, &caller, &new_admin)?;
 
-        set_agent_registered(&env, &agent, true);
+        if is_admin(&env, &new_admin) {
+            return Err(ContractError::AdminAlreadyExists);
+        }
 
-        // Event: Agent registered - Fires when admin adds a new agent to the approved list
-        // Used by off-chain systems to track which addresses can confirm payouts
-        emit_agent_registered(&env, agent);
+        let expires_at = env
+            .ledger()
+            .timestamp()
+            .checked_add(ADMIN_PROPOSAL_TTL)
+            .ok_or(ContractError::Overflow)?;
+        set_pending_admin(
+            &env,
+            &PendingAdmin {
+                address: new_admin.clone(),
+                proposed_by: caller.clone(),
+                expires_at,
+            },
+        );
 
+        emit_admin_proposed(&env, caller, new_admin, expires_at);
 
         Ok(())
     }
 
-    /// Removes an agent's authorization to receive remittance payouts.
-    ///
-    /// Only the contract admin can remove agents. Removed agents cannot confirm
-    /// new payouts, but existing remittances assigned to them remain valid.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `agent` - Address of the agent to remove
+    /// Accepts a pending admin proposal, adding the proposed address to the admin set.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Agent successfully removed
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Ok(())` - Proposed address joined the admin set
+    /// * `Err(ContractError::NoPendingAdmin)` - No proposal is pending
+    /// * `Err(ContractError::AdminProposalExpired)` - Proposal lapsed before acceptance
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn remove_agent(env: Env, agent: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Requires authentication from the proposed address.
+    pub fn accept_admin(env: Env) -> Result<(), ContractError> {
+        let pending = get_pending_admin(&env).ok_or(ContractError::NoPendingAdmin)?;
 
-        set_agent_registered(&env, &agent, false);
+        pending.address.require_auth();
 
-        // Event: Agent removed - Fires when admin removes an agent from the approved list
-        // Used by off-chain systems to revoke payout confirmation privileges
-        emit_agent_removed(&env, agent);
+        if env.ledger().timestamp() > pending.expires_at {
+            return Err(ContractError::AdminProposalExpired);
+        }
+
+        clear_pending_admin(&env);
+
+        if !is_admin(&env, &pending.address) {
+            set_admin_role(&env, &pending.address, true);
+            let count = get_admin_count(&env)
+                .checked_add(1)
+                .ok_or(ContractError::Overflow)?;
+            set_admin_count(&env, count);
+        }
 
+        emit_admin_accepted(&env, pending.address.clone());
+        log_add_admin(&env, &pending.proposed_by, &pending.address);
 
         Ok(())
     }
 
-    /// Updates the platform fee rate.
-    ///
-    /// Only the contract admin can update the fee. The new fee applies to all
-    /// remittances created after the update.
+    /// Returns the pending admin proposal, if any.
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        get_pending_admin(&env)
+    }
+
+    /// Removes an admin from the admin set.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `caller` - Admin performing the removal
+    /// * `admin` - Admin to remove (may be the caller)
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Fee successfully updated
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Ok(())` - Admin removed
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::AdminNotFound)` - Address is not an admin
+    /// * `Err(ContractError::CannotRemoveLastAdmin)` - Address is the only remaining admin
+    /// * `Err(ContractError::TimelockRequired)` - A timelock delay is set; queue `AdminAction::RemoveAdmin` instead
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
-        // Centralized validation
-        validate_update_fee_request(fee_bps)?;
-        
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn remove_admin(env: Env, caller: Address, admin: Address) -> Result<(), ContractError> {
+        validate_admin_operation(&env, &caller, &admin)?;
+        require_no_timelock(&env)?;
 
-        set_platform_fee_bps(&env, fee_bps);
-        emit_fee_updated(&env, fee_bps);
+        apply_remove_admin(&env, &caller, &admin)
+    }
 
-        log_update_fee(&env, fee_bps);
+    /// Returns the admin that initialized the contract and the ledger timestamp
+    /// of initialization, for provenance checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((admin, initialized_at))` - Initializing admin and timestamp
+    /// * `Err(ContractError::NotInitialized)` - Contract has not been initialized
+    pub fn get_init_info(env: Env) -> Result<(Address, u64), ContractError> {
+        get_init_info(&env)
+    }
 
-        Ok(())
+    /// Returns the schema version of the deployed contract.
+    ///
+    /// Set at initialization and bumped by each successful `migrate`; 0 before
+    /// initialization.
+    pub fn version(env: Env) -> u32 {
+        get_contract_version(&env)
     }
 
-    /// Creates a new remittance transaction.
+    /// Records that the contract's storage has been migrated to the next schema version.
     ///
-    /// Transfers the specified amount from the sender to the contract, calculates
-    /// the platform fee, and creates a pending remittance record. The agent can later
-    /// confirm the payout to receive the amount minus fees.
+    /// Versions advance one step at a time, so tooling cannot skip or replay a
+    /// migration step.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `sender` - Address initiating the remittance
-    /// * `agent` - Address of the registered agent who will receive the payout
-    /// * `amount` - Amount to remit in USDC (must be positive)
-    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `caller` - Admin running the migration
+    /// * `target_version` - Version being migrated to, exactly one above `version()`
     ///
     /// # Returns
     ///
-    /// * `Ok(remittance_id)` - Unique ID of the created remittance
-    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Ok(())` - Version advanced
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::MigrationInProgress)` - A batched import is part way through
+    /// * `Err(ContractError::InvalidMigrationBatch)` - Target is not the next version
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address.
-   pub fn create_remittance(
-    env: Env,
-    sender: Address,
-    agent: Address,
-    amount: i128,
-    expiry: Option<u64>,
-) -> Result<u64, ContractError> {
-    validate_create_remittance_request(&env, &sender, &agent, amount)?;
+    /// Requires authentication from an admin.
+    pub fn migrate(env: Env, caller: Address, target_version: u32) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
 
-    sender.require_auth();
+        let from_version = advance_contract_version(&env, target_version)?;
+        emit_contract_migrated(&env, caller, from_version, target_version);
 
-    let fee_bps = get_platform_fee_bps(&env)?;
-    let fee = amount
-        .checked_mul(fee_bps as i128)
-        .ok_or(ContractError::Overflow)?
-        .checked_div(10000)
-        .ok_or(ContractError::Overflow)?;
+        Ok(())
+    }
 
-    let usdc_token = get_usdc_token(&env)?;
-    let token_client = token::Client::new(&env, &usdc_token);
-    token_client.transfer(&sender, &env.current_contract_address(), &amount);
+    /// Abandons a batched migration import that is part way through, for example
+    /// after a bad batch was rejected.
+    ///
+    /// Clears the migration-in-progress flag without rolling back batches that
+    /// already imported. The batch cursor stays at the first batch not yet
+    /// applied, so a restarted import continues from there instead of
+    /// re-applying committed batches. Operators cannot call this.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(next_batch)` - Batch number the next import will accept
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn abort_migration(env: Env, caller: Address) -> Result<u32, ContractError> {
+        require_admin(&env, &caller)?;
 
-    let counter = get_remittance_counter(&env)?;
-    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        let next_batch = abort_import(&env);
+        emit_migration_aborted(&env, caller, next_batch);
 
-    let remittance = Remittance {
-        id: remittance_id,
-        sender: sender.clone(),
-        agent: agent.clone(),
-        amount,
-        fee,
-        status: RemittanceStatus::Pending,
-        expiry,
-    };
+        Ok(next_batch)
+    }
 
-    set_remittance(&env, remittance_id, &remittance);
-    set_remittance_counter(&env, remittance_id);
-    
-    // Set initial transfer state
-    set_transfer_state(&env, remittance_id, TransferState::Initiated)?;
+    /// Returns the storage layout version of the deployed contract.
+    ///
+    /// Contracts initialized by this build start on `CURRENT_DATA_VERSION`.
+    /// Contracts upgraded from a build that predates the version report
+    /// `LEGACY_DATA_VERSION` until `migrate_data` moves their keys.
+    pub fn contract_data_version(env: Env) -> u32 {
+        get_data_version(&env)
+    }
 
-    Ok(remittance_id)  // ← capital O
-}
-    /// Confirms a remittance payout to the agent.
+    /// Moves legacy-layout storage keys to their `DataKey` equivalents and
+    /// advances the data version by one step.
     ///
-    /// Transfers the remittance amount (minus platform fee) to the agent and marks
-    /// the remittance as completed. Includes duplicate settlement protection and
-    /// expiry validation.
+    /// Until this runs, reads fall back to legacy keys; afterwards legacy keys are
+    /// ignored. Persistent entries cannot be enumerated on-chain, so list every
+    /// token with an explicit daily limit and every agent with a daily cap.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `caller` - Admin running the migration
+    /// * `target_version` - Layout version being migrated to, exactly one above `contract_data_version()`
+    /// * `tokens` - Tokens whose legacy daily limits should move
+    /// * `agents` - Agents whose legacy caps and current-day usage should move
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Payout successfully confirmed and transferred
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
-    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
-    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
-    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Ok(())` - Keys moved and data version advanced
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::MigrationInProgress)` - A batched import is part way through
+    /// * `Err(ContractError::InvalidMigrationBatch)` - Target is not the next layout, or too many entries listed
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the agent address assigned to the remittance.
-    /// Requires Settler role.
-    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
-
-        remittance.agent.require_auth();
-        
-        // Require Settler role
-        require_role_settler(&env, &remittance.agent)?;
-        
-        // Transition to Processing state
-        set_transfer_state(&env, remittance_id, TransferState::Processing)?;
-
-        if remittance.status != RemittanceStatus::Pending {
-            return Err(ContractError::InvalidStatus);
-        }
-
-        // Check for duplicate settlement execution
-        if has_settlement_hash(&env, remittance_id) {
-            return Err(ContractError::DuplicateSettlement);
-        }
+    /// Requires authentication from an admin.
+    pub fn migrate_data(
+        env: Env,
+        caller: Address,
+        target_version: u32,
+        tokens: Vec<Address>,
+        agents: Vec<Address>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
 
-        // Check if settlement has expired
-        if let Some(expiry_time) = remittance.expiry {
-            let current_time = env.ledger().timestamp();
-            if current_time > expiry_time {
-                return Err(ContractError::SettlementExpired);
-            }
-        }
+        let from_version = advance_data_version(&env, target_version, &tokens, &agents)?;
+        emit_data_layout_migrated(&env, caller, from_version, target_version);
 
-        // Check rate limit for sender
-        check_settlement_rate_limit(&env, &remittance.sender)?;
+        Ok(())
+    }
 
-        // Validate the agent address before transfer
-        validate_address(&remittance.agent)?;
+    /// Queues a sensitive admin action to run after the timelock delay.
+    ///
+    /// The action unlocks at `now + delay`, giving the community time to react
+    /// before a fee change, treasury withdrawal, admin removal or stray token
+    /// recovery takes effect.
+    /// Parameters are validated when the action is executed, not when it is queued.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(action_id)` - ID to pass to `execute_action` or `cancel_queued_action`
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::Overflow)` - Unlock timestamp overflows
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn queue_action(env: Env, caller: Address, action: AdminAction) -> Result<u64, ContractError> {
+        require_admin(&env, &caller)?;
 
-        // Calculate protocol fee
-        let protocol_fee_bps = get_protocol_fee_bps(&env);
-        let protocol_fee = remittance
-            .amount
-            .checked_mul(protocol_fee_bps as i128)
-            .ok_or(ContractError::Overflow)?
-            .checked_div(10000)
+        let unlock_at = env
+            .ledger()
+            .timestamp()
+            .checked_add(get_timelock_delay(&env))
             .ok_or(ContractError::Overflow)?;
-
-        // Calculate payout after platform and protocol fees
-        let payout_amount = remittance
-            .amount
-            .checked_sub(remittance.fee)
-            .ok_or(ContractError::Overflow)?
-            .checked_sub(protocol_fee)
+        let action_id = get_queued_action_counter(&env)
+            .checked_add(1)
             .ok_or(ContractError::Overflow)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        
-        // Transfer payout to agent
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.agent,
-            &payout_amount,
+        set_queued_action(
+            &env,
+            &QueuedAction {
+                id: action_id,
+                action: action.clone(),
+                queued_by: caller.clone(),
+                unlock_at,
+            },
         );
-        
-        // Transfer protocol fee to treasury
-        if protocol_fee > 0 {
-            let treasury = get_treasury(&env)?;
-            token_client.transfer(
-                &env.current_contract_address(),
-                &treasury,
-                &protocol_fee,
-            );
-        }
+        set_queued_action_counter(&env, action_id);
 
-        let current_fees = get_accumulated_fees(&env)?;
-        let new_fees = current_fees
-            .checked_add(remittance.fee)
-            .ok_or(ContractError::Overflow)?;
-        set_accumulated_fees(&env, new_fees);
+        emit_action_queued(&env, action_id, caller, action, unlock_at);
 
-        remittance.status = RemittanceStatus::Completed;
-        set_remittance(&env, remittance_id, &remittance);
-        
-        // Transition to Completed state
-        set_transfer_state(&env, remittance_id, TransferState::Completed)?;
+        Ok(action_id)
+    }
 
-        // Mark settlement as executed to prevent duplicates
-        set_settlement_hash(&env, remittance_id);
-        
-        // Update last settlement time for rate limiting
-        let current_time = env.ledger().timestamp();
-        set_last_settlement_time(&env, &remittance.sender, current_time);
+    /// Runs a queued action once its timelock delay has elapsed.
+    ///
+    /// The action is removed from the queue, so it can only run once. It fails
+    /// with the same errors as the entrypoint it stands in for.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Action executed
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::ActionNotFound)` - No action is queued under this ID
+    /// * `Err(ContractError::TimelockNotElapsed)` - The action's unlock timestamp has not been reached
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn execute_action(env: Env, caller: Address, action_id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
 
-        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
-        // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(&env, remittance_id, remittance.agent.clone(), payout_amount);
-        
-        // Event: Settlement completed - Fires with final executed settlement values
-        // Used by off-chain systems for reconciliation and audit trails of completed transactions
-        emit_settlement_completed(&env, remittance_id, remittance.sender.clone(), remittance.agent.clone(), usdc_token.clone(), payout_amount);
+        let queued = get_queued_action(&env, action_id)?;
+        if env.ledger().timestamp() < queued.unlock_at {
+            return Err(ContractError::TimelockNotElapsed);
+        }
 
-        log_confirm_payout(&env, remittance_id, payout_amount);
+        remove_queued_action(&env, action_id);
+
+        match queued.action {
+            AdminAction::UpdateFee(fee_bps) => apply_update_fee(&env, fee_bps)?,
+            AdminAction::WithdrawTreasury(token, to, amount) => {
+                apply_withdraw_treasury(&env, token, to, amount)?
+            }
+            AdminAction::RemoveAdmin(admin) => apply_remove_admin(&env, &caller, &admin)?,
+            AdminAction::RecoverStrayTokens(token, to, amount) => {
+                apply_recover_stray_tokens(&env, token, to, amount)?
+            }
+        }
+
+        emit_action_executed(&env, action_id, caller);
 
         Ok(())
     }
 
-    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+    /// Drops a queued action without running it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Action cancelled
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::ActionNotFound)` - No action is queued under this ID
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn cancel_queued_action(env: Env, caller: Address, action_id: u64) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        let remittance = get_remittance(&env, remittance_id)?;
 
-        // Verify remittance is in a valid state (Completed)
-        if remittance.status != RemittanceStatus::Completed {
-            return Err(ContractError::InvalidStateTransition);
-        }
+        get_queued_action(&env, action_id)?;
+        remove_queued_action(&env, action_id);
+
+        emit_action_cancelled(&env, action_id, caller);
 
-        // Remittance is already completed, no further action needed
         Ok(())
     }
 
-    /// Cancels a pending remittance and refunds the sender.
-    ///
-    /// Returns the full remittance amount to the sender and marks the remittance
-    /// as cancelled. Can only be called by the original sender.
-    ///
-    /// # Arguments
+    /// Returns a queued action by ID.
+    pub fn get_queued_action(env: Env, action_id: u64) -> Result<QueuedAction, ContractError> {
+        get_queued_action(&env, action_id)
+    }
+
+    /// Returns the delay in seconds queued actions must wait before they can run.
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        get_timelock_delay(&env)
+    }
+
+    /// Checks if an address is in the admin set.
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        is_admin(&env, &address)
+    }
+
+    /// Grants the operator role to an address.
     ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to cancel
+    /// Operators can pause and unpause the contract, set limits and manage the
+    /// token whitelist. They cannot change the admin set or withdraw treasury
+    /// funds; those remain admin-only.
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - Remittance successfully cancelled and refunded
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Ok(())` - Operator added (idempotent if already an operator)
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address who created the remittance.
-    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn add_operator(env: Env, caller: Address, operator: Address) -> Result<(), ContractError> {
+        validate_admin_operation(&env, &caller, &operator)?;
 
-        remittance.sender.require_auth();
+        assign_role(&env, &operator, &Role::Operator);
+        emit_operator_added(&env, caller, operator);
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
+        Ok(())
+    }
 
-        remittance.status = RemittanceStatus::Cancelled;
-        set_remittance(&env, remittance_id, &remittance);
-        
-        // Transition to Refunded state
-        set_transfer_state(&env, remittance_id, TransferState::Refunded)?;
+    /// Revokes the operator role from an address.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Operator removed
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::OperatorNotFound)` - Address is not an operator
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn remove_operator(env: Env, caller: Address, operator: Address) -> Result<(), ContractError> {
+        validate_admin_operation(&env, &caller, &operator)?;
 
-        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
-        // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), remittance.amount);
+        if !has_role(&env, &operator, &Role::Operator) {
+            return Err(ContractError::OperatorNotFound);
+        }
 
-        log_cancel_remittance(&env, remittance_id);
+        remove_role(&env, &operator, &Role::Operator);
+        emit_operator_removed(&env, caller, operator);
 
         Ok(())
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    /// Checks if an address holds the operator role.
+    pub fn is_operator(env: Env, address: Address) -> bool {
+        has_role(&env, &address, &Role::Operator)
+    }
+
+    /// Registers a new agent authorized to receive remittance payouts.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// Only the contract admin can register agents. Registered agents can confirm
+    /// payouts for remittances assigned to them.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
+    /// * `agent` - Address to register as an authorized agent
+    /// * `label` - Optional display label of up to `MAX_AGENT_LABEL_LEN` bytes, such as a corridor name
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent successfully registered
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidAmount)` - Label exceeds `MAX_AGENT_LABEL_LEN` bytes
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn register_agent(env: Env, agent: Address, label: Option<soroban_sdk::Bytes>) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_label(&label)?;
+
+        // Re-registering an active agent keeps its original registration time
+        if !is_agent_registered(&env, &agent) {
+            set_agent_registered_at(&env, &agent, env.ledger().timestamp());
+        }
+        set_agent_registered(&env, &agent, true);
+        set_agent_label(&env, &agent, &label);
+
+        // Event: Agent registered - Fires when admin adds a new agent to the approved list
+        // Used by off-chain systems to track which addresses can confirm payouts
+        emit_agent_registered(&env, agent);
+
+
+        Ok(())
+    }
+
+    /// Sets or clears a registered agent's label. Only admins can call this.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Label updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidAmount)` - Label exceeds `MAX_AGENT_LABEL_LEN` bytes
+    pub fn set_agent_label(env: Env, caller: Address, agent: Address, label: Option<soroban_sdk::Bytes>) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+        validate_agent_label(&label)?;
+
+        set_agent_label(&env, &agent, &label);
+
+        Ok(())
+    }
+
+    /// Sets or clears the address an agent's settlement funds are paid to.
+    ///
+    /// Lets an agent authorize settlements with a hot key while its funds land at a
+    /// separate, more secure address. Settlement still requires auth from the agent's
+    /// own address; the agent fee and any payout made to the agent itself are sent
+    /// to the payout address instead.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout address updated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::InvalidAddress)` - Payout address is the contract itself
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn set_agent_payout(env: Env, agent: Address, payout: Option<Address>) -> Result<(), ContractError> {
+        agent.require_auth();
+
+        validate_agent_registered(&env, &agent)?;
+        if let Some(payout) = &payout {
+            validate_address(payout)?;
+            // Fees sent to the contract itself would be stranded
+            if *payout == env.current_contract_address() {
+                return Err(ContractError::InvalidAddress);
+            }
+        }
+
+        set_agent_payout(&env, &agent, &payout);
+        emit_agent_payout_updated(&env, agent, payout);
+
+        Ok(())
+    }
+
+    /// Returns the address an agent's settlement funds are paid to.
+    pub fn get_agent_payout(env: Env, agent: Address) -> Address {
+        get_agent_payout(&env, &agent)
+    }
+
+    /// Returns an agent's profile: registration, status, settlement stats and label.
+    ///
+    /// Removed agents are still returned, with `registered` set to false.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Agent)` - Agent profile
+    /// * `Err(ContractError::AgentNotRegistered)` - Address was never registered as an agent
+    pub fn get_agent(env: Env, agent: Address) -> Result<Agent, ContractError> {
+        if !was_agent_ever_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        Ok(Agent {
+            registered: is_agent_registered(&env, &agent),
+            registered_at: get_agent_registered_at(&env, &agent),
+            status: get_agent_status(&env, &agent),
+            stats: get_agent_stats(&env, &agent),
+            label: get_agent_label(&env, &agent),
+            payout: get_agent_payout(&env, &agent),
+            address: agent,
+        })
+    }
+
+    /// Registers several agents in one call. Only the contract admin can call this.
+    ///
+    /// Agents that are already registered, or listed more than once, are skipped
+    /// rather than rejected. Every address is validated before any change is made,
+    /// so an invalid entry (the contract itself) fails the whole batch with
+    /// `InvalidAddress` and no agent is registered.
+    ///
+    /// Returns the number of newly registered agents, or `BatchTooLarge` if more
+    /// than `MAX_BATCH_SIZE` agents are listed.
+    pub fn register_agents(env: Env, agents: Vec<Address>) -> Result<u32, ContractError> {
+        ensure_batch_size(agents.len())?;
+        validate_batch_addresses(&env, &agents)?;
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        let mut added: u32 = 0;
+        for agent in agents.iter() {
+            if is_agent_registered(&env, &agent) {
+                continue;
+            }
+
+            set_agent_registered_at(&env, &agent, env.ledger().timestamp());
+            set_agent_registered(&env, &agent, true);
+            emit_agent_registered(&env, agent);
+            added += 1;
+        }
+
+        Ok(added)
+    }
+
+    /// Removes an agent's authorization to receive remittance payouts.
+    ///
+    /// Only the contract admin can remove agents. Removed agents cannot confirm
+    /// new payouts, but existing remittances assigned to them remain valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent successfully removed
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn remove_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_agent_registered(&env, &agent, false);
+
+        // Event: Agent removed - Fires when admin removes an agent from the approved list
+        // Used by off-chain systems to revoke payout confirmation privileges
+        emit_agent_removed(&env, agent);
+
+
+        Ok(())
+    }
+
+    /// Temporarily suspends an agent from settling remittances.
+    ///
+    /// The agent stays registered and keeps its settlement history and stats, but
+    /// any settlement attempt fails with `AgentSuspended` until it is reinstated.
+    /// A suspended agent can still be removed with `remove_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to suspend
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent successfully suspended
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn suspend_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+
+        set_agent_status(&env, &agent, &AgentStatus::Suspended);
+        emit_agent_suspended(&env, agent);
+
+        Ok(())
+    }
+
+    /// Reinstates a suspended agent so it can settle remittances again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address of the agent to reinstate
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent successfully reinstated
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn reinstate_agent(env: Env, agent: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_agent_registered(&env, &agent)?;
+
+        set_agent_status(&env, &agent, &AgentStatus::Active);
+        emit_agent_reinstated(&env, agent);
+
+        Ok(())
+    }
+
+    /// Returns an agent's operational status.
+    pub fn get_agent_status(env: Env, agent: Address) -> AgentStatus {
+        get_agent_status(&env, &agent)
+    }
+
+    /// Updates the platform fee rate.
+    ///
+    /// Only the contract admin can update the fee. The new fee applies to all
+    /// remittances created after the update.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee successfully updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::TimelockRequired)` - A timelock delay is set; queue `AdminAction::UpdateFee` instead
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
+        // Centralized validation
+        validate_update_fee_request(fee_bps)?;
+        
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_timelock(&env)?;
+
+        apply_update_fee(&env, fee_bps)
+    }
+
+    /// Configures the settlement fee split between the contract treasury and agents.
+    ///
+    /// The settlement fee is reserved on top of the principal when a remittance is
+    /// created. At settlement the agent share is paid to the settling agent along with
+    /// the payout, and the treasury share is retained in the contract's treasury balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `treasury_bps` - Treasury share in basis points
+    /// * `agent_bps` - Agent share in basis points
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee split successfully updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAmount)` - Shares sum to more than 10000 bps
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn set_fee_config(env: Env, treasury_bps: u32, agent_bps: u32) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        validate_fee_config(treasury_bps, agent_bps)?;
+
+        set_fee_config(&env, &FeeConfig { treasury_bps, agent_bps });
+        emit_fee_config_updated(&env, treasury_bps, agent_bps);
+
+        Ok(())
+    }
+
+    /// Returns the current settlement fee split.
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        get_fee_config(&env)
+    }
+
+    /// Overrides the settlement fee split for remittances in one token.
+    ///
+    /// Applies to remittances created (or topped up) after the call; fees already
+    /// reserved on existing remittances are unchanged. Only admins can call this.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Override set
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAmount)` - Shares sum to more than 10000 bps
+    pub fn set_token_fee(
+        env: Env,
+        caller: Address,
+        token: Address,
+        treasury_bps: u32,
+        agent_bps: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_address(&token)?;
+        validate_fee_config(treasury_bps, agent_bps)?;
+
+        let config = Some(FeeConfig { treasury_bps, agent_bps });
+        set_token_fee_config(&env, &token, &config);
+        emit_token_fee_updated(&env, token, config);
+
+        Ok(())
+    }
+
+    /// Removes a token's settlement fee override so the global split applies again.
+    /// Only admins can call this.
+    pub fn clear_token_fee(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_token_fee_config(&env, &token, &None);
+        emit_token_fee_updated(&env, token, None);
+
+        Ok(())
+    }
+
+    /// Returns a token's settlement fee override, or None if it uses the global split.
+    pub fn get_token_fee(env: Env, token: Address) -> Option<FeeConfig> {
+        get_token_fee_config(&env, &token)
+    }
+
+    /// Sets the rate agents use to pay out `from_token` escrow in `to_token`.
+    ///
+    /// `rate_bps` is the amount of `to_token` delivered per unit of `from_token`, in
+    /// basis points (10000 = 1:1). A rate of zero removes the pair.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rate updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAddress)` - Both tokens are the same
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_conversion_rate(
+        env: Env,
+        caller: Address,
+        from_token: Address,
+        to_token: Address,
+        rate_bps: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        if from_token == to_token {
+            return Err(ContractError::InvalidAddress);
+        }
+
+        set_conversion_rate(&env, &from_token, &to_token, rate_bps);
+        emit_conversion_rate_updated(&env, from_token, to_token, rate_bps);
+
+        Ok(())
+    }
+
+    /// Returns the configured rate for paying out `from_token` escrow in `to_token`, if any.
+    pub fn get_conversion_rate(env: Env, from_token: Address, to_token: Address) -> Option<u32> {
+        get_conversion_rate(&env, &from_token, &to_token)
+    }
+
+    /// Creates a new remittance transaction.
+    ///
+    /// Transfers the specified amount from the sender to the contract, calculates
+    /// the platform fee, and creates a pending remittance record. The agent can later
+    /// confirm the payout to receive the amount minus fees.
+    ///
+    /// If a settlement fee is configured via `set_fee_config`, it is reserved on top
+    /// of the principal, so the sender is debited `amount` plus the settlement fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Address of the registered agent who will receive the payout
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `amount` - Amount to remit in `token` units (must be positive)
+    /// * `expiry_override` - Optional absolute expiry timestamp (seconds since epoch), which must be in
+    ///   the future; when absent, the default expiry window set by `set_default_expiry` applies
+    /// * `memo` - Optional payment reference (e.g. invoice number, up to 64 bytes) stored on the record; immutable once set
+    /// * `idempotency_key` - Optional client-supplied key; retrying with a key the sender has already
+    ///   used returns the original remittance ID without creating a new remittance or moving funds
+    /// * `not_before` - Optional timestamp before which settlement is rejected with `NotYetSettleable`;
+    ///   funds are escrowed immediately and the expiry, if any, must fall after it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created (or previously created) remittance
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::AgentNotRegistered)` - Specified agent is not registered
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::TokenMismatch)` - The agent has a different preferred token
+    /// * `Err(ContractError::MemoTooLong)` - Memo exceeds 64 bytes
+    /// * `Err(ContractError::SettlementExpired)` - Expiry override is not in the future
+    /// * `Err(ContractError::InvalidAmount)` - `not_before` is not before the expiry
+    /// * `Err(ContractError::DailySendLimitExceeded)` - Sender's daily limit for the token would be exceeded
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in fee calculation
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        token: Address,
+        amount: i128,
+        expiry_override: Option<u64>,
+        memo: Option<soroban_sdk::Bytes>,
+        idempotency_key: Option<soroban_sdk::BytesN<32>>,
+        not_before: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        // A retried submission resolves to the remittance it already created
+        if let Some(key) = &idempotency_key {
+            if let Some(existing_id) = get_idempotent_remittance(&env, &sender, key) {
+                return Ok(existing_id);
+            }
+        }
+
+        validate_create_remittance_request(&env, &sender, &agent, &token, amount)?;
+        validate_memo(&memo)?;
+        let expiry = resolve_remittance_expiry(&env, expiry_override)?;
+        validate_not_before(not_before, expiry)?;
+
+        sender.require_auth();
+
+        let remittance_id = open_remittance(
+            &env,
+            sender.clone(),
+            agent,
+            token,
+            amount,
+            expiry,
+            not_before,
+            memo,
+            RemittanceMode::Agent,
+        )?;
+        if let Some(key) = &idempotency_key {
+            set_idempotent_remittance(&env, &sender, key, remittance_id);
+        }
+
+        Ok(remittance_id)
+    }
+
+    /// Lets a relayer submit remittances on the sender's behalf via `create_remittance_for`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn approve_relayer(env: Env, sender: Address, relayer: Address) -> Result<(), ContractError> {
+        sender.require_auth();
+        validate_address(&relayer)?;
+
+        set_relayer_approved(&env, &sender, &relayer, true);
+        emit_relayer_approved(&env, sender, relayer);
+
+        Ok(())
+    }
+
+    /// Withdraws a relayer's permission to submit remittances on the sender's behalf.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn revoke_relayer(env: Env, sender: Address, relayer: Address) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        set_relayer_approved(&env, &sender, &relayer, false);
+        emit_relayer_revoked(&env, sender, relayer);
+
+        Ok(())
+    }
+
+    /// Checks whether a relayer may submit remittances on a sender's behalf.
+    pub fn is_relayer_approved(env: Env, sender: Address, relayer: Address) -> bool {
+        is_relayer_approved(&env, &sender, &relayer)
+    }
+
+    /// Adds a recipient to the sender's allowlist.
+    ///
+    /// The list only restricts payouts once enabled with `set_recipient_allowlist_enabled`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn add_allowed_recipient(env: Env, sender: Address, recipient: Address) -> Result<(), ContractError> {
+        sender.require_auth();
+        validate_address(&recipient)?;
+
+        set_recipient_allowed(&env, &sender, &recipient, true);
+        emit_recipient_allowed(&env, sender, recipient);
+
+        Ok(())
+    }
+
+    /// Removes a recipient from the sender's allowlist.
+    ///
+    /// Pending remittances to the recipient are unaffected.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn remove_allowed_recipient(env: Env, sender: Address, recipient: Address) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        set_recipient_allowed(&env, &sender, &recipient, false);
+        emit_recipient_disallowed(&env, sender, recipient);
+
+        Ok(())
+    }
+
+    /// Turns the sender's recipient allowlist on or off.
+    ///
+    /// While enabled, creating a remittance or redirecting one to a recipient
+    /// outside the list fails with `ContractError::Unauthorized`. Disabled by default.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn set_recipient_allowlist_enabled(env: Env, sender: Address, enabled: bool) -> Result<(), ContractError> {
+        sender.require_auth();
+
+        set_recipient_allowlist_enabled(&env, &sender, enabled);
+        emit_recipient_allowlist_toggled(&env, sender, enabled);
+
+        Ok(())
+    }
+
+    /// Checks whether the sender's recipient allowlist is enforced.
+    pub fn is_recipient_allowlist_enabled(env: Env, sender: Address) -> bool {
+        is_recipient_allowlist_enabled(&env, &sender)
+    }
+
+    /// Checks whether a recipient is on the sender's allowlist.
+    pub fn is_recipient_allowed(env: Env, sender: Address, recipient: Address) -> bool {
+        is_recipient_allowed(&env, &sender, &recipient)
+    }
+
+    /// Restricts the remittances a recipient will accept to a single token.
+    ///
+    /// While set, creating a remittance to the recipient (as agent, claimant or
+    /// split recipient) or redirecting one to them in any other token fails with
+    /// `ContractError::TokenMismatch`. Pass None to accept any whitelisted
+    /// token again. Existing remittances are unaffected.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Preference updated
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the recipient.
+    pub fn set_preferred_token(env: Env, recipient: Address, token: Option<Address>) -> Result<(), ContractError> {
+        recipient.require_auth();
+
+        if let Some(token) = &token {
+            validate_token_accepted(&env, token)?;
+        }
+
+        set_preferred_token(&env, &recipient, &token);
+        emit_preferred_token_set(&env, recipient, token);
+
+        Ok(())
+    }
+
+    /// Returns the recipient's preferred token, or None if they accept any whitelisted token.
+    pub fn get_preferred_token(env: Env, recipient: Address) -> Option<Address> {
+        get_preferred_token(&env, &recipient)
+    }
+
+    /// Creates a remittance submitted by a relayer the sender has approved.
+    ///
+    /// Behaves exactly like `create_remittance`: funds still come from `sender`,
+    /// who must authorize the call, while the relayer submits and pays for the
+    /// transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::Unauthorized)` - Relayer is not approved by the sender
+    /// * Otherwise the same errors as `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both the relayer and the sender.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_remittance_for(
+        env: Env,
+        relayer: Address,
+        sender: Address,
+        agent: Address,
+        token: Address,
+        amount: i128,
+        expiry_override: Option<u64>,
+        memo: Option<soroban_sdk::Bytes>,
+        idempotency_key: Option<soroban_sdk::BytesN<32>>,
+        not_before: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        relayer.require_auth();
+        if !is_relayer_approved(&env, &sender, &relayer) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        Self::create_remittance(env, sender, agent, token, amount, expiry_override, memo, idempotency_key, not_before)
+    }
+
+    /// Creates a remittance that the recipient claims directly, with no agent.
+    ///
+    /// For non-custodial corridors. Fees and limits apply as in `create_remittance`,
+    /// except that no agent share of the settlement fee is reserved. The remittance
+    /// can only be paid out through `claim_remittance`; agent settlement paths
+    /// reject it with `InvalidStatus`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `recipient` - Address that will claim the funds
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `amount` - Amount to remit in `token` units (must be positive)
+    /// * `expiry_override` - Optional absolute expiry timestamp, as in `create_remittance`
+    /// * `memo` - Optional payment reference (up to 64 bytes)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::InvalidAddress)` - Recipient is the contract itself
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::TokenMismatch)` - The recipient has a different preferred token
+    /// * Otherwise the same errors as `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_claimable_remittance(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+        expiry_override: Option<u64>,
+        memo: Option<soroban_sdk::Bytes>,
+    ) -> Result<u64, ContractError> {
+        validate_create_claimable_remittance_request(&env, &sender, &recipient, &token, amount)?;
+        validate_memo(&memo)?;
+        let expiry = resolve_remittance_expiry(&env, expiry_override)?;
+
+        sender.require_auth();
+
+        open_remittance(&env, sender, recipient, token, amount, expiry, None, memo, RemittanceMode::Claim)
+    }
+
+    /// Creates a remittance whose principal an agent pays out to several recipients at once.
+    ///
+    /// The sum of the per-recipient amounts is escrowed and validated against the
+    /// same limits as `create_remittance`. `settle_split` later pays every recipient
+    /// their amount, less their pro rata share of platform and protocol fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address initiating the remittance
+    /// * `agent` - Registered agent who will settle the split
+    /// * `token` - Whitelisted token contract to remit in
+    /// * `recipients` - Recipients paired with their amounts (at most 10)
+    /// * `expiry_override` - Optional absolute expiry timestamp, as in `create_remittance`
+    /// * `memo` - Optional payment reference (up to 64 bytes)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(remittance_id)` - Unique ID of the created remittance
+    /// * `Err(ContractError::EmptyCollection)` - No recipients supplied
+    /// * `Err(ContractError::BatchTooLarge)` - More than `MAX_SPLIT_RECIPIENTS` (10) recipients supplied
+    /// * `Err(ContractError::InvalidAmount)` - A recipient's amount is zero or negative
+    /// * `Err(ContractError::Overflow)` - Recipient amounts overflow when summed
+    /// * `Err(ContractError::InvalidAddress)` - A recipient is the contract itself
+    /// * `Err(ContractError::TokenMismatch)` - A recipient has a different preferred token
+    /// * Otherwise the same errors as `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn create_split_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        token: Address,
+        recipients: Vec<(Address, i128)>,
+        expiry_override: Option<u64>,
+        memo: Option<soroban_sdk::Bytes>,
+    ) -> Result<u64, ContractError> {
+        let amount = validate_split_recipients(&env, &sender, &token, &recipients)?;
+        validate_create_remittance_request(&env, &sender, &agent, &token, amount)?;
+        validate_memo(&memo)?;
+        let expiry = resolve_remittance_expiry(&env, expiry_override)?;
+
+        sender.require_auth();
+
+        let remittance_id = open_remittance(&env, sender, agent, token, amount, expiry, None, memo, RemittanceMode::Split)?;
+        set_remittance_splits(&env, remittance_id, &recipients);
+        extend_remittance_ttl(&env, remittance_id, expiry);
+
+        Ok(remittance_id)
+    }
+
+    /// Settles a split remittance, paying every recipient in a single call.
+    ///
+    /// Each recipient receives their amount less a pro rata share of platform and
+    /// protocol fees; the agent receives its reserved settlement fee. All transfers
+    /// happen in this call, so either every recipient is paid or the call reverts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - Split settled; `net` is the total paid to recipients
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not a pending split
+    /// * Otherwise the same errors as `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance's agent, who must hold the Settler role.
+    pub fn settle_split(env: Env, remittance_id: u64) -> Result<SettlementReceipt, ContractError> {
+        let mut remittance = validate_settle_split_request(&env, remittance_id)?;
+
+        let settler = remittance.settling_agent();
+        settler.require_auth();
+        require_role_settler(&env, &settler)?;
+
+        let splits = get_remittance_splits(&env, remittance_id);
+        let tranche = remittance.remaining;
+        let (payout_amount, receipt) = settle_tranche_among(&env, &mut remittance, tranche, &splits)?;
+
+        log_confirm_payout(&env, remittance_id, payout_amount);
+
+        Ok(receipt)
+    }
+
+    /// Returns the recipients and amounts of a split remittance (empty for other remittances).
+    pub fn get_remittance_splits(env: Env, remittance_id: u64) -> Vec<(Address, i128)> {
+        get_remittance_splits(&env, remittance_id)
+    }
+
+    /// Pays a claim-mode remittance out to its recipient.
+    ///
+    /// Transfers the escrowed amount minus platform and protocol fees to the
+    /// recipient and marks the remittance `Completed`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Funds transferred to the recipient
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not `Pending` or was created in agent mode
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance's recipient.
+    pub fn claim_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = validate_claim_remittance_request(&env, remittance_id)?;
+
+        remittance.recipient.require_auth();
+
+        let tranche = remittance.remaining;
+        settle_tranche(&env, &mut remittance, tranche)?;
+
+        Ok(())
+    }
+    /// Confirms a remittance payout to the agent.
+    ///
+    /// Transfers the remittance's remaining balance (minus platform fee) to the agent
+    /// and marks the remittance as completed. Includes duplicate settlement protection
+    /// and expiry validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - Payout confirmed; gross principal, fees and net amount delivered
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Pending or PartiallySettled
+    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent settling the remittance: the agent set by
+    /// `assign_agent` if any, otherwise the agent named at creation.
+    /// Requires Settler role.
+    pub fn confirm_payout(env: Env, remittance_id: u64) -> Result<SettlementReceipt, ContractError> {
+        // Centralized validation before business logic
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        let settler = remittance.settling_agent();
+        settler.require_auth();
+        
+        // Require Settler role
+        require_role_settler(&env, &settler)?;
+
+        // Pay out whatever principal is still outstanding
+        let tranche = remittance.remaining;
+        let payee = remittance.recipient.clone();
+        let (payout_amount, receipt) = settle_tranche_to(&env, &mut remittance, tranche, &payee)?;
+
+        log_confirm_payout(&env, remittance_id, payout_amount);
+
+        Ok(receipt)
+    }
+
+    /// Settles a pending remittance to an address chosen by an admin.
+    ///
+    /// Break-glass override for remittances whose agent has gone unresponsive.
+    /// Pays the full principal, less platform and protocol fees, to `to`; the
+    /// agent's reserved fee share goes with it since no agent settled. Skips the
+    /// settlement rate limit and works while paused, but an expired remittance
+    /// still cannot be settled. Emits a `("remittance", "force_settled")` event
+    /// naming the admin and the agent that was bypassed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin forcing the settlement
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `to` - Address that receives the payout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance settled
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not a pending agent-mode remittance
+    /// * `Err(ContractError::SettlementExpired)` - Remittance has expired
+    /// * `Err(ContractError::InvalidAddress)` - `to` is the contract itself
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn force_settle(env: Env, caller: Address, remittance_id: u64, to: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        let mut remittance = validate_force_settle_request(&env, remittance_id, &to)?;
+
+        // Reject re-entry from a token contract called during an ongoing settlement
+        if is_settlement_locked(&env) {
+            return Err(ContractError::DuplicateSettlement);
+        }
+        set_settlement_lock(&env, true);
+
+        let original_agent = remittance.settling_agent();
+
+        let protocol_fee = checked_mul_bps(remittance.amount, get_protocol_fee_bps(&env), get_fee_rounding(&env))?;
+        let payout_amount = checked_add_i128(
+            checked_sub_i128(checked_sub_i128(remittance.amount, remittance.fee)?, protocol_fee)?,
+            remittance.agent_fee,
+        )?;
+
+        // Effects before any external token call
+        let new_fees = checked_add_i128(get_accumulated_fees(&env, &remittance.token), remittance.fee)?;
+        set_accumulated_fees(&env, &remittance.token, new_fees);
+        if remittance.treasury_fee > 0 {
+            let treasury_balance =
+                checked_add_i128(get_treasury_balance(&env, &remittance.token), remittance.treasury_fee)?;
+            set_treasury_balance(&env, &remittance.token, treasury_balance);
+        }
+        let released = checked_add_i128(
+            checked_add_i128(remittance.amount, remittance.agent_fee)?,
+            remittance.treasury_fee,
+        )?;
+        set_tvl(&env, &remittance.token, checked_sub_i128(get_tvl(&env, &remittance.token), released)?);
+
+        remittance.remaining = 0;
+        transition(&mut remittance, RemittanceStatus::Completed)?;
+        set_remittance(&env, remittance_id, &remittance);
+        set_transfer_state(&env, remittance_id, TransferState::Processing)?;
+        set_transfer_state(&env, remittance_id, TransferState::Completed)?;
+        set_settlement_hash(&env, remittance_id);
+
+        // Interactions: external token transfers
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(&env.current_contract_address(), &to, &payout_amount);
+        if protocol_fee > 0 {
+            let treasury = get_treasury(&env)?;
+            token_client.transfer(&env.current_contract_address(), &treasury, &protocol_fee);
+        }
+
+        record_transition(&env, &remittance, Some(RemittanceStatus::Pending))?;
+        emit_remittance_force_settled(
+            &env,
+            remittance_id,
+            caller,
+            original_agent,
+            &remittance.recipient,
+            &remittance.token,
+            to,
+            payout_amount,
+        );
+
+        notify_settlement_hook(&env, remittance_id, &remittance.recipient, payout_amount);
+
+        set_settlement_lock(&env, false);
+
+        Ok(())
+    }
+
+    /// Confirms payout of a remittance, delivering it to the recipient in `out_token`.
+    ///
+    /// With `out_token` equal to the escrow token this is exactly `confirm_payout`.
+    /// Otherwise the recipient's net amount is converted at the configured rate,
+    /// rounded down, and transferred in `out_token` from the agent to the
+    /// recipient, while the agent is credited the escrowed payout in the
+    /// escrow token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `out_token` - Token the recipient is paid in
+    /// * `min_out` - Least the recipient must receive in `out_token`, guarding against
+    ///   a rate change landing in the same ledger
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - Payout confirmed; amounts are in the escrow token
+    /// * `Err(ContractError::TokenNotWhitelisted)` - No conversion rate configured for the pair
+    /// * `Err(ContractError::SlippageExceeded)` - Recipient would receive less than `min_out`;
+    ///   nothing is settled or transferred
+    /// * `Err(ContractError::InsufficientLiquidity)` - Agent holds less `out_token` than the
+    ///   converted amount; nothing is settled or transferred
+    /// * `Err(ContractError::Overflow)` - Converted amount overflows
+    /// * Otherwise the same errors as `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the settling agent, who must hold enough
+    /// `out_token` to pay the recipient.
+    pub fn confirm_payout_in_token(
+        env: Env,
+        remittance_id: u64,
+        out_token: Address,
+        min_out: i128,
+    ) -> Result<SettlementReceipt, ContractError> {
+        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        // Net the recipient is owed once fees come out of the whole remaining principal
+        let tranche = remittance.remaining;
+        let (fee, protocol_fee) = tranche_fees(&env, &remittance, tranche)?;
+        let net = checked_sub_i128(checked_sub_i128(tranche, fee)?, protocol_fee)?;
+
+        if out_token == remittance.token {
+            if net < min_out {
+                return Err(ContractError::SlippageExceeded);
+            }
+            return Self::confirm_payout(env, remittance_id);
+        }
+        let rate_bps = get_conversion_rate(&env, &remittance.token, &out_token)
+            .ok_or(ContractError::TokenNotWhitelisted)?;
+
+        // Checked before any state change or transfer
+        let out_amount = checked_mul_div_i128(net, rate_bps as i128, BPS_DENOMINATOR)?;
+        if out_amount < min_out {
+            return Err(ContractError::SlippageExceeded);
+        }
+
+        let settler = remittance.settling_agent();
+        settler.require_auth();
+        require_role_settler(&env, &settler)?;
+
+        // The agent takes the whole escrowed payout and fronts the recipient in out_token
+        let recipient = remittance.recipient.clone();
+        if recipient != settler && !has_token_balance(&env, &settler, &out_token, out_amount) {
+            return Err(ContractError::InsufficientLiquidity);
+        }
+        let (payout_amount, receipt) = settle_tranche_to(&env, &mut remittance, tranche, &settler)?;
+
+        if recipient != settler && out_amount > 0 {
+            token::Client::new(&env, &out_token).transfer(&settler, &recipient, &out_amount);
+        }
+        emit_payout_converted(&env, remittance_id, settler, out_token, out_amount);
+
+        log_confirm_payout(&env, remittance_id, payout_amount);
+
+        Ok(receipt)
+    }
+
+    /// Checks whether an agent holds at least `amount` of `token`.
+    ///
+    /// Lets agents and off-chain tooling confirm liquidity before committing to
+    /// fiat payouts.
+    pub fn check_agent_liquidity(env: Env, agent: Address, token: Address, amount: i128) -> bool {
+        has_token_balance(&env, &agent, &token, amount)
+    }
+
+    /// Pays out part of a remittance so agents can disburse it in multiple tranches.
+    ///
+    /// Subtracts `amount` from the remittance's remaining balance and transfers that
+    /// principal (minus its pro-rata share of fees) to the agent. The remittance stays
+    /// `PartiallySettled` until the remaining balance reaches zero, at which point it
+    /// becomes `Completed`. Every tranche emits an event carrying the cumulative
+    /// settled amount for off-chain reconciliation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to settle
+    /// * `amount` - Principal to settle in this tranche
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Tranche successfully paid out
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is already completed or cancelled
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive or exceeds the remaining balance
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent settling the remittance: the agent set by
+    /// `assign_agent` if any, otherwise the agent named at creation.
+    /// Requires Settler role.
+    pub fn settle_partial(env: Env, remittance_id: u64, amount: i128) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let mut remittance = validate_settle_partial_request(&env, remittance_id, amount)?;
+
+        let settler = remittance.settling_agent();
+        settler.require_auth();
+
+        // Require Settler role
+        require_role_settler(&env, &settler)?;
+
+        settle_tranche(&env, &mut remittance, amount)?;
+
+        Ok(())
+    }
+
+    /// Settles several remittances in full in one transaction.
+    ///
+    /// Each ID is settled as `confirm_payout` would settle it. An ID that cannot be
+    /// settled (missing, not settleable, expired, rate limited, ...) is reported with
+    /// its error and left untouched; the remaining IDs still settle and pay out.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `ids` - Remittance IDs to settle (at most 100)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(results)` - `(remittance_id, outcome)` for every ID, in input order
+    /// * `Err(ContractError::InvalidAmount)` - Batch is empty
+    /// * `Err(ContractError::BatchTooLarge)` - Batch has more than `MAX_BATCH_SIZE` IDs
+    /// * `Err(ContractError::ContractPaused)` - Contract is paused
+    ///
+    /// An ID that would take its agent past their daily cap, counting the IDs
+    /// settled earlier in the batch, is reported with `AgentDailyCapExceeded`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the settling agent of each remittance that
+    /// passes validation. Each settling agent must hold the Settler role.
+    pub fn settle_batch(
+        env: Env,
+        ids: Vec<u64>,
+    ) -> Result<Vec<SettlementOutcome>, ContractError> {
+        if ids.is_empty() {
+            return Err(ContractError::InvalidAmount);
+        }
+        ensure_batch_size(ids.len())?;
+        validate_not_paused(&env)?;
+
+        let mut results = Vec::new(&env);
+        for id in ids.iter() {
+            let mut remittance = match validate_batch_settlement(&env, id) {
+                Ok(remittance) => remittance,
+                Err(err) => {
+                    results.push_back((id, Err(err)));
+                    continue;
+                }
+            };
+
+            remittance.settling_agent().require_auth();
+
+            let tranche = remittance.remaining;
+            let payout_amount = settle_tranche(&env, &mut remittance, tranche)?;
+            log_confirm_payout(&env, id, payout_amount);
+
+            results.push_back((id, Ok(())));
+        }
+
+        Ok(results)
+    }
+
+    pub fn finalize_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        let remittance = get_remittance(&env, remittance_id)?;
+
+        // Verify remittance is in a valid state (Completed)
+        if remittance.status != RemittanceStatus::Completed {
+            return Err(ContractError::InvalidStateTransition);
+        }
+
+        // Remittance is already completed, no further action needed
+        Ok(())
+    }
+
+    /// Cancels a pending remittance and refunds the sender.
+    ///
+    /// Returns the full remittance amount, including any reserved settlement fee, to
+    /// the sender and marks the remittance as cancelled. Can only be called by the
+    /// original sender, and only before an agent has paid out any part of it: once a
+    /// remittance is `PartiallySettled` or `Completed` the cancel fails with
+    /// `InvalidStatus` and leaves the record untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully cancelled and refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+
+        let refund_amount = checked_add_i128(
+            checked_add_i128(remittance.amount, remittance.treasury_fee)?,
+            remittance.agent_fee,
+        )?;
+
+        let tvl = checked_sub_i128(get_tvl(&env, &remittance.token), refund_amount)?;
+        set_tvl(&env, &remittance.token, tvl);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &refund_amount,
+        );
+
+        transition(&mut remittance, RemittanceStatus::Cancelled)?;
+        remittance.assigned_agent = None;
+        set_remittance(&env, remittance_id, &remittance);
+        record_transition(&env, &remittance, Some(RemittanceStatus::Pending))?;
+        
+        // Transition to Refunded state
+        set_transfer_state(&env, remittance_id, TransferState::Refunded)?;
+
+        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
+        // Used by off-chain systems to track cancellations and update transaction status
+        emit_remittance_cancelled(&env, remittance_id, remittance.sender.clone(), refund_amount);
+
+        log_cancel_remittance(&env, remittance_id);
+
+        Ok(())
+    }
+
+    /// Assigns a pending remittance to a specific settling agent.
+    ///
+    /// Once assigned, only that agent can settle the remittance; the agent named at
+    /// creation can no longer do so. Can be called again to reassign while the
+    /// remittance is still `Pending`. A payout still addressed to the replaced agent
+    /// moves to the new one; a recipient set with `update_recipient` is kept. The
+    /// assignment is cleared if the remittance is cancelled or refunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Sender of the remittance or an admin
+    /// * `remittance_id` - ID of the remittance to assign
+    /// * `agent` - Registered agent to assign
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Agent assigned
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending
+    /// * `Err(ContractError::RemittanceOnHold)` - Remittance is on hold
+    /// * `Err(ContractError::AgentNotRegistered)` - Agent is not registered
+    /// * `Err(ContractError::AgentSuspended)` - Agent is suspended
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the sender nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller.
+    pub fn assign_agent(env: Env, caller: Address, remittance_id: u64, agent: Address) -> Result<(), ContractError> {
+        let mut remittance = validate_assign_agent_request(&env, remittance_id, &agent)?;
+
+        caller.require_auth();
+        if caller != remittance.sender && !is_admin(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        // A payout still going to the agent being replaced follows the new agent;
+        // one redirected with update_recipient keeps its recipient
+        if remittance.recipient == remittance.settling_agent() {
+            remittance.recipient = agent.clone();
+        }
+        remittance.assigned_agent = Some(agent.clone());
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_agent_assigned(&env, remittance_id, caller, agent);
+
+        Ok(())
+    }
+
+    /// Adds funds to a pending remittance.
+    ///
+    /// Pulls `additional_amount` more principal, plus the settlement fee reserved on
+    /// it, into escrow and raises the remittance's amount and remaining balance.
+    /// Fees on the added amount use the rates in effect now; fees already charged
+    /// on the original amount are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to top up
+    /// * `additional_amount` - Principal to add
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance topped up
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending, or has expired
+    /// * `Err(ContractError::RemittanceOnHold)` - Remittance is on hold
+    /// * `Err(ContractError::InvalidAmount)` - Additional amount is not positive, or the new total exceeds the maximum
+    /// * `Err(ContractError::DailySendLimitExceeded)` - Additional amount exceeds the sender's daily limit
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the original sender.
+    pub fn top_up_remittance(env: Env, remittance_id: u64, additional_amount: i128) -> Result<(), ContractError> {
+        let mut remittance = validate_top_up_remittance_request(&env, remittance_id, additional_amount)?;
+
+        remittance.sender.require_auth();
+
+        check_daily_limit(&env, &remittance.sender, &remittance.token, additional_amount)?;
+
+        let (fee, treasury_fee, agent_fee) = remittance_fees(&env, &remittance.token, additional_amount, &remittance.mode)?;
+        let total_due = checked_add_i128(checked_add_i128(additional_amount, treasury_fee)?, agent_fee)?;
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(&remittance.sender, &env.current_contract_address(), &total_due);
+        set_tvl(&env, &remittance.token, checked_add_i128(get_tvl(&env, &remittance.token), total_due)?);
+
+        remittance.amount = checked_add_i128(remittance.amount, additional_amount)?;
+        remittance.remaining = checked_add_i128(remittance.remaining, additional_amount)?;
+        remittance.fee = checked_add_i128(remittance.fee, fee)?;
+        remittance.treasury_fee = checked_add_i128(remittance.treasury_fee, treasury_fee)?;
+        remittance.agent_fee = checked_add_i128(remittance.agent_fee, agent_fee)?;
+        set_remittance(&env, remittance_id, &remittance);
+        record_top_up(&env, &remittance, additional_amount)?;
+
+        emit_remittance_topped_up(&env, remittance_id, remittance.sender.clone(), additional_amount, remittance.amount);
+
+        Ok(())
+    }
+
+    /// Redirects a pending remittance to a new payout address.
+    ///
+    /// The agent still settles the remittance and keeps the agent fee; only the
+    /// principal payout goes to the new recipient. The original recipient stays
+    /// on the record for audit.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to redirect
+    /// * `new_recipient` - Address that will receive the payout
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Recipient updated
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not pending
+    /// * `Err(ContractError::RemittanceOnHold)` - Remittance is on hold
+    /// * `Err(ContractError::InvalidAddress)` - New recipient is not a valid payout address
+    /// * `Err(ContractError::TokenMismatch)` - New recipient has a different preferred token
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the original sender.
+    pub fn update_recipient(env: Env, remittance_id: u64, new_recipient: Address) -> Result<(), ContractError> {
+        let mut remittance = validate_update_recipient_request(&env, remittance_id, &new_recipient)?;
+
+        remittance.sender.require_auth();
+
+        let old_recipient = remittance.recipient.clone();
+        remittance.recipient = new_recipient.clone();
+        set_remittance(&env, remittance_id, &remittance);
+
+        emit_recipient_updated(&env, remittance_id, old_recipient, new_recipient);
+
+        Ok(())
+    }
+
+    /// Refunds an expired remittance so the sender can reclaim locked funds.
+    ///
+    /// Once the ledger timestamp is past the remittance's expiry and it is still
+    /// `Pending` (or has been swept to `Expired`), returns the escrowed amount,
+    /// including any reserved settlement fee, to the original sender and marks the
+    /// remittance as `Refunded`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - The original sender, or an admin acting on their behalf
+    /// * `remittance_id` - ID of the remittance to refund
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is neither Pending nor Expired (including already refunded)
+    /// * `Err(ContractError::RemittanceNotExpired)` - Remittance has no expiry or it has not passed yet
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither the sender nor an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be the sender or an admin.
+    pub fn refund_remittance(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        let mut remittance = validate_refund_remittance_request(&env, remittance_id)?;
+
+        caller.require_auth();
+        if caller != remittance.sender && !is_admin(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let refund_amount = checked_add_i128(
+            checked_add_i128(remittance.amount, remittance.treasury_fee)?,
+            remittance.agent_fee,
+        )?;
+
+        let tvl = checked_sub_i128(get_tvl(&env, &remittance.token), refund_amount)?;
+        set_tvl(&env, &remittance.token, tvl);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &remittance.sender,
+            &refund_amount,
+        );
+
+        let old_status = remittance.status.clone();
+        transition(&mut remittance, RemittanceStatus::Refunded)?;
+        remittance.assigned_agent = None;
+        set_remittance(&env, remittance_id, &remittance);
+        record_transition(&env, &remittance, Some(old_status))?;
+
+        // Transition to Refunded state
+        set_transfer_state(&env, remittance_id, TransferState::Refunded)?;
+
+        // Event: Remittance refunded - Fires when an expired remittance's escrow is returned to the sender
+        // Used by off-chain systems to track reclaimed funds and close out stale transactions
+        emit_remittance_refunded(&env, remittance_id, remittance.sender.clone(), refund_amount);
+
+        Ok(())
+    }
+
+    /// Reclaims the escrow of a remittance abandoned long past its expiry.
+    ///
+    /// A last resort for funds the sender never refunded: once the ledger timestamp
+    /// is past the remittance's expiry plus the reclaim grace period, moves the
+    /// escrowed amount, including any reserved settlement fee, to `to` and marks the
+    /// remittance `Reclaimed`. Remittances without an expiry cannot be reclaimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin performing the reclaim
+    /// * `remittance_id` - ID of the abandoned remittance
+    /// * `to` - Treasury address that receives the funds
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Escrow reclaimed
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is neither Pending nor Expired
+    /// * `Err(ContractError::RemittanceNotExpired)` - Remittance has no expiry or the grace period has not elapsed
+    /// * `Err(ContractError::InvalidAddress)` - `to` is the contract itself
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn admin_reclaim(env: Env, caller: Address, remittance_id: u64, to: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        let mut remittance = validate_admin_reclaim_request(&env, remittance_id, &to)?;
+
+        let reclaim_amount = checked_add_i128(
+            checked_add_i128(remittance.amount, remittance.treasury_fee)?,
+            remittance.agent_fee,
+        )?;
+
+        let tvl = checked_sub_i128(get_tvl(&env, &remittance.token), reclaim_amount)?;
+        set_tvl(&env, &remittance.token, tvl);
+
+        let old_status = remittance.status.clone();
+        transition(&mut remittance, RemittanceStatus::Reclaimed)?;
+        remittance.assigned_agent = None;
+        set_remittance(&env, remittance_id, &remittance);
+        set_transfer_state(&env, remittance_id, TransferState::Refunded)?;
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_client.transfer(&env.current_contract_address(), &to, &reclaim_amount);
+
+        record_transition(&env, &remittance, Some(old_status))?;
+        emit_remittance_reclaimed(&env, remittance_id, caller, to, reclaim_amount);
+
+        Ok(())
+    }
+
+    /// Freezes a remittance pending compliance review. Admins and operators can call this.
+    ///
+    /// While held, settling, claiming, refunding, cancelling and reclaiming the
+    /// remittance, as well as topping it up, redirecting it or reassigning its
+    /// agent, fail with `RemittanceOnHold`. The hold is independent of status,
+    /// so a partially settled remittance can be frozen too.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Hold placed
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither an admin nor an operator
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::RemittanceOnHold)` - Remittance is already on hold
+    /// * `Err(ContractError::InvalidStatus)` - Remittance no longer holds escrow
+    pub fn place_hold(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        validate_not_on_hold(&remittance)?;
+        match remittance.status {
+            RemittanceStatus::Pending | RemittanceStatus::PartiallySettled | RemittanceStatus::Expired => {}
+            _ => return Err(ContractError::InvalidStatus),
+        }
+
+        remittance.on_hold = true;
+        set_remittance(&env, remittance_id, &remittance);
+        set_held_index(&env, remittance_id, true);
+        emit_hold_placed(&env, remittance_id, caller);
+
+        Ok(())
+    }
+
+    /// Lifts a compliance hold placed with `place_hold`. Admins and operators can call this.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Hold released
+    /// * `Err(ContractError::Unauthorized)` - Caller is neither an admin nor an operator
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not on hold
+    pub fn release_hold(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if !remittance.on_hold {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        remittance.on_hold = false;
+        set_remittance(&env, remittance_id, &remittance);
+        set_held_index(&env, remittance_id, false);
+        emit_hold_released(&env, remittance_id, caller);
+
+        Ok(())
+    }
+
+    /// Sets how long past expiry a remittance must sit before `admin_reclaim` can
+    /// take its escrow. Only admins can call this. Defaults to 90 days.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Grace period successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    pub fn set_reclaim_grace_period(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_reclaim_grace_period(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the admin reclaim grace period in seconds.
+    pub fn get_reclaim_grace_period(env: Env) -> u64 {
+        get_reclaim_grace_period(&env)
+    }
+
+    /// Sets how long past expiry the agent may still settle a remittance.
+    /// Only admins can call this. Defaults to 0 (settlement ends at expiry).
+    ///
+    /// Covers `confirm_payout`, `settle_partial`, `settle_split` and batch
+    /// settlement while `now <= expiry + seconds`. Refunds, cancellation, sweeps,
+    /// claims and `force_settle` keep using the strict expiry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Grace window successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    pub fn set_settlement_grace(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_settlement_grace(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the agent settlement grace window in seconds.
+    pub fn get_settlement_grace(env: Env) -> u64 {
+        get_settlement_grace(&env)
+    }
+
+    /// Sets how long after settlement an admin may reverse it with `reverse_settlement`.
+    /// Only admins can call this. Defaults to 0 (settlements are final).
+    ///
+    /// Only remittances fully settled in a single payout while the window is
+    /// non-zero can be reversed; split remittances never can.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Reversal window successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    pub fn set_settlement_reversal_window(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_settlement_reversal_window(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the settlement reversal window in seconds.
+    pub fn get_settlement_reversal_window(env: Env) -> u64 {
+        get_settlement_reversal_window(&env)
+    }
+
+    /// Reverses a disputed settlement, clawing the payout back and restoring the
+    /// remittance to `Pending`. Only admins can call this.
+    ///
+    /// Allowed while `now <= settled_at + window`. The payee must have approved the
+    /// contract to spend at least the payout, typically when claiming or receiving it.
+    /// Fees charged by the settlement are kept, so the restored remittance carries
+    /// only the clawed-back amount with no platform, settlement or protocol fee;
+    /// cancelling or refunding it returns that amount to the sender. The clawed-back
+    /// amount is also taken out of the agent's settlement stats and daily cap usage
+    /// and the circuit breaker's volume, so settling it again counts it once. If the
+    /// remittance's expiry has passed or falls within one reversal window of now, it
+    /// is extended to `now + window` so the restored remittance can be settled again.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout clawed back and remittance restored to Pending
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin, or the payee has
+    ///   not approved a large enough allowance
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not Completed, was not
+    ///   settled reversibly, or the window has passed
+    /// * `Err(ContractError::InsufficientLiquidity)` - Payee no longer holds the payout
+    pub fn reverse_settlement(env: Env, caller: Address, remittance_id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        validate_transition(&remittance.status, &RemittanceStatus::Pending)?;
+        let settlement = get_reversible_settlement(&env, remittance_id).ok_or(ContractError::InvalidStatus)?;
+        let window = get_settlement_reversal_window(&env);
+        let now = env.ledger().timestamp();
+        let deadline = settlement.settled_at.checked_add(window).ok_or(ContractError::Overflow)?;
+        if now > deadline {
+            return Err(ContractError::InvalidStatus);
+        }
+        let settle_by = now.checked_add(window).ok_or(ContractError::Overflow)?;
+
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(&env, &remittance.token);
+        if token_client.allowance(&settlement.payee, &contract) < settlement.amount {
+            return Err(ContractError::Unauthorized);
+        }
+        if !has_token_balance(&env, &settlement.payee, &remittance.token, settlement.amount) {
+            return Err(ContractError::InsufficientLiquidity);
+        }
+
+        if remittance.mode != RemittanceMode::Claim {
+            let agent = remittance.settling_agent();
+            release_agent_settlement(&env, &agent, settlement.amount)?;
+            release_agent_daily_usage(&env, &agent, settlement.settled_at, settlement.amount);
+        }
+        release_settlement_volume(&env, settlement.amount, settlement.settled_at);
+
+        remittance.amount = settlement.amount;
+        remittance.remaining = settlement.amount;
+        remittance.fee = 0;
+        remittance.treasury_fee = 0;
+        remittance.agent_fee = 0;
+        reopen_settlement(&mut remittance, settle_by)?;
+        set_remittance(&env, remittance_id, &remittance);
+        remove_reversible_settlement(&env, remittance_id);
+        set_settlement_reversed(&env, remittance_id);
+        clear_settlement_hash(&env, remittance_id);
+        clear_transfer_state(&env, remittance_id);
+        set_tvl(
+            &env,
+            &remittance.token,
+            checked_add_i128(get_tvl(&env, &remittance.token), settlement.amount)?,
+        );
+
+        token_client.transfer_from(&contract, &settlement.payee, &contract, &settlement.amount);
+
+        record_transition(&env, &remittance, Some(RemittanceStatus::Completed))?;
+        emit_settlement_reversed(&env, remittance_id, settlement.payee, settlement.amount);
+
+        Ok(())
+    }
+
+    /// Sets how basis-point fees are rounded when they do not divide evenly.
+    /// Only admins can call this. Defaults to `FeeRounding::Down`.
+    ///
+    /// Platform and settlement fees are fixed when a remittance is created, so a
+    /// change only affects remittances created afterwards. The protocol fee is
+    /// computed at settlement and uses the mode in force then.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Rounding mode successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    pub fn set_fee_rounding(env: Env, caller: Address, mode: FeeRounding) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_fee_rounding(&env, mode);
+
+        Ok(())
+    }
+
+    /// Returns the rounding applied to basis-point fees.
+    pub fn get_fee_rounding(env: Env) -> FeeRounding {
+        get_fee_rounding(&env)
+    }
+
+    /// Marks stale pending remittances as `Expired` so off-chain views stay accurate.
+    ///
+    /// Callable by anyone. IDs that do not exist, are not `Pending`, or have not
+    /// passed their expiry are skipped rather than failing the batch. Funds stay
+    /// in escrow; swept remittances can still be refunded with `refund_remittance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `ids` - Remittance IDs to check (at most 100)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - IDs that were transitioned to `Expired`
+    /// * `Err(ContractError::BatchTooLarge)` - More than `MAX_BATCH_SIZE` IDs supplied
+    pub fn sweep_expired(env: Env, ids: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+        ensure_batch_size(ids.len())?;
+
+        let now = env.ledger().timestamp();
+        let mut expired = Vec::new(&env);
+
+        for id in ids.iter() {
+            let mut remittance = match get_remittance(&env, id) {
+                Ok(remittance) => remittance,
+                Err(_) => continue,
+            };
+            if !is_valid_transition(&remittance.status, &RemittanceStatus::Expired) {
+                continue;
+            }
+            match remittance.expiry {
+                Some(expiry) if now > expiry => {}
+                _ => continue,
+            }
+
+            transition(&mut remittance, RemittanceStatus::Expired)?;
+            set_remittance(&env, id, &remittance);
+            record_transition(&env, &remittance, Some(RemittanceStatus::Pending))?;
+            emit_remittance_expired(&env, id, remittance.sender.clone());
+            expired.push_back(id);
+        }
+
+        Ok(expired)
+    }
+
+    /// Frees daily-usage buckets left over from previous days.
+    ///
+    /// Callable by anyone. Usage is bucketed per UTC day in temporary storage, so
+    /// a new day always starts from zero and old buckets expire on their own;
+    /// this only reclaims them sooner. Both sender and agent buckets of each
+    /// address are pruned, and today's buckets are never touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `users` - Addresses whose stale buckets should be removed (at most 100)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Number of buckets removed
+    /// * `Err(ContractError::BatchTooLarge)` - More than `MAX_BATCH_SIZE` addresses supplied
+    pub fn prune_daily_usage(env: Env, users: Vec<Address>) -> Result<u32, ContractError> {
+        ensure_batch_size(users.len())?;
+
+        let mut removed = 0;
+        for user in users.iter() {
+            removed += prune_usage(&env, &user);
+        }
+
+        Ok(removed)
+    }
+
+    /// Withdraws accumulated platform fees in a token to a specified address.
+    ///
+    /// Transfers all fees accumulated in `token` to the recipient address and resets
+    /// that token's fee counter to zero. Only the contract admin can withdraw fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token whose accumulated fees are withdrawn
+    /// * `to` - Address to receive the withdrawn fees
     ///
     /// # Returns
     ///
@@ -524,18 +3169,17 @@ impl SwiftRemitContract {
     /// # Authorization
     ///
     /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> Result<(), ContractError> {
         // Centralized validation before business logic
-        let fees = validate_withdraw_fees_request(&env, &to)?;
+        let fees = validate_withdraw_fees_request(&env, &token, &to)?;
         
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
+        let token_client = token::Client::new(&env, &token);
         token_client.transfer(&env.current_contract_address(), &to, &fees);
 
-        set_accumulated_fees(&env, 0);
+        set_accumulated_fees(&env, &token, 0);
 
         // Event: Fees withdrawn - Fires when admin withdraws accumulated platform fees
         // Used by off-chain systems to track revenue collection and maintain financial records
@@ -543,27 +3187,294 @@ impl SwiftRemitContract {
 
         log_withdraw_fees(&env, &to, fees);
 
-        Ok(())
+        Ok(())
+    }
+
+    /// Withdraws part of the treasury's share of settlement fees in a token.
+    ///
+    /// Works for any token with an accrued balance, including tokens that have
+    /// since been removed from the whitelist.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `token` - Token whose treasury balance is withdrawn
+    /// * `to` - Address to receive the withdrawal
+    /// * `amount` - Amount to withdraw
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Amount transferred and deducted from the treasury balance
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive or exceeds the available balance
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::TimelockRequired)` - A timelock delay is set; queue `AdminAction::WithdrawTreasury` instead
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_treasury(env: Env, token: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        require_no_timelock(&env)?;
+
+        apply_withdraw_treasury(&env, token, to, amount)
+    }
+
+    /// Returns the treasury's accrued share of settlement fees in a token.
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        get_treasury_balance(&env, &token)
+    }
+
+    /// Recovers tokens sent directly to the contract outside any remittance.
+    ///
+    /// Only the surplus reported by `get_recoverable_balance` can be moved: the
+    /// contract's balance minus TVL, accrued platform fees and the treasury
+    /// balance. Escrowed funds and fees owed to the platform or treasury can
+    /// never be recovered this way.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin recovering the tokens
+    /// * `token` - Token to recover
+    /// * `to` - Address to receive the tokens
+    /// * `amount` - Amount to recover
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Tokens transferred
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive or exceeds the recoverable surplus
+    /// * `Err(ContractError::InvalidAddress)` - `to` is the contract itself
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::TimelockRequired)` - A timelock delay is set; queue `AdminAction::RecoverStrayTokens` instead
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn recover_stray_tokens(env: Env, caller: Address, token: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        require_no_timelock(&env)?;
+
+        apply_recover_stray_tokens(&env, token, to, amount)
+    }
+
+    /// Returns how much of a token the contract holds beyond escrowed funds,
+    /// accrued platform fees and the treasury balance.
+    pub fn get_recoverable_balance(env: Env, token: Address) -> Result<i128, ContractError> {
+        get_recoverable_balance(&env, &token)
+    }
+
+    /// Returns the total value locked in open remittances for a token.
+    ///
+    /// Counts escrowed principal plus the settlement fees reserved with it, so it
+    /// always equals the contract's token balance minus accrued platform fees and
+    /// the treasury balance.
+    pub fn get_tvl(env: Env, token: Address) -> i128 {
+        get_tvl(&env, &token)
+    }
+
+    /// Returns lifetime counts of remittances created, settled, cancelled,
+    /// refunded, reclaimed and expired, plus how many are still active.
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        get_global_stats(&env)
+    }
+
+    /// Returns the lifetime principal remitted in a token, counted at creation.
+    pub fn get_token_volume(env: Env, token: Address) -> i128 {
+        get_token_volume(&env, &token)
+    }
+
+    /// Returns a sender's lifetime counts of remittances created, settled,
+    /// cancelled, refunded and reclaimed, plus how many are still active.
+    /// A sender with no activity gets all zeros.
+    pub fn get_sender_stats(env: Env, sender: Address) -> SenderStats {
+        get_sender_stats(&env, &sender)
+    }
+
+    /// Returns the lifetime principal a sender has remitted in a token, counted at creation.
+    pub fn get_sender_volume(env: Env, sender: Address, token: Address) -> i128 {
+        get_sender_volume(&env, &sender, &token)
+    }
+
+    /// Retrieves a remittance record by ID.
+    ///
+    /// Returns the full stored record, including its current status, remaining
+    /// balance and creation timestamp. Reads the same persistent entry written by
+    /// the settlement paths, so the view is always consistent with on-chain state.
+    /// This is a read-only view and requires no authorization.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Extends the storage TTL of a remittance so it is not archived before settlement.
+    ///
+    /// Writes to a remittance already extend its TTL; this lets anyone keep an
+    /// untouched long-lived remittance alive. Safe to call repeatedly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - TTL extended
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn bump_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        extend_remittance_ttl(&env, remittance_id, remittance.expiry);
+        Ok(())
+    }
+
+    /// Returns the ID the next created remittance will receive.
+    ///
+    /// IDs come from a monotonic counter and are never reused, even after the
+    /// remittance holding an ID is cancelled or refunded.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The next remittance ID
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::Overflow)` - The ID space is exhausted
+    pub fn next_remittance_id(env: Env) -> Result<u64, ContractError> {
+        get_remittance_counter(&env)?
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)
+    }
+
+    /// Previews the receipt `confirm_payout` would return for a new remittance.
+    ///
+    /// Builds the remittance `create_remittance` would record and runs the same
+    /// fee split a full settlement uses, at the current configuration, without
+    /// writing state or moving funds. `remittance_id` is the ID the next
+    /// remittance would receive and `settled_at` is the current ledger timestamp.
+    /// `settlement_fee` is the amount reserved on top of the principal, using the
+    /// token's fee override if one is set. When `agent` is given the receipt
+    /// reflects its payout address; otherwise the contract stands in for the
+    /// agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Principal of the remittance
+    /// * `token` - Token the remittance would be created in
+    /// * `agent` - Optional agent that would settle it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SettlementReceipt)` - The receipt `confirm_payout` would return
+    /// * `Err(ContractError::InvalidAmount)` - Amount is not positive or outside the remittance bounds
+    /// * `Err(ContractError::TokenNotWhitelisted)` - Token is not whitelisted
+    /// * `Err(ContractError::AgentNotRegistered)` - A given agent is not registered
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn quote(
+        env: Env,
+        amount: i128,
+        token: Address,
+        agent: Option<Address>,
+    ) -> Result<SettlementReceipt, ContractError> {
+        validate_remittance_amount(&env, amount)?;
+        validate_token_accepted(&env, &token)?;
+        if let Some(agent) = &agent {
+            validate_agent_registered(&env, agent)?;
+        }
+
+        // The sender has no bearing on fees, so the contract stands in for it
+        let remittance = new_remittance(
+            &env,
+            Self::next_remittance_id(env.clone())?,
+            env.current_contract_address(),
+            agent.unwrap_or_else(|| env.current_contract_address()),
+            token,
+            amount,
+            None,
+            None,
+            None,
+            RemittanceMode::Agent,
+        )?;
+        let amounts = tranche_amounts(&env, &remittance, amount)?;
+
+        settlement_receipt(remittance.id, amount, &amounts, remittance.settling_agent(), env.ledger().timestamp())
     }
 
-    /// Retrieves a remittance record by ID.
+
+    /// Lists the IDs of remittances created by a sender, in creation order.
+    ///
+    /// Returns at most `limit` IDs starting at `start_index`; `limit` is capped at
+    /// 100 to stay within resource budgets. Returns an empty vector when
+    /// `start_index` is past the end. This is a read-only view and requires no
+    /// authorization.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to retrieve
+    /// * `sender` - Address whose remittances to list
+    /// * `start_index` - Position of the first ID to return
+    /// * `limit` - Maximum number of IDs to return
+    pub fn list_remittances_by_sender(
+        env: Env,
+        sender: Address,
+        start_index: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        paginate(&env, get_sender_remittances(&env, &sender), start_index, limit)
+    }
+
+    /// Lists the IDs of remittances currently in a status.
     ///
-    /// # Returns
+    /// IDs are ordered by when they entered the status. Pagination works as in
+    /// `list_remittances_by_sender`, with `limit` capped at 100. Holds are a flag
+    /// rather than a status, so held remittances are listed by
+    /// `list_held_remittances` instead. This is a read-only view and requires no
+    /// authorization.
     ///
-    /// * `Ok(Remittance)` - The remittance record
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
-        get_remittance(&env, remittance_id)
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `status` - Status whose remittances to list
+    /// * `start_index` - Position of the first ID to return
+    /// * `limit` - Maximum number of IDs to return
+    pub fn list_remittances_by_status(
+        env: Env,
+        status: RemittanceStatus,
+        start_index: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        paginate(&env, get_status_index(&env, &status), start_index, limit)
+    }
+
+    /// Lists the IDs of remittances currently under a compliance hold, in the
+    /// order the holds were placed. Pagination works as in
+    /// `list_remittances_by_sender`.
+    pub fn list_held_remittances(env: Env, start_index: u32, limit: u32) -> Vec<u64> {
+        paginate(&env, get_held_remittances(&env), start_index, limit)
     }
 
+    /// Returns the platform fees accumulated in a token and awaiting withdrawal.
+    pub fn get_accumulated_fees(env: Env, token: Address) -> i128 {
+        get_accumulated_fees(&env, &token)
+    }
 
-    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
-        get_accumulated_fees(&env)
+    /// Returns an agent's settlement track record.
+    ///
+    /// This is a read-only view and requires no authorization. Stats are kept for
+    /// agents that have since been removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((settlement_count, total_volume))` - Fully settled remittances and total principal paid out
+    /// * `Err(ContractError::AgentNotRegistered)` - Address was never registered as an agent
+    pub fn get_agent_stats(env: Env, agent: Address) -> Result<(u64, i128), ContractError> {
+        if !was_agent_ever_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        let stats = get_agent_stats(&env, &agent);
+        Ok((stats.settlement_count, stats.total_volume))
     }
 
     /// Checks if an address is registered as an agent.
@@ -636,18 +3547,127 @@ impl SwiftRemitContract {
         Ok(compute_settlement_id_from_remittance(&env, &remittance))
     }
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
+    /// Rehearses importing a migration batch without writing any state.
+    ///
+    /// Runs the same bounds, ordering and hash checks as the batched import, so
+    /// operators can replay a full batch sequence on a forked ledger before
+    /// migrating for real. Never sets the migration-in-progress flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `expected_hash` - Hash the batch is expected to carry
+    /// * `batch` - Batch to validate
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Batch would import from the current state
+    /// * `Err(ContractError::InvalidMigrationBatch)` - Batch is out of bounds or out of order
+    /// * `Err(ContractError::InvalidMigrationHash)` - Batch hash does not match
+    pub fn migration_dry_run(
+        env: Env,
+        expected_hash: soroban_sdk::BytesN<32>,
+        batch: MigrationBatch,
+    ) -> Result<(), ContractError> {
+        validate_batch(&env, &batch, &expected_hash)
+    }
+
+    /// Configures the circuit breaker that pauses the contract on a settlement surge.
+    ///
+    /// Settled principal is tracked over a rolling window of `window_seconds`.
+    /// When it exceeds `threshold` the contract pauses itself and emits a
+    /// `("contract", "circuit_tripped")` event; admins resume with `unpause`.
+    /// A threshold of zero disables the breaker. Reconfiguring resets the
+    /// tracked volume.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Breaker configured
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAmount)` - Threshold is negative, or an enabled breaker has a zero window
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_volume_circuit_breaker(
+        env: Env,
+        caller: Address,
+        threshold: i128,
+        window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        if threshold < 0 || (threshold > 0 && window_seconds == 0) {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_circuit_breaker_config(&env, &CircuitBreakerConfig { threshold, window_seconds });
+        Ok(())
+    }
+
+    /// Returns the circuit breaker configuration.
+    pub fn get_volume_circuit_breaker(env: Env) -> CircuitBreakerConfig {
+        get_circuit_breaker_config(&env)
+    }
+
+    /// Registers a contract to be notified after each settlement, or clears it with `None`.
+    ///
+    /// The hook is called as `on_settlement(remittance_id, recipient, amount)` once
+    /// the settlement's state changes and transfers are done, where `amount` is
+    /// the recipient's net amount. A hook that fails or does not exist does not
+    /// revert the settlement; a `("hook", "failed")` event is emitted instead.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Hook updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAddress)` - Hook is this contract
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin.
+    pub fn set_settlement_hook(env: Env, caller: Address, hook: Option<Address>) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
 
+        if let Some(hook) = &hook {
+            validate_address(hook)?;
+            if *hook == env.current_contract_address() {
+                return Err(ContractError::InvalidAddress);
+            }
+        }
+
+        set_settlement_hook(&env, &hook);
+        Ok(())
+    }
+
+    /// Returns the contract notified after each settlement, if any.
+    pub fn get_settlement_hook(env: Env) -> Option<Address> {
+        get_settlement_hook(&env)
+    }
+
+    /// Pauses the contract during an incident.
+    ///
+    /// While paused, new remittances and settlements are rejected with
+    /// `ContractPaused`. `cancel_remittance` and `refund_remittance` keep working
+    /// so senders can always recover escrowed funds.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin or operator.
+    pub fn pause(env: Env, caller: Address) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
         set_paused(&env, true);
         emit_paused(&env, caller);
         Ok(())
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    /// Resumes normal operation after a pause.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin or operator.
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
 
         set_paused(&env, false);
         emit_unpaused(&env, caller);
@@ -743,18 +3763,236 @@ impl SwiftRemitContract {
         crate::storage::is_paused(&env)
     }
     
-    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+    /// Sets the minimum time between settlements for the same sender.
+    ///
+    /// The cooldown is stored in instance storage and read by the settlement paths
+    /// at runtime, so it can be tuned per deployment. A value of zero disables
+    /// settlement rate limiting.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `caller` - Admin or operator making the change
+    /// * `cooldown_seconds` - Cooldown in seconds, at most one day
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Cooldown successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin or operator
+    /// * `Err(ContractError::InvalidAmount)` - Cooldown exceeds one day
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin or operator.
+    pub fn set_rate_limit(env: Env, caller: Address, cooldown_seconds: u64) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        validate_rate_limit_cooldown(cooldown_seconds)?;
 
         set_rate_limit_cooldown(&env, cooldown_seconds);
 
         Ok(())
     }
+
+    /// Deprecated alias of `set_rate_limit`, kept for existing integrations.
+    pub fn update_rate_limit(env: Env, caller: Address, cooldown_seconds: u64) -> Result<(), ContractError> {
+        Self::set_rate_limit(env, caller, cooldown_seconds)
+    }
     
     pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
         get_rate_limit_cooldown(&env)
     }
+
+    /// Sets the daily send limit for a token. Only admins and operators can call this.
+    ///
+    /// Each sender's total principal sent in `token` during a UTC day is capped at
+    /// `amount`. Usage resets when the ledger timestamp crosses into the next day.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Limit successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin or operator
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    pub fn set_daily_limit(env: Env, caller: Address, token: Address, amount: i128) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        validate_address(&token)?;
+        validate_amount(amount)?;
+
+        set_token_daily_limit(&env, &token, amount);
+
+        Ok(())
+    }
+
+    /// Sets the daily send limit applied to tokens without an explicit limit.
+    /// Only admins and operators can call this. Defaults to unlimited until set.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Default limit successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin or operator
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    pub fn set_default_daily_limit(env: Env, caller: Address, amount: i128) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        validate_amount(amount)?;
+
+        set_default_daily_limit(&env, amount);
+
+        Ok(())
+    }
+
+    /// Returns all current contract parameters in a single read.
+    ///
+    /// Lets clients fetch paused state, fees, limits and windows without one call
+    /// per value. Unset parameters are reported as their effective defaults.
+    pub fn get_config(env: Env) -> Config {
+        let fee_config = get_fee_config(&env);
+        Config {
+            paused: is_paused(&env),
+            platform_fee_bps: get_platform_fee_bps(&env).unwrap_or(0),
+            protocol_fee_bps: get_protocol_fee_bps(&env),
+            treasury_bps: fee_config.treasury_bps,
+            agent_bps: fee_config.agent_bps,
+            min_remittance_amount: get_min_remittance_amount(&env),
+            max_remittance_amount: get_max_remittance_amount(&env),
+            default_daily_limit: get_default_daily_limit(&env),
+            rate_limit_cooldown: get_rate_limit_cooldown(&env).unwrap_or(0),
+            default_expiry: get_default_expiry(&env),
+            timelock_delay: get_timelock_delay(&env),
+            reclaim_grace_period: get_reclaim_grace_period(&env),
+            settlement_grace: get_settlement_grace(&env),
+            settlement_reversal_window: get_settlement_reversal_window(&env),
+            require_token_whitelist: is_token_whitelist_required(&env),
+            fee_rounding: get_fee_rounding(&env),
+        }
+    }
+
+    /// Returns the daily send limit enforced for a token.
+    pub fn get_daily_limit(env: Env, token: Address) -> i128 {
+        get_token_daily_limit(&env, &token)
+    }
+
+    /// Returns how much a user has sent in a token during the current UTC day,
+    /// alongside the limit `create_remittance` enforces for that token.
+    ///
+    /// Reads the same day bucket as enforcement, so usage drops to zero at UTC
+    /// midnight. The limit falls back to the default when the token has no
+    /// explicit limit.
+    ///
+    /// # Returns
+    ///
+    /// * `(used_today, limit)` - Amount sent today and the enforced daily limit
+    pub fn get_daily_usage(env: Env, user: Address, token: Address) -> (i128, i128) {
+        (
+            get_daily_usage(&env, &user, &token),
+            get_token_daily_limit(&env, &token),
+        )
+    }
+
+    /// Caps how much principal an agent can settle per UTC day. Only admins can call this.
+    ///
+    /// Uses the same day buckets as the sender daily limit, so usage resets at UTC
+    /// midnight. Pass 0 to remove the cap; agents without a cap are unlimited.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Cap successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    /// * `Err(ContractError::InvalidAmount)` - Amount is negative
+    pub fn set_agent_daily_cap(env: Env, caller: Address, agent: Address, amount: i128) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        validate_address(&agent)?;
+        if amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        set_agent_daily_cap(&env, &agent, amount);
+
+        Ok(())
+    }
+
+    /// Returns how much principal an agent has settled during the current UTC day,
+    /// alongside the agent's cap (`i128::MAX` when uncapped).
+    ///
+    /// # Returns
+    ///
+    /// * `(settled_today, cap)` - Amount settled today and the enforced daily cap
+    pub fn get_agent_daily_usage(env: Env, agent: Address) -> (i128, i128) {
+        (
+            get_agent_daily_usage(&env, &agent),
+            get_agent_daily_cap(&env, &agent),
+        )
+    }
+
+    /// Sets the expiry window applied to remittances created without an explicit expiry.
+    /// Only admins can call this. Pass 0 to stop applying a default expiry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Default expiry successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    pub fn set_default_expiry(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+
+        set_default_expiry(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Returns the default expiry window in seconds (0 if none is applied).
+    pub fn get_default_expiry(env: Env) -> u64 {
+        get_default_expiry(&env)
+    }
+
+    /// Sets the largest principal a single remittance may carry.
+    /// Only admins and operators can call this. Defaults to unlimited until set.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Ceiling successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin or operator
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative, or below the minimum
+    pub fn set_max_remittance_amount(env: Env, caller: Address, amount: i128) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        validate_amount(amount)?;
+        validate_remittance_amount_bounds(get_min_remittance_amount(&env), amount)?;
+
+        set_max_remittance_amount(&env, amount);
+
+        Ok(())
+    }
+
+    /// Returns the largest principal a single remittance may carry.
+    pub fn get_max_remittance_amount(env: Env) -> i128 {
+        get_max_remittance_amount(&env)
+    }
+
+    /// Sets the smallest principal a single remittance may carry, to stop dust spam.
+    /// Only admins and operators can call this. Defaults to zero (no floor) until set.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Floor successfully updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin or operator
+    /// * `Err(ContractError::InvalidAmount)` - Amount is negative or above the maximum
+    pub fn set_min_remittance_amount(env: Env, caller: Address, amount: i128) -> Result<(), ContractError> {
+        require_operator(&env, &caller)?;
+
+        validate_remittance_amount_bounds(amount, get_max_remittance_amount(&env))?;
+
+        set_min_remittance_amount(&env, amount);
+
+        Ok(())
+    }
+
+    /// Returns the smallest principal a single remittance may carry.
+    pub fn get_min_remittance_amount(env: Env) -> i128 {
+        get_min_remittance_amount(&env)
+    }
     
     pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
         get_last_settlement_time(&env, &sender)
@@ -791,7 +4029,8 @@ impl SwiftRemitContract {
     /// 
     /// # Errors
     /// - ContractPaused: Contract is in paused state
-    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
+    /// - InvalidAmount: Batch is empty
+    /// - BatchTooLarge: Batch size exceeds MAX_BATCH_SIZE
     /// - RemittanceNotFound: One or more remittance IDs don't exist
     /// - InvalidStatus: One or more remittances are not in Pending status
     /// - DuplicateSettlement: Duplicate remittance IDs in batch
@@ -809,9 +4048,13 @@ impl SwiftRemitContract {
         if batch_size == 0 {
             return Err(ContractError::InvalidAmount);
         }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidAmount);
+        ensure_batch_size(batch_size)?;
+
+        // Reject re-entry from a token contract called during an ongoing settlement
+        if is_settlement_locked(&env) {
+            return Err(ContractError::DuplicateSettlement);
         }
+        set_settlement_lock(&env, true);
 
         // Load all remittances and validate
         let mut remittances = Vec::new(&env);
@@ -832,7 +4075,9 @@ impl SwiftRemitContract {
             // Load and validate remittance
             let remittance = get_remittance(&env, remittance_id)?;
 
-            // Verify remittance is pending
+            // Verify remittance is pending, settled by an agent and not held for review
+            validate_not_on_hold(&remittance)?;
+            validate_remittance_mode(&remittance, RemittanceMode::Agent)?;
             if remittance.status != RemittanceStatus::Pending {
                 return Err(ContractError::InvalidStatus);
             }
@@ -842,73 +4087,88 @@ impl SwiftRemitContract {
                 return Err(ContractError::DuplicateSettlement);
             }
 
-            // Check expiry
-            if let Some(expiry_time) = remittance.expiry {
-                let current_time = env.ledger().timestamp();
-                if current_time > expiry_time {
-                    return Err(ContractError::SettlementExpired);
-                }
-            }
+            validate_agent_settlement_not_expired(&env, remittance.expiry)?;
+            validate_settlement_released(&env, remittance.not_before)?;
 
             // Validate addresses
-            validate_address(&remittance.agent)?;
+            validate_address(&remittance.settling_agent())?;
+            validate_agent_not_suspended(&env, &remittance.settling_agent())?;
+
+            // Counted before any transfer; usage accumulates across the batch
+            check_agent_daily_cap(&env, &remittance.settling_agent(), remittance.amount)?;
 
             remittances.push_back(remittance);
         }
 
-        // Compute net settlements
-        let net_transfers = compute_net_settlements(&env, &remittances);
-
-        // Validate net settlement calculations
-        validate_net_settlement(&remittances, &net_transfers)?;
-
-        // Execute net transfers
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-
-        for i in 0..net_transfers.len() {
-            let transfer = net_transfers.get_unchecked(i);
-
-            // Determine actual sender and recipient based on net_amount sign
-            let (from, to, amount) = if transfer.net_amount > 0 {
-                // Positive: party_a -> party_b
-                (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
-            } else if transfer.net_amount < 0 {
-                // Negative: party_b -> party_a
-                (transfer.party_b.clone(), transfer.party_a.clone(), -transfer.net_amount)
-            } else {
-                // Zero: complete offset, no transfer needed
-                continue;
-            };
-
-            // Calculate payout amount (net amount minus fees)
-            let payout_amount = amount
-                .checked_sub(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+        // Net each token separately; flows in different tokens never offset
+        let mut tokens: Vec<Address> = Vec::new(&env);
+        for i in 0..remittances.len() {
+            let token = remittances.get_unchecked(i).token;
+            if !tokens.contains(&token) {
+                tokens.push_back(token);
+            }
+        }
 
-            // Execute the net transfer from contract to recipient
-            // Note: The sender's funds are already in the contract from create_remittance
-            token_client.transfer(
-                &env.current_contract_address(),
-                &to,
-                &payout_amount,
-            );
+        for t in 0..tokens.len() {
+            let batch_token = tokens.get_unchecked(t);
+            let mut token_remittances = Vec::new(&env);
+            for i in 0..remittances.len() {
+                let remittance = remittances.get_unchecked(i);
+                if remittance.token == batch_token {
+                    token_remittances.push_back(remittance);
+                }
+            }
 
-            // Accumulate fees
-            let current_fees = get_accumulated_fees(&env)?;
-            let new_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
-            set_accumulated_fees(&env, new_fees);
-
-            // Emit settlement event (using remittance ID from the transfer)
-            // Note: In batch processing, we use the first remittance ID as reference
-            let remittance_id = if i < remittances.len() {
-                remittances.get_unchecked(i).id
-            } else {
-                0
-            };
-            emit_settlement_completed(&env, remittance_id, from, to, usdc_token.clone(), payout_amount);
+            // Compute net settlements
+            let net_transfers = compute_net_settlements(&env, &token_remittances)?;
+
+            // Validate net settlement calculations
+            validate_net_settlement(&token_remittances, &net_transfers)?;
+
+            // Execute net transfers
+            let token_client = token::Client::new(&env, &batch_token);
+
+            for i in 0..net_transfers.len() {
+                let transfer = net_transfers.get_unchecked(i);
+
+                // Determine actual sender and recipient based on net_amount sign
+                let (from, to, amount) = if transfer.net_amount > 0 {
+                    // Positive: party_a -> party_b
+                    (transfer.party_a.clone(), transfer.party_b.clone(), transfer.net_amount)
+                } else if transfer.net_amount < 0 {
+                    // Negative: party_b -> party_a
+                    (transfer.party_b.clone(), transfer.party_a.clone(), checked_sub_i128(0, transfer.net_amount)?)
+                } else {
+                    // Zero: complete offset, no transfer needed
+                    continue;
+                };
+
+                // Calculate payout amount (net amount minus fees)
+                let payout_amount = checked_sub_i128(amount, transfer.total_fees)?;
+
+                // Execute the net transfer from contract to recipient, at its payout
+                // address when the recipient is an agent that set one
+                // Note: The sender's funds are already in the contract from create_remittance
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &get_agent_payout(&env, &to),
+                    &payout_amount,
+                );
+
+                // Accumulate fees
+                let current_fees = get_accumulated_fees(&env, &batch_token);
+                let new_fees = checked_add_i128(current_fees, transfer.total_fees)?;
+                set_accumulated_fees(&env, &batch_token, new_fees);
+
+                // Emit settlement event (using remittance ID from the transfer)
+                // Note: In batch processing, we use the first remittance ID as reference
+                let remittance_id = if i < token_remittances.len() {
+                    token_remittances.get_unchecked(i).id
+                } else {
+                    0
+                };
+                emit_settlement_completed(&env, remittance_id, from, to, batch_token.clone(), payout_amount);
+            }
         }
 
         // Mark all remittances as completed and set settlement hashes
@@ -916,31 +4176,58 @@ impl SwiftRemitContract {
 
         for i in 0..remittances.len() {
             let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Completed;
+            remittance.remaining = 0;
+            transition(&mut remittance, RemittanceStatus::Completed)?;
             set_remittance(&env, remittance.id, &remittance);
+            record_transition(&env, &remittance, Some(RemittanceStatus::Pending))?;
+            let settler = remittance.settling_agent();
+            record_agent_settlement(&env, &settler, remittance.amount, true)?;
             set_settlement_hash(&env, remittance.id);
             settled_ids.push_back(remittance.id);
+            track_settlement_volume(&env, remittance.amount)?;
+
+            // Settlement fees sit outside the netted principal, so pay them per remittance
+            if remittance.agent_fee > 0 {
+                token::Client::new(&env, &remittance.token).transfer(
+                    &env.current_contract_address(),
+                    &get_agent_payout(&env, &settler),
+                    &remittance.agent_fee,
+                );
+            }
+            if remittance.treasury_fee > 0 {
+                let treasury_balance =
+                    checked_add_i128(get_treasury_balance(&env, &remittance.token), remittance.treasury_fee)?;
+                set_treasury_balance(&env, &remittance.token, treasury_balance);
+            }
+
+            let released = checked_add_i128(
+                checked_add_i128(remittance.amount, remittance.agent_fee)?,
+                remittance.treasury_fee,
+            )?;
+            set_tvl(&env, &remittance.token, checked_sub_i128(get_tvl(&env, &remittance.token), released)?);
 
             // Emit individual remittance completion event
-            let payout_amount = remittance
-                .amount
-                .checked_sub(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
+            let payout_amount = checked_sub_i128(remittance.amount, remittance.fee)?;
             emit_remittance_completed(
                 &env,
                 remittance.id,
-                remittance.agent.clone(),
+                &remittance.recipient,
+                &remittance.token,
+                settler,
                 payout_amount,
             );
+            notify_settlement_hook(&env, remittance.id, &remittance.recipient, payout_amount);
         }
 
+        set_settlement_lock(&env, false);
+
         Ok(BatchSettlementResult { settled_ids })
     }
 
-    /// Add a token to the whitelist. Only admins can call this.
+    /// Add a token to the whitelist. Only admins and operators can call this.
     pub fn whitelist_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
         // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+        validate_operator_operation(&env, &caller, &token)?;
 
         if is_token_whitelisted(&env, &token) {
             return Err(ContractError::TokenAlreadyWhitelisted);
@@ -953,10 +4240,43 @@ impl SwiftRemitContract {
         Ok(())
     }
 
-    /// Remove a token from the whitelist. Only admins can call this.
+    /// Add several tokens to the whitelist in one call. Only admins and operators can call this.
+    ///
+    /// Tokens that are already whitelisted are skipped rather than rejected. Every
+    /// address is validated before any change is made, so an invalid entry (the
+    /// contract itself, or a token listed twice) fails the whole batch with
+    /// `InvalidAddress` and no token is added.
+    ///
+    /// Returns the number of newly whitelisted tokens, or `BatchTooLarge` if more
+    /// than `MAX_BATCH_SIZE` tokens are listed.
+    pub fn whitelist_tokens(env: Env, caller: Address, tokens: Vec<Address>) -> Result<u32, ContractError> {
+        ensure_batch_size(tokens.len())?;
+        validate_address(&caller)?;
+        validate_batch_addresses(&env, &tokens)?;
+        validate_no_duplicate_addresses(&tokens)?;
+        require_operator(&env, &caller)?;
+
+        let mut added: u32 = 0;
+        for token in tokens.iter() {
+            if is_token_whitelisted(&env, &token) {
+                continue;
+            }
+
+            set_token_whitelisted(&env, &token, true);
+            log_whitelist_token(&env, &token);
+            added += 1;
+        }
+
+        Ok(added)
+    }
+
+    /// Remove a token from the whitelist. Only admins and operators can call this.
+    ///
+    /// Only new remittances are blocked; remittances already created in the token
+    /// can still be settled, cancelled and refunded.
     pub fn remove_whitelisted_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
         // Centralized validation
-        validate_admin_operation(&env, &caller, &token)?;
+        validate_operator_operation(&env, &caller, &token)?;
 
         if !is_token_whitelisted(&env, &token) {
             return Err(ContractError::TokenNotWhitelisted);
@@ -969,15 +4289,60 @@ impl SwiftRemitContract {
         Ok(())
     }
 
+    /// De-list a token, grandfathering in-flight remittances.
+    ///
+    /// Equivalent to `remove_whitelisted_token`.
+    pub fn remove_token(env: Env, caller: Address, token: Address) -> Result<(), ContractError> {
+        Self::remove_whitelisted_token(env, caller, token)
+    }
+
     /// Check if a token is whitelisted.
     pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
         is_token_whitelisted(&env, &token)
     }
 
-    /// Update rate limit configuration. Only admins can call this.
+    /// Lists every whitelisted token, in the order they were whitelisted.
+    ///
+    /// Tokens removed with `remove_whitelisted_token` drop out of the list.
+    pub fn list_whitelisted_tokens(env: Env) -> Vec<Address> {
+        get_whitelisted_tokens(&env)
+    }
+
+    /// Turns the token whitelist requirement for new remittances on or off.
+    ///
+    /// Meant for test deployments that remit in mock tokens. While disabled,
+    /// creating a remittance or setting a preferred token accepts any token;
+    /// the whitelist itself and its management calls keep working, and apply
+    /// again as soon as the requirement is re-enabled. Existing remittances in
+    /// non-whitelisted tokens can still be settled, cancelled and refunded.
+    /// Enabled by default.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Requirement updated
+    /// * `Err(ContractError::Unauthorized)` - Caller is not an admin
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the caller, who must be an admin.
+    pub fn set_require_token_whitelist(env: Env, caller: Address, enabled: bool) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        set_require_token_whitelist(&env, enabled);
+        emit_token_whitelist_required(&env, caller, enabled);
+
+        Ok(())
+    }
+
+    /// Checks whether new remittances must use a whitelisted token.
+    pub fn is_token_whitelist_required(env: Env) -> bool {
+        is_token_whitelist_required(&env)
+    }
+
+    /// Update rate limit configuration. Only admins and operators can call this.
     /// 
     /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
+    /// - `caller`: Admin or operator address (must be authorized)
     /// - `max_requests`: Maximum number of requests allowed per window
     /// - `window_seconds`: Time window in seconds
     /// - `enabled`: Whether rate limiting is enabled
@@ -994,7 +4359,7 @@ impl SwiftRemitContract {
         window_seconds: u64,
         enabled: bool,
     ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
+        require_operator(&env, &caller)?;
 
         let config = RateLimitConfig {
             max_requests,
@@ -1116,7 +4481,7 @@ impl SwiftRemitContract {
     ///
     /// * `Ok(())` - Verification data stored successfully
     /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::InvalidReputationScore)` - Score not in 0-100 range
+    /// * `Err(ContractError::InvalidAmount)` - Score not in 0-100 range
     ///
     /// # Authorization
     ///
@@ -1134,7 +4499,7 @@ impl SwiftRemitContract {
         admin.require_auth();
 
         if reputation_score > 100 {
-            return Err(ContractError::InvalidReputationScore);
+            return Err(ContractError::InvalidAmount);
         }
 
         let verification = AssetVerification {
@@ -1163,7 +4528,7 @@ impl SwiftRemitContract {
     /// # Returns
     ///
     /// * `Ok(AssetVerification)` - The verification record
-    /// * `Err(ContractError::AssetNotFound)` - Asset not found in verification database
+    /// * `Err(ContractError::KeyNotFound)` - Asset not found in verification database
     pub fn get_asset_verification(
         env: Env,
         asset_code: String,
@@ -1202,8 +4567,8 @@ impl SwiftRemitContract {
     /// # Returns
     ///
     /// * `Ok(())` - Asset is safe to use
-    /// * `Err(ContractError::SuspiciousAsset)` - Asset is flagged as suspicious
-    /// * `Err(ContractError::AssetNotFound)` - Asset not in verification database
+    /// * `Err(ContractError::Unauthorized)` - Asset is flagged as suspicious
+    /// * `Err(ContractError::KeyNotFound)` - Asset not in verification database
     pub fn validate_asset_safety(
         env: Env,
         asset_code: String,
@@ -1212,7 +4577,7 @@ impl SwiftRemitContract {
         let verification = get_asset_verification(&env, &asset_code, &issuer)?;
         
         if verification.status == VerificationStatus::Suspicious {
-            return Err(ContractError::SuspiciousAsset);
+            return Err(ContractError::Unauthorized);
         }
 
         Ok(())
@@ -0,0 +1,129 @@
+use crate::{ContractError, FeeRounding};
+
+/// Basis points in one whole (100%)
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Add two amounts, mapping overflow to `ContractError::Overflow`
+pub fn checked_add_i128(a: i128, b: i128) -> Result<i128, ContractError> {
+    a.checked_add(b).ok_or(ContractError::Overflow)
+}
+
+/// Subtract `b` from `a`, mapping overflow to `ContractError::Overflow`
+pub fn checked_sub_i128(a: i128, b: i128) -> Result<i128, ContractError> {
+    a.checked_sub(b).ok_or(ContractError::Overflow)
+}
+
+/// Portion of `amount` given by `bps` basis points, rounded as `rounding` directs
+///
+/// `Down` truncates toward zero, `Up` rounds away from zero on any remainder and
+/// `Nearest` rounds away from zero from half a unit.
+pub fn checked_mul_bps(amount: i128, bps: u32, rounding: FeeRounding) -> Result<i128, ContractError> {
+    let product = amount
+        .checked_mul(bps as i128)
+        .ok_or(ContractError::Overflow)?;
+    let quotient = product / BPS_DENOMINATOR;
+    let remainder = product % BPS_DENOMINATOR;
+
+    let round_away = match rounding {
+        FeeRounding::Down => false,
+        FeeRounding::Up => remainder != 0,
+        FeeRounding::Nearest => remainder.abs() * 2 >= BPS_DENOMINATOR,
+    };
+    if round_away {
+        checked_add_i128(quotient, product.signum())
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// Compute `a * b / c`, rounded toward zero
+///
+/// Fails with `ContractError::Overflow` on overflow or when `c` is zero.
+pub fn checked_mul_div_i128(a: i128, b: i128, c: i128) -> Result<i128, ContractError> {
+    a.checked_mul(b)
+        .ok_or(ContractError::Overflow)?
+        .checked_div(c)
+        .ok_or(ContractError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXTREMES: [i128; 9] = [
+        i128::MIN,
+        i128::MIN + 1,
+        -10_000,
+        -1,
+        0,
+        1,
+        10_000,
+        i128::MAX - 1,
+        i128::MAX,
+    ];
+
+    const BPS: [u32; 6] = [0, 1, 250, 9_999, 10_000, u32::MAX];
+
+    fn ok_or_overflow(result: Result<i128, ContractError>) {
+        if let Err(err) = result {
+            assert_eq!(err, ContractError::Overflow);
+        }
+    }
+
+    #[test]
+    fn test_extreme_values_only_ever_overflow() {
+        for a in EXTREMES {
+            for b in EXTREMES {
+                ok_or_overflow(checked_add_i128(a, b));
+                ok_or_overflow(checked_sub_i128(a, b));
+                for c in EXTREMES {
+                    ok_or_overflow(checked_mul_div_i128(a, b, c));
+                }
+            }
+            for bps in BPS {
+                ok_or_overflow(checked_mul_bps(a, bps, FeeRounding::Down));
+                ok_or_overflow(checked_mul_bps(a, bps, FeeRounding::Up));
+                ok_or_overflow(checked_mul_bps(a, bps, FeeRounding::Nearest));
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_bps() {
+        assert_eq!(checked_mul_bps(1_000, 250, FeeRounding::Down), Ok(25));
+        assert_eq!(checked_mul_bps(1_000, 10_000, FeeRounding::Down), Ok(1_000));
+        assert_eq!(checked_mul_bps(39, 250, FeeRounding::Down), Ok(0));
+        assert_eq!(checked_mul_bps(i128::MAX, 10_000, FeeRounding::Down), Err(ContractError::Overflow));
+    }
+
+    #[test]
+    fn test_checked_mul_bps_rounding_modes() {
+        // 1_019 * 250 / 10_000 = 25.475
+        assert_eq!(checked_mul_bps(1_019, 250, FeeRounding::Down), Ok(25));
+        assert_eq!(checked_mul_bps(1_019, 250, FeeRounding::Up), Ok(26));
+        assert_eq!(checked_mul_bps(1_019, 250, FeeRounding::Nearest), Ok(25));
+
+        // 1_020 * 250 / 10_000 = 25.5, a tie
+        assert_eq!(checked_mul_bps(1_020, 250, FeeRounding::Down), Ok(25));
+        assert_eq!(checked_mul_bps(1_020, 250, FeeRounding::Up), Ok(26));
+        assert_eq!(checked_mul_bps(1_020, 250, FeeRounding::Nearest), Ok(26));
+
+        // 1_021 * 250 / 10_000 = 25.525
+        assert_eq!(checked_mul_bps(1_021, 250, FeeRounding::Nearest), Ok(26));
+
+        // Exact divisions are unaffected
+        for rounding in [FeeRounding::Down, FeeRounding::Up, FeeRounding::Nearest] {
+            assert_eq!(checked_mul_bps(1_000, 250, rounding), Ok(25));
+            assert_eq!(checked_mul_bps(0, 250, rounding), Ok(0));
+        }
+    }
+
+    #[test]
+    fn test_checked_add_sub_boundaries() {
+        assert_eq!(checked_add_i128(i128::MAX, 0), Ok(i128::MAX));
+        assert_eq!(checked_add_i128(i128::MAX, 1), Err(ContractError::Overflow));
+        assert_eq!(checked_sub_i128(i128::MIN, 1), Err(ContractError::Overflow));
+        assert_eq!(checked_sub_i128(0, i128::MIN), Err(ContractError::Overflow));
+        assert_eq!(checked_mul_div_i128(1, 1, 0), Err(ContractError::Overflow));
+    }
+}
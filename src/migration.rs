@@ -1,11 +1,80 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec, xdr::ToXdr};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, TryFromVal, Val, Vec, xdr::ToXdr};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::storage::DataKey;
+use crate::{ContractError, Remittance, RemittanceMode, RemittanceStatus};
 
 /// Maximum number of items that can be exported/imported in a single batch
 /// to prevent excessive resource consumption
 pub const MAX_MIGRATION_BATCH_SIZE: u32 = 100;
 
+/// Schema version recorded when the contract is initialized
+pub const INITIAL_CONTRACT_VERSION: u32 = 1;
+
+/// Storage layout of contracts deployed before the layout version was recorded,
+/// where each module kept its own key enum
+pub const LEGACY_DATA_VERSION: u32 = 1;
+
+/// Storage layout written by this build, where every key is a `DataKey` variant
+pub const CURRENT_DATA_VERSION: u32 = 2;
+
+/// Keys written under the legacy layout.
+///
+/// A `contracttype` enum key encodes as its variant name plus fields, so these
+/// variants reproduce the keys of the former `DailyLimitKey`, `RateLimitKey` and
+/// `CircuitBreakerKey` enums exactly. They are read only while the contract is
+/// on `LEGACY_DATA_VERSION` and are never written.
+#[contracttype]
+#[derive(Clone)]
+pub(crate) enum LegacyDataKey {
+    /// Fallback daily send limit (instance storage)
+    Default,
+    /// Per-token daily send limit (persistent storage)
+    Token(Address),
+    /// Amount sent by (user, token, day) (persistent storage)
+    Usage(Address, Address, u64),
+    /// Agent settlement cap (persistent storage)
+    AgentCap(Address),
+    /// Principal settled by (agent, day) (persistent storage)
+    AgentUsage(Address, u64),
+    /// Rate limit configuration (instance storage)
+    Config,
+    /// Rate limit window by address (temporary storage)
+    Entry(Address),
+    /// Circuit breaker configuration (instance storage)
+    BreakerConfig,
+    /// Circuit breaker volume (instance storage)
+    BreakerVolume,
+}
+
+/// Whether the contract still has to be migrated off the legacy layout
+pub(crate) fn is_legacy_layout(env: &Env) -> bool {
+    crate::storage::get_data_version(env) < CURRENT_DATA_VERSION
+}
+
+/// Read a legacy instance entry, or None once the layout has been migrated
+pub(crate) fn legacy_instance<V: TryFromVal<Env, Val>>(env: &Env, key: &LegacyDataKey) -> Option<V> {
+    if !is_legacy_layout(env) {
+        return None;
+    }
+    env.storage().instance().get(key)
+}
+
+/// Read a legacy persistent entry, or None once the layout has been migrated
+pub(crate) fn legacy_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &LegacyDataKey) -> Option<V> {
+    if !is_legacy_layout(env) {
+        return None;
+    }
+    env.storage().persistent().get(key)
+}
+
+/// Read a legacy temporary entry, or None once the layout has been migrated
+pub(crate) fn legacy_temporary<V: TryFromVal<Env, Val>>(env: &Env, key: &LegacyDataKey) -> Option<V> {
+    if !is_legacy_layout(env) {
+        return None;
+    }
+    env.storage().temporary().get(key)
+}
+
 /// Migration state snapshot containing all contract data
 /// This structure ensures complete and verifiable state transfer
 #[contracttype]
@@ -46,7 +115,7 @@ pub struct InstanceData {
     /// Global remittance counter
     pub remittance_counter: u64,
     
-    /// Accumulated platform fees
+    /// Accumulated platform fees in the USDC token
     pub accumulated_fees: i128,
     
     /// Contract pause status
@@ -126,12 +195,13 @@ pub struct MigrationVerification {
 /// MigrationSnapshot containing all contract state
 pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     // Collect instance data
+    let usdc_token = crate::storage::get_usdc_token(env)?;
     let instance_data = InstanceData {
         admin: crate::storage::get_admin(env)?,
-        usdc_token: crate::storage::get_usdc_token(env)?,
+        usdc_token: usdc_token.clone(),
         platform_fee_bps: crate::storage::get_platform_fee_bps(env)?,
         remittance_counter: crate::storage::get_remittance_counter(env)?,
-        accumulated_fees: crate::storage::get_accumulated_fees(env)?,
+        accumulated_fees: crate::storage::get_accumulated_fees(env, &usdc_token),
         paused: crate::storage::is_paused(env),
         admin_count: crate::storage::get_admin_count(env),
     };
@@ -187,7 +257,7 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
     );
     
     Ok(MigrationSnapshot {
-        version: 1,
+        version: crate::storage::get_contract_version(env),
         timestamp,
         ledger_sequence,
         instance_data,
@@ -223,6 +293,11 @@ pub fn import_state(
     if crate::storage::has_admin(env) {
         return Err(ContractError::AlreadyInitialized);
     }
+
+    // A full import would clobber a batched import that is part way through
+    if crate::storage::is_migration_in_progress(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
     
     // Verify snapshot hash
     let computed_hash = compute_snapshot_hash(
@@ -242,9 +317,14 @@ pub fn import_state(
     crate::storage::set_usdc_token(env, &snapshot.instance_data.usdc_token);
     crate::storage::set_platform_fee_bps(env, snapshot.instance_data.platform_fee_bps);
     crate::storage::set_remittance_counter(env, snapshot.instance_data.remittance_counter);
-    crate::storage::set_accumulated_fees(env, snapshot.instance_data.accumulated_fees);
+    crate::storage::set_accumulated_fees(
+        env,
+        &snapshot.instance_data.usdc_token,
+        snapshot.instance_data.accumulated_fees,
+    );
     crate::storage::set_paused(env, snapshot.instance_data.paused);
     crate::storage::set_admin_count(env, snapshot.instance_data.admin_count);
+    crate::storage::set_contract_version(env, snapshot.version);
     
     // Import persistent data
     
@@ -322,19 +402,46 @@ fn compute_snapshot_hash(
         data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
         data.append(&r.sender.clone().to_xdr(env));
         data.append(&r.agent.clone().to_xdr(env));
+        data.append(&r.recipient.clone().to_xdr(env));
+        if let Some(assigned_agent) = r.assigned_agent.clone() {
+            data.append(&assigned_agent.to_xdr(env));
+        }
+        data.append(&r.token.clone().to_xdr(env));
         data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
         data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
+        data.append(&Bytes::from_array(env, &r.remaining.to_be_bytes()));
         
         let status_byte = match r.status {
             RemittanceStatus::Pending => 0u8,
             RemittanceStatus::Completed => 1u8,
             RemittanceStatus::Cancelled => 2u8,
+            RemittanceStatus::PartiallySettled => 3u8,
+            RemittanceStatus::Refunded => 4u8,
+            RemittanceStatus::Expired => 5u8,
+            RemittanceStatus::Reclaimed => 6u8,
         };
         data.append(&Bytes::from_array(env, &[status_byte]));
+
+        let mode_byte = match r.mode {
+            RemittanceMode::Agent => 0u8,
+            RemittanceMode::Claim => 1u8,
+            RemittanceMode::Split => 2u8,
+        };
+        data.append(&Bytes::from_array(env, &[mode_byte]));
         
         if let Some(expiry) = r.expiry {
             data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
         }
+
+        if let Some(not_before) = r.not_before {
+            data.append(&Bytes::from_array(env, &not_before.to_be_bytes()));
+        }
+
+        data.append(&Bytes::from_array(env, &[r.on_hold as u8]));
+
+        if let Some(memo) = r.memo.clone() {
+            data.append(&memo);
+        }
     }
     
     // Agents
@@ -449,6 +556,44 @@ pub fn export_batch(
     })
 }
 
+/// Validate a batch against the current import sequence
+/// 
+/// Performs every check `import_batch` makes before writing, so a batch
+/// that passes here will import successfully from the current state.
+/// 
+/// # Parameters
+/// - `batch`: Batch to validate
+/// - `expected_hash`: Hash the batch must carry and recompute to
+/// 
+/// # Returns
+/// Ok(()) if the batch would import, or the error the import would return
+pub fn validate_batch(
+    env: &Env,
+    batch: &MigrationBatch,
+    expected_hash: &BytesN<32>,
+) -> Result<(), ContractError> {
+    // Verify batch bounds
+    if batch.batch_number >= batch.total_batches
+        || batch.remittances.len() > MAX_MIGRATION_BATCH_SIZE
+    {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+    
+    // Verify batch ordering
+    if batch.batch_number != crate::storage::get_migration_next_batch(env) {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+    
+    // Verify batch hash
+    let computed_hash = compute_batch_hash(env, &batch.remittances, batch.batch_number);
+    
+    if batch.batch_hash != *expected_hash || computed_hash != *expected_hash {
+        return Err(ContractError::InvalidMigrationHash);
+    }
+    
+    Ok(())
+}
+
 /// Import state from batch
 /// 
 /// Import a single batch of remittances. Must be called in order
@@ -463,22 +608,168 @@ pub fn import_batch(
     env: &Env,
     batch: MigrationBatch,
 ) -> Result<(), ContractError> {
-    // Verify batch hash
-    let computed_hash = compute_batch_hash(env, &batch.remittances, batch.batch_number);
-    
-    if computed_hash != batch.batch_hash {
-        return Err(ContractError::InvalidMigrationHash);
-    }
+    validate_batch(env, &batch, &batch.batch_hash)?;
     
     // Import remittances
     for i in 0..batch.remittances.len() {
         let remittance = batch.remittances.get_unchecked(i);
+        let previous = crate::storage::get_remittance(env, remittance.id).ok();
         crate::storage::set_remittance(env, remittance.id, &remittance);
+        crate::storage::reindex_remittance(env, previous.as_ref(), &remittance);
+    }
+    
+    // Advance the sequence, resetting it once the final batch lands
+    let next_batch = batch.batch_number + 1;
+    if next_batch == batch.total_batches {
+        crate::storage::set_migration_next_batch(env, 0);
+        crate::storage::set_migration_in_progress(env, false);
+    } else {
+        crate::storage::set_migration_next_batch(env, next_batch);
+        crate::storage::set_migration_in_progress(env, true);
     }
     
     Ok(())
 }
 
+/// Abandon a batched import part way through
+///
+/// Clears the in-progress flag so the contract is no longer blocked from other
+/// migration steps. Batches that already imported stay imported: the batch
+/// cursor only advances after a batch commits, so it is left pointing at the
+/// first batch that has not been applied and a restarted import resumes there.
+///
+/// # Returns
+/// The batch number the next import will accept
+pub fn abort_import(env: &Env) -> u32 {
+    crate::storage::set_migration_in_progress(env, false);
+    crate::storage::get_migration_next_batch(env)
+}
+
+/// Advance the live schema version by one step
+/// 
+/// Migrations must be applied one version at a time, so any target other
+/// than the next version is rejected. This prevents both skipping a step
+/// and replaying one that already ran.
+/// 
+/// # Parameters
+/// - `target_version`: Version being migrated to
+/// 
+/// # Returns
+/// The version migrated from, or `InvalidMigrationBatch` if `target_version`
+/// is not exactly one above the live version
+pub fn advance_contract_version(
+    env: &Env,
+    target_version: u32,
+) -> Result<u32, ContractError> {
+    // Bumping the version mid-import would mix data from two schemas
+    if crate::storage::is_migration_in_progress(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
+    
+    let current_version = crate::storage::get_contract_version(env);
+    if current_version.checked_add(1) != Some(target_version) {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+    
+    crate::storage::set_contract_version(env, target_version);
+    
+    Ok(current_version)
+}
+
+/// Advance the storage layout version by one step, rewriting legacy keys
+///
+/// Instance entries are moved unconditionally. Persistent entries cannot be
+/// enumerated on-chain, so the caller lists the tokens whose daily limits and
+/// the agents whose caps and current-day usage should move. Values already
+/// written under the new layout are newer than their legacy copies and are
+/// kept. Sender usage and rate limit windows are not moved; they restart under
+/// the new layout.
+///
+/// # Parameters
+/// - `target_version`: Layout version being migrated to
+/// - `tokens`: Tokens with an explicit daily limit under the legacy layout
+/// - `agents`: Agents with a cap or usage under the legacy layout
+///
+/// # Returns
+/// The version migrated from, `MigrationInProgress` if a batched import is
+/// underway, or `InvalidMigrationBatch` if `target_version` is not the next
+/// known layout or more than `MAX_MIGRATION_BATCH_SIZE` entries are listed
+pub fn advance_data_version(
+    env: &Env,
+    target_version: u32,
+    tokens: &Vec<Address>,
+    agents: &Vec<Address>,
+) -> Result<u32, ContractError> {
+    if crate::storage::is_migration_in_progress(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
+
+    let current_version = crate::storage::get_data_version(env);
+    if current_version.checked_add(1) != Some(target_version)
+        || target_version > CURRENT_DATA_VERSION
+    {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+    if tokens.len().saturating_add(agents.len()) > MAX_MIGRATION_BATCH_SIZE {
+        return Err(ContractError::InvalidMigrationBatch);
+    }
+
+    move_instance_entry(env, &LegacyDataKey::Default, &DataKey::DefaultDailyLimit);
+    move_instance_entry(env, &LegacyDataKey::Config, &DataKey::RateLimitConfig);
+    move_instance_entry(env, &LegacyDataKey::BreakerConfig, &DataKey::CircuitBreakerConfig);
+    move_instance_entry(env, &LegacyDataKey::BreakerVolume, &DataKey::CircuitBreakerVolume);
+
+    let day = crate::day_bucket(env.ledger().timestamp());
+    for token in tokens.iter() {
+        move_persistent_entry(
+            env,
+            &LegacyDataKey::Token(token.clone()),
+            &DataKey::TokenDailyLimit(token),
+        );
+    }
+    for agent in agents.iter() {
+        move_persistent_entry(
+            env,
+            &LegacyDataKey::AgentCap(agent.clone()),
+            &DataKey::AgentDailyCap(agent.clone()),
+        );
+        // Usage now lives in temporary storage, so it is rewritten rather than moved
+        let legacy_usage = LegacyDataKey::AgentUsage(agent.clone(), day);
+        if let Some(used) = env.storage().persistent().get::<_, i128>(&legacy_usage) {
+            if !env.storage().temporary().has(&DataKey::AgentDailyUsage(agent.clone(), day)) {
+                crate::daily_limit::store_agent_usage(env, &agent, day, used);
+            }
+            env.storage().persistent().remove(&legacy_usage);
+        }
+    }
+
+    crate::storage::set_data_version(env, target_version);
+
+    Ok(current_version)
+}
+
+/// Move a legacy instance entry to its new key unless the new key is already set
+fn move_instance_entry(env: &Env, legacy: &LegacyDataKey, key: &DataKey) {
+    let storage = env.storage().instance();
+    if let Some(value) = storage.get::<_, Val>(legacy) {
+        if !storage.has(key) {
+            storage.set(key, &value);
+        }
+        storage.remove(legacy);
+    }
+}
+
+/// Move a legacy persistent entry to its new key unless the new key is already set
+fn move_persistent_entry(env: &Env, legacy: &LegacyDataKey, key: &DataKey) {
+    let storage = env.storage().persistent();
+    if let Some(value) = storage.get::<_, Val>(legacy) {
+        if !storage.has(key) {
+            storage.set(key, &value);
+        }
+        storage.remove(legacy);
+    }
+}
+
 /// Compute hash of a batch for verification
 fn compute_batch_hash(
     env: &Env,
@@ -496,19 +787,46 @@ fn compute_batch_hash(
         data.append(&Bytes::from_array(env, &r.id.to_be_bytes()));
         data.append(&r.sender.clone().to_xdr(env));
         data.append(&r.agent.clone().to_xdr(env));
+        data.append(&r.recipient.clone().to_xdr(env));
+        if let Some(assigned_agent) = r.assigned_agent.clone() {
+            data.append(&assigned_agent.to_xdr(env));
+        }
+        data.append(&r.token.clone().to_xdr(env));
         data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
         data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
+        data.append(&Bytes::from_array(env, &r.remaining.to_be_bytes()));
         
         let status_byte = match r.status {
             RemittanceStatus::Pending => 0u8,
             RemittanceStatus::Completed => 1u8,
             RemittanceStatus::Cancelled => 2u8,
+            RemittanceStatus::PartiallySettled => 3u8,
+            RemittanceStatus::Refunded => 4u8,
+            RemittanceStatus::Expired => 5u8,
+            RemittanceStatus::Reclaimed => 6u8,
         };
         data.append(&Bytes::from_array(env, &[status_byte]));
+
+        let mode_byte = match r.mode {
+            RemittanceMode::Agent => 0u8,
+            RemittanceMode::Claim => 1u8,
+            RemittanceMode::Split => 2u8,
+        };
+        data.append(&Bytes::from_array(env, &[mode_byte]));
         
         if let Some(expiry) = r.expiry {
             data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
         }
+
+        if let Some(not_before) = r.not_before {
+            data.append(&Bytes::from_array(env, &not_before.to_be_bytes()));
+        }
+
+        data.append(&Bytes::from_array(env, &[r.on_hold as u8]));
+
+        if let Some(memo) = r.memo.clone() {
+            data.append(&memo);
+        }
     }
     
     env.crypto().sha256(&data).into()
@@ -1,5 +1,6 @@
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
+use crate::math::{checked_add_i128, checked_sub_i128};
 use crate::{ContractError, Remittance, RemittanceStatus};
 
 /// Represents a net transfer between two parties after offsetting opposing flows.
@@ -53,8 +54,12 @@ struct DirectionalFlow {
 /// - `remittances`: Vector of remittances to net
 ///
 /// # Returns
-/// Vector of NetTransfer structs representing the minimal set of transfers needed
-pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<NetTransfer> {
+/// Vector of NetTransfer structs representing the minimal set of transfers needed,
+/// or `ContractError::Overflow` if any party pair's running totals overflow
+pub fn compute_net_settlements(
+    env: &Env,
+    remittances: &Vec<Remittance>,
+) -> Result<Vec<NetTransfer>, ContractError> {
     let mut flows: Vec<DirectionalFlow> = Vec::new(env);
     
     // Extract all directional flows from remittances
@@ -87,8 +92,12 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
         // Apply the flow in the normalized direction
         // direction = 1 means flow is A -> B (add to net)
         // direction = -1 means flow is B -> A (subtract from net)
-        let new_net = current_net + (flow.amount * direction);
-        let new_fees = current_fees + flow.fee;
+        let new_net = if direction > 0 {
+            checked_add_i128(current_net, flow.amount)?
+        } else {
+            checked_sub_i128(current_net, flow.amount)?
+        };
+        let new_fees = checked_add_i128(current_fees, flow.fee)?;
 
         net_map.set(key, (new_net, new_fees));
     }
@@ -118,7 +127,7 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Vec<
         }
     }
 
-    result
+    Ok(result)
 }
 
 /// Normalizes a pair of addresses to ensure deterministic ordering.
@@ -177,12 +186,8 @@ pub fn validate_net_settlement(
     for i in 0..original_remittances.len() {
         let remittance = original_remittances.get_unchecked(i);
         if remittance.status == RemittanceStatus::Pending {
-            total_original_amount = total_original_amount
-                .checked_add(remittance.amount)
-                .ok_or(ContractError::Overflow)?;
-            total_original_fees = total_original_fees
-                .checked_add(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
+            total_original_amount = checked_add_i128(total_original_amount, remittance.amount)?;
+            total_original_fees = checked_add_i128(total_original_fees, remittance.fee)?;
         }
     }
 
@@ -194,17 +199,13 @@ pub fn validate_net_settlement(
         let transfer = net_transfers.get_unchecked(i);
         // Use absolute value since net_amount can be negative
         let abs_amount = if transfer.net_amount < 0 {
-            -transfer.net_amount
+            checked_sub_i128(0, transfer.net_amount)?
         } else {
             transfer.net_amount
         };
 
-        total_net_amount = total_net_amount
-            .checked_add(abs_amount)
-            .ok_or(ContractError::Overflow)?;
-        total_net_fees = total_net_fees
-            .checked_add(transfer.total_fees)
-            .ok_or(ContractError::Overflow)?;
+        total_net_amount = checked_add_i128(total_net_amount, abs_amount)?;
+        total_net_fees = checked_add_i128(total_net_fees, transfer.total_fees)?;
     }
 
     // Verify fees are preserved exactly
@@ -222,11 +223,13 @@ pub fn validate_net_settlement(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RemittanceMode;
     use soroban_sdk::{testutils::Address as _, Env};
 
     #[test]
     fn test_simple_netting() {
         let env = Env::default();
+        let token = Address::generate(&env);
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
 
@@ -237,10 +240,22 @@ mod tests {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         // B -> A: 90
@@ -248,13 +263,25 @@ mod tests {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 90,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 90,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances).unwrap();
 
         assert_eq!(net_transfers.len(), 1);
         let transfer = net_transfers.get_unchecked(0);
@@ -273,6 +300,7 @@ mod tests {
     #[test]
     fn test_complete_offset() {
         let env = Env::default();
+        let token = Address::generate(&env);
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
 
@@ -283,10 +311,22 @@ mod tests {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         // B -> A: 100
@@ -294,13 +334,25 @@ mod tests {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances).unwrap();
 
         // Complete offset should result in no transfers
         assert_eq!(net_transfers.len(), 0);
@@ -309,6 +361,7 @@ mod tests {
     #[test]
     fn test_multiple_parties() {
         let env = Env::default();
+        let token = Address::generate(&env);
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
         let addr_c = Address::generate(&env);
@@ -320,10 +373,22 @@ mod tests {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         // B -> C: 50
@@ -331,10 +396,22 @@ mod tests {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_c.clone(),
+            original_recipient: addr_c.clone(),
+            recipient: addr_c.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 50,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 50,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         // C -> A: 30
@@ -342,13 +419,25 @@ mod tests {
             id: 3,
             sender: addr_c.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 30,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 30,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances).unwrap();
 
         // Should have 3 net transfers (one for each pair)
         assert_eq!(net_transfers.len(), 3);
@@ -364,6 +453,7 @@ mod tests {
     #[test]
     fn test_validation_success() {
         let env = Env::default();
+        let token = Address::generate(&env);
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
 
@@ -373,23 +463,47 @@ mod tests {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         remittances.push_back(Remittance {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 90,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 90,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
-        let net_transfers = compute_net_settlements(&remittances);
+        let net_transfers = compute_net_settlements(&env, &remittances).unwrap();
 
         assert!(validate_net_settlement(&remittances, &net_transfers).is_ok());
     }
@@ -397,6 +511,7 @@ mod tests {
     #[test]
     fn test_order_independence() {
         let env = Env::default();
+        let token = Address::generate(&env);
         let addr_a = Address::generate(&env);
         let addr_b = Address::generate(&env);
 
@@ -406,19 +521,43 @@ mod tests {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
         remittances1.push_back(Remittance {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 90,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 90,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
         // Second ordering (reversed)
@@ -427,23 +566,47 @@ mod tests {
             id: 2,
             sender: addr_b.clone(),
             agent: addr_a.clone(),
+            original_recipient: addr_a.clone(),
+            recipient: addr_a.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 90,
             fee: 1,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 90,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
         remittances2.push_back(Remittance {
             id: 1,
             sender: addr_a.clone(),
             agent: addr_b.clone(),
+            original_recipient: addr_b.clone(),
+            recipient: addr_b.clone(),
+            assigned_agent: None,
+            token: token.clone(),
             amount: 100,
             fee: 2,
+            treasury_fee: 0,
+            agent_fee: 0,
+            remaining: 100,
             status: RemittanceStatus::Pending,
             expiry: None,
+            not_before: None,
+            on_hold: false,
+            created_at: 0,
+            memo: None,
+            mode: RemittanceMode::Agent,
         });
 
-        let net1 = compute_net_settlements(&remittances1);
-        let net2 = compute_net_settlements(&remittances2);
+        let net1 = compute_net_settlements(&env, &remittances1).unwrap();
+        let net2 = compute_net_settlements(&env, &remittances2).unwrap();
 
         // Results should be identical regardless of input order
         assert_eq!(net1.len(), net2.len());
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env};
 
+use crate::migration::{legacy_instance, legacy_temporary, LegacyDataKey};
+use crate::storage::DataKey;
 use crate::ContractError;
 
 /// Rate limit configuration stored in instance storage
@@ -24,15 +26,6 @@ struct RateLimitEntry {
     window_start: u64,
 }
 
-#[contracttype]
-#[derive(Clone)]
-enum RateLimitKey {
-    /// Global rate limit configuration
-    Config,
-    /// Per-address rate limit tracking
-    Entry(Address),
-}
-
 /// Initialize rate limiting with default configuration
 pub fn init_rate_limit(env: &Env) {
     let config = RateLimitConfig {
@@ -42,14 +35,15 @@ pub fn init_rate_limit(env: &Env) {
     };
     env.storage()
         .instance()
-        .set(&RateLimitKey::Config, &config);
+        .set(&DataKey::RateLimitConfig, &config);
 }
 
 /// Get current rate limit configuration
 pub fn get_rate_limit_config(env: &Env) -> RateLimitConfig {
     env.storage()
         .instance()
-        .get(&RateLimitKey::Config)
+        .get(&DataKey::RateLimitConfig)
+        .or_else(|| legacy_instance(env, &LegacyDataKey::Config))
         .unwrap_or(RateLimitConfig {
             max_requests: 100,
             window_seconds: 60,
@@ -61,7 +55,7 @@ pub fn get_rate_limit_config(env: &Env) -> RateLimitConfig {
 pub fn set_rate_limit_config(env: &Env, config: RateLimitConfig) {
     env.storage()
         .instance()
-        .set(&RateLimitKey::Config, &config);
+        .set(&DataKey::RateLimitConfig, &config);
 }
 
 /// Check and update rate limit for an address
@@ -75,13 +69,14 @@ pub fn check_rate_limit(env: &Env, address: &Address) -> Result<(), ContractErro
     }
 
     let current_time = env.ledger().timestamp();
-    let key = RateLimitKey::Entry(address.clone());
+    let key = DataKey::RateLimitEntry(address.clone());
 
     // Get or create rate limit entry
     let mut entry: RateLimitEntry = env
         .storage()
         .temporary()
         .get(&key)
+        .or_else(|| legacy_temporary(env, &LegacyDataKey::Entry(address.clone())))
         .unwrap_or(RateLimitEntry {
             request_count: 0,
             window_start: current_time,
@@ -116,12 +111,13 @@ pub fn check_rate_limit(env: &Env, address: &Address) -> Result<(), ContractErro
 /// Get current rate limit status for an address
 pub fn get_rate_limit_status(env: &Env, address: &Address) -> (u32, u32, u64) {
     let config = get_rate_limit_config(env);
-    let key = RateLimitKey::Entry(address.clone());
+    let key = DataKey::RateLimitEntry(address.clone());
 
     let entry: RateLimitEntry = env
         .storage()
         .temporary()
         .get(&key)
+        .or_else(|| legacy_temporary(env, &LegacyDataKey::Entry(address.clone())))
         .unwrap_or(RateLimitEntry {
             request_count: 0,
             window_start: env.ledger().timestamp(),
@@ -0,0 +1,190 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::math::checked_add_i128;
+use crate::storage::DataKey;
+use crate::{ContractError, Remittance, RemittanceStatus};
+
+/// Lifetime remittance counters stored in instance storage
+///
+/// Every remittance is counted once in `total_created` and stays in `active`
+/// until it reaches a terminal status, so `total_created` always equals
+/// `active + total_settled + total_cancelled + total_refunded + total_reclaimed`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GlobalStats {
+    /// Remittances ever created
+    pub total_created: u64,
+    /// Remittances paid out in full
+    pub total_settled: u64,
+    /// Remittances cancelled by the sender
+    pub total_cancelled: u64,
+    /// Remittances refunded to the sender after expiry
+    pub total_refunded: u64,
+    /// Remittances reclaimed by an admin
+    pub total_reclaimed: u64,
+    /// Remittances swept to `Expired` (they remain active until refunded or reclaimed)
+    pub total_expired: u64,
+    /// Remittances not yet in a terminal status
+    pub active: u64,
+}
+
+/// Per-sender lifetime remittance counters stored in persistent storage
+///
+/// Balances the same way as `GlobalStats`: `total_created` always equals
+/// `active + total_settled + total_cancelled + total_refunded + total_reclaimed`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SenderStats {
+    /// Remittances the sender created
+    pub total_created: u64,
+    /// Remittances paid out in full
+    pub total_settled: u64,
+    /// Remittances the sender cancelled
+    pub total_cancelled: u64,
+    /// Remittances refunded to the sender after expiry
+    pub total_refunded: u64,
+    /// Remittances reclaimed by an admin
+    pub total_reclaimed: u64,
+    /// Remittances not yet in a terminal status
+    pub active: u64,
+}
+
+/// Get the lifetime counters (all zero before the first remittance)
+pub fn get_global_stats(env: &Env) -> GlobalStats {
+    env.storage()
+        .instance()
+        .get(&DataKey::GlobalStats)
+        .unwrap_or_default()
+}
+
+/// Get the lifetime principal remitted in a token
+pub fn get_token_volume(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenVolume(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Get a sender's lifetime counters (all zero for a sender with no activity)
+pub fn get_sender_stats(env: &Env, sender: &Address) -> SenderStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderStats(sender.clone()))
+        .unwrap_or_default()
+}
+
+/// Get the lifetime principal a sender has remitted in a token
+pub fn get_sender_volume(env: &Env, sender: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderVolume(sender.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+fn increment(counter: &mut u64) -> Result<(), ContractError> {
+    *counter = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+    Ok(())
+}
+
+/// Move one remittance out of `active` and into a terminal counter
+fn resolve(counter: &mut u64, active: &mut u64) -> Result<(), ContractError> {
+    increment(counter)?;
+    *active = active.checked_sub(1).ok_or(ContractError::Underflow)?;
+    Ok(())
+}
+
+/// Move one remittance back from a terminal counter into `active`
+fn reopen(counter: &mut u64, active: &mut u64) -> Result<(), ContractError> {
+    *counter = counter.checked_sub(1).ok_or(ContractError::Underflow)?;
+    increment(active)
+}
+
+fn add_volume(env: &Env, key: DataKey, persistent: bool, amount: i128) -> Result<(), ContractError> {
+    if persistent {
+        let volume = checked_add_i128(env.storage().persistent().get(&key).unwrap_or(0), amount)?;
+        env.storage().persistent().set(&key, &volume);
+    } else {
+        let volume = checked_add_i128(env.storage().instance().get(&key).unwrap_or(0), amount)?;
+        env.storage().instance().set(&key, &volume);
+    }
+    Ok(())
+}
+
+/// Add a top-up to the global and sender volumes of the remittance's token
+///
+/// Volume counts principal remitted, so a topped-up remittance ends up counted
+/// at its full amount, the same as one created at that amount.
+pub fn record_top_up(env: &Env, remittance: &Remittance, amount: i128) -> Result<(), ContractError> {
+    add_volume(env, DataKey::TokenVolume(remittance.token.clone()), false, amount)?;
+    add_volume(
+        env,
+        DataKey::SenderVolume(remittance.sender.clone(), remittance.token.clone()),
+        true,
+        amount,
+    )
+}
+
+/// Update the global and sender counters for a remittance that has just moved
+/// to its current status
+///
+/// `old_status` is None when the remittance was just created. This is the only
+/// place counters change, and it runs on every status transition, so no path
+/// can update a remittance without updating the statistics. Volumes also grow
+/// through `record_top_up`.
+pub fn record_status_change(
+    env: &Env,
+    remittance: &Remittance,
+    old_status: &Option<RemittanceStatus>,
+) -> Result<(), ContractError> {
+    if old_status.as_ref() == Some(&remittance.status) {
+        return Ok(());
+    }
+
+    let mut stats = get_global_stats(env);
+    let mut sender = get_sender_stats(env, &remittance.sender);
+
+    match &remittance.status {
+        RemittanceStatus::Pending if old_status.is_none() => {
+            increment(&mut stats.total_created)?;
+            increment(&mut stats.active)?;
+            increment(&mut sender.total_created)?;
+            increment(&mut sender.active)?;
+            add_volume(env, DataKey::TokenVolume(remittance.token.clone()), false, remittance.amount)?;
+            add_volume(
+                env,
+                DataKey::SenderVolume(remittance.sender.clone(), remittance.token.clone()),
+                true,
+                remittance.amount,
+            )?;
+        }
+        // A reversed settlement is no longer counted as settled
+        RemittanceStatus::Pending if old_status.as_ref() == Some(&RemittanceStatus::Completed) => {
+            reopen(&mut stats.total_settled, &mut stats.active)?;
+            reopen(&mut sender.total_settled, &mut sender.active)?;
+        }
+        RemittanceStatus::Pending | RemittanceStatus::PartiallySettled => return Ok(()),
+        RemittanceStatus::Expired => increment(&mut stats.total_expired)?,
+        RemittanceStatus::Completed => {
+            resolve(&mut stats.total_settled, &mut stats.active)?;
+            resolve(&mut sender.total_settled, &mut sender.active)?;
+        }
+        RemittanceStatus::Cancelled => {
+            resolve(&mut stats.total_cancelled, &mut stats.active)?;
+            resolve(&mut sender.total_cancelled, &mut sender.active)?;
+        }
+        RemittanceStatus::Refunded => {
+            resolve(&mut stats.total_refunded, &mut stats.active)?;
+            resolve(&mut sender.total_refunded, &mut sender.active)?;
+        }
+        RemittanceStatus::Reclaimed => {
+            resolve(&mut stats.total_reclaimed, &mut stats.active)?;
+            resolve(&mut sender.total_reclaimed, &mut sender.active)?;
+        }
+    }
+
+    env.storage().instance().set(&DataKey::GlobalStats, &stats);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderStats(remittance.sender.clone()), &sender);
+    Ok(())
+}
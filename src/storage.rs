@@ -5,9 +5,12 @@
 //! Uses both instance storage (contract-level config) and persistent storage
 //! (per-entity data).
 
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String, Vec};
 
-use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
+use crate::{
+    AgentStats, AgentStatus, ContractError, DailyLimit, FeeConfig, FeeRounding, PendingAdmin, QueuedAction,
+    Remittance, RemittanceStatus, ReversibleSettlement, TransferRecord,
+};
 
 /// Storage keys for the SwiftRemit contract.
 ///
@@ -16,9 +19,14 @@ use crate::{ContractError, Remittance, TransferRecord, DailyLimit};
 ///   RemittanceCounter, AccumulatedFees)
 /// - Persistent storage: Per-entity data that needs long-term retention (Remittance records,
 ///   AgentRegistered status)
+/// - Temporary storage: Short-lived tracking that may expire (rate limit windows)
+///
+/// Every key the contract writes lives in this enum so that each variant name, and
+/// therefore each encoded key, is unique. Keys written before data version 2 are
+/// described by `LegacyDataKey` and are only read until the layout migration runs.
 #[contracttype]
 #[derive(Clone)]
-enum DataKey {
+pub(crate) enum DataKey {
     // === Contract Configuration ===
     // Core contract settings stored in instance storage
     /// Contract administrator address with privileged access (deprecated - use AdminRole)
@@ -30,6 +38,18 @@ enum DataKey {
     /// Counter for tracking number of admins
     AdminCount,
 
+    /// Admin proposal awaiting acceptance (instance storage)
+    PendingAdmin,
+
+    /// Initializing admin and ledger timestamp of initialization (instance storage)
+    InitInfo,
+
+    /// Schema version of the deployed contract, bumped by each migration (instance storage)
+    ContractVersion,
+
+    /// Storage layout version, bumped by each data layout migration (instance storage)
+    DataVersion,
+
     /// Role assignment indexed by (address, role) (persistent storage)
     RoleAssignment(Address, crate::Role),
 
@@ -41,6 +61,9 @@ enum DataKey {
     
     /// Protocol fee in basis points (1 bps = 0.01%)
     ProtocolFeeBps,
+
+    /// Rounding applied to basis-point fees (instance storage)
+    FeeRounding,
     
     /// Treasury address for protocol fees
     Treasury,
@@ -53,15 +76,73 @@ enum DataKey {
     /// Individual remittance record indexed by ID (persistent storage)
     Remittance(u64),
 
+    /// IDs of remittances created by a sender, in creation order (persistent storage)
+    SenderRemittances(Address),
+
+    /// Recipients and their amounts for a split remittance (persistent storage)
+    RemittanceSplits(u64),
+
+    /// IDs of remittances currently in a status (persistent storage)
+    StatusIndex(RemittanceStatus),
+
+    /// IDs of remittances currently under a compliance hold (persistent storage)
+    HeldRemittances,
+
+    /// Remittance ID created under a sender's idempotency key (persistent storage)
+    IdempotencyKey(Address, BytesN<32>),
+
+    /// Whether a sender lets a relayer submit remittances on their behalf,
+    /// indexed by (sender, relayer) (persistent storage)
+    RelayerApproval(Address, Address),
+
+    /// Whether a sender only pays recipients on their allowlist, indexed by sender (persistent storage)
+    RecipientAllowlistEnabled(Address),
+
+    /// Recipient on a sender's allowlist, indexed by (sender, recipient) (persistent storage)
+    AllowedRecipient(Address, Address),
+
+    /// Only token a recipient accepts, indexed by recipient (persistent storage)
+    PreferredToken(Address),
+
     // === Agent Management ===
     // Keys for tracking registered agents
     /// Agent registration status indexed by agent address (persistent storage)
     AgentRegistered(Address),
 
+    /// Settlement count and volume indexed by agent address (persistent storage)
+    AgentStats(Address),
+
+    /// Active/suspended status indexed by agent address (persistent storage)
+    AgentStatus(Address),
+
+    /// Timestamp of an agent's most recent registration (persistent storage)
+    AgentRegisteredAt(Address),
+
+    /// Admin-set label indexed by agent address (persistent storage)
+    AgentLabel(Address),
+
+    /// Address receiving an agent's settlement funds, if not the agent itself (persistent storage)
+    AgentPayout(Address),
+
     // === Fee Tracking ===
     // Keys for managing platform fees
-    /// Total accumulated platform fees awaiting withdrawal
-    AccumulatedFees,
+    /// Accumulated platform fees awaiting withdrawal, indexed by token
+    AccumulatedFees(Address),
+
+    /// Settlement fee split between treasury and agent (instance storage)
+    FeeConfig,
+
+    /// Settlement fee split overriding `FeeConfig` for one token (instance storage)
+    TokenFeeConfig(Address),
+
+    /// Treasury share of settlement fees held by the contract, indexed by token (instance storage)
+    TreasuryBalance(Address),
+
+    /// Payout conversion rate in basis points, indexed by (escrow token, payout token) (persistent storage)
+    ConversionRate(Address, Address),
+
+    /// Escrowed value of open remittances, indexed by token (instance storage)
+    Tvl(Address),
 
     /// Integrator fee in basis points
     IntegratorFeeBps,
@@ -80,6 +161,9 @@ enum DataKey {
     /// Combined settlement metadata (persistent storage)
     /// Contains flags that were previously stored separately to reduce reads.
     SettlementData(u64),
+
+    /// Set while a settlement is executing token transfers (instance storage)
+    SettlementLock,
     
     // === Rate Limiting ===
     // Keys for preventing abuse through rate limiting
@@ -88,6 +172,52 @@ enum DataKey {
     
     /// Last settlement timestamp for a sender address (persistent storage)
     LastSettlementTime(Address),
+
+    /// Ceiling on the principal of a single remittance (instance storage)
+    MaxRemittanceAmount,
+
+    /// Floor on the principal of a single remittance (instance storage)
+    MinRemittanceAmount,
+
+    /// Expiry window in seconds applied when a remittance has no explicit expiry (instance storage)
+    DefaultExpiry,
+
+    /// Seconds after expiry before an admin may reclaim an abandoned remittance (instance storage)
+    ReclaimGracePeriod,
+
+    /// Seconds past expiry during which the agent may still settle (instance storage)
+    SettlementGrace,
+
+    /// Seconds after settlement during which an admin may reverse it (instance storage)
+    SettlementReversalWindow,
+
+    /// Payout of a settlement that can still be reversed indexed by remittance ID (persistent storage)
+    ReversibleSettlement(u64),
+
+    /// Marks a remittance restored by `reverse_settlement`, whose fees were already collected (persistent storage)
+    ReversedSettlement(u64),
+
+    /// Contract notified after each settlement (instance storage)
+    SettlementHook,
+
+    // === Timelock ===
+    // Keys for delaying sensitive admin operations
+    /// Delay in seconds between queueing and executing a sensitive admin action (instance storage)
+    TimelockDelay,
+
+    /// Counter for generating queued action IDs (instance storage)
+    QueuedActionCounter,
+
+    /// Queued admin action indexed by ID (persistent storage)
+    QueuedAction(u64),
+
+    // === Migration ===
+    // Keys for sequencing batched migration imports
+    /// Next batch number a batched import will accept (instance storage)
+    MigrationNextBatch,
+
+    /// Whether a batched import has started but not yet received its final batch (instance storage)
+    MigrationInProgress,
     
     // === Daily Limits ===
     // Keys for tracking daily transfer limits
@@ -96,11 +226,57 @@ enum DataKey {
     
     /// User transfer records indexed by user address (persistent storage)
     UserTransfers(Address),
+
+    /// Fallback send limit for tokens without an explicit limit (instance storage)
+    DefaultDailyLimit,
+
+    /// Explicit per-token send limit (persistent storage)
+    TokenDailyLimit(Address),
+
+    /// Amounts sent per token indexed by (user, UTC day) (temporary storage)
+    DailyUsage(Address, u64),
+
+    /// Settlement cap for an agent (persistent storage)
+    AgentDailyCap(Address),
+
+    /// Principal settled indexed by (agent, UTC day) (temporary storage)
+    AgentDailyUsage(Address, u64),
+
+    // === Rate Limiting and Circuit Breaker ===
+    /// Global request rate limit configuration (instance storage)
+    RateLimitConfig,
+
+    /// Request window tracking indexed by address (temporary storage)
+    RateLimitEntry(Address),
+
+    /// Volume circuit breaker configuration (instance storage)
+    CircuitBreakerConfig,
+
+    /// Settled volume in the current and previous breaker windows (instance storage)
+    CircuitBreakerVolume,
+
+    /// Lifetime remittance counters (instance storage)
+    GlobalStats,
+
+    /// Lifetime principal remitted indexed by token (instance storage)
+    TokenVolume(Address),
+
+    /// Lifetime remittance counters indexed by sender (persistent storage)
+    SenderStats(Address),
+
+    /// Lifetime principal remitted indexed by (sender, token) (persistent storage)
+    SenderVolume(Address, Address),
     
     // === Token Whitelist ===
     // Keys for managing whitelisted tokens
     /// Token whitelist status indexed by token address (persistent storage)
     TokenWhitelisted(Address),
+
+    /// Every currently whitelisted token, in whitelisting order (persistent storage)
+    WhitelistedTokens,
+
+    /// Whether new remittances must use a whitelisted token (instance storage)
+    RequireTokenWhitelist,
     
     /// Settlement completion event emission tracking (persistent storage)
     /// Tracks whether the completion event has been emitted for a settlement
@@ -167,170 +343,1025 @@ pub fn set_usdc_token(env: &Env, token: &Address) {
     env.storage().instance().set(&DataKey::UsdcToken, token);
 }
 
-/// Retrieves the USDC token contract address.
+/// Retrieves the USDC token contract address.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Ok(Address)` - The USDC token contract address
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_usdc_token(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::UsdcToken)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Sets the platform fee rate.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fee_bps` - Fee in basis points (1 bps = 0.01%)
+pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PlatformFeeBps, &fee_bps);
+}
+
+/// Retrieves the platform fee rate.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Ok(u32)` - Fee in basis points
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_platform_fee_bps(env: &Env) -> Result<u32, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlatformFeeBps)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Sets the remittance counter for ID generation.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `counter` - Current counter value
+pub fn set_remittance_counter(env: &Env, counter: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RemittanceCounter, &counter);
+}
+
+/// Retrieves the current remittance counter.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Ok(u64)` - Current counter value
+/// * `Err(ContractError::NotInitialized)` - Contract not initialized
+pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RemittanceCounter)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Stores a remittance record.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID
+/// * `remittance` - Remittance record to store
+pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Remittance(id), remittance);
+    extend_remittance_ttl(env, id, remittance.expiry);
+}
+
+/// Approximate ledger close time, used to convert expiry windows into ledgers
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+
+/// Lifetime in ledgers kept on a remittance beyond its expiry (about 30 days)
+pub const REMITTANCE_TTL_BUFFER_LEDGERS: u32 = 518_400;
+
+/// Extends the TTL of a remittance record and its transfer state.
+///
+/// The entries are kept alive until the remittance's expiry plus
+/// `REMITTANCE_TTL_BUFFER_LEDGERS`, capped at the network's maximum TTL, so a
+/// pending remittance cannot be archived before it is settled or refunded.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID
+/// * `expiry` - Remittance expiry timestamp, if any
+pub fn extend_remittance_ttl(env: &Env, id: u64, expiry: Option<u64>) {
+    let window = expiry
+        .unwrap_or(0)
+        .saturating_sub(env.ledger().timestamp());
+    let window_ledgers = u32::try_from(window / LEDGER_CLOSE_SECONDS).unwrap_or(u32::MAX);
+    let extend_to = window_ledgers
+        .saturating_add(REMITTANCE_TTL_BUFFER_LEDGERS)
+        .min(env.storage().max_ttl());
+
+    let storage = env.storage().persistent();
+    storage.extend_ttl(&DataKey::Remittance(id), extend_to, extend_to);
+    let state_key = DataKey::TransferState(id);
+    if storage.has(&state_key) {
+        storage.extend_ttl(&state_key, extend_to, extend_to);
+    }
+    let splits_key = DataKey::RemittanceSplits(id);
+    if storage.has(&splits_key) {
+        storage.extend_ttl(&splits_key, extend_to, extend_to);
+    }
+}
+
+/// Retrieves the remaining TTL, in ledgers, of a remittance record.
+#[cfg(test)]
+pub fn get_remittance_ttl(env: &Env, id: u64) -> u32 {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    env.storage().persistent().get_ttl(&DataKey::Remittance(id))
+}
+
+/// Retrieves a remittance record by ID.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID to retrieve
+///
+/// # Returns
+///
+/// * `Ok(Remittance)` - The remittance record
+/// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Remittance(id))
+        .ok_or(ContractError::RemittanceNotFound)
+}
+
+/// Stores the recipients of a split remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID
+/// * `splits` - Recipients paired with their share of the principal
+pub fn set_remittance_splits(env: &Env, id: u64, splits: &Vec<(Address, i128)>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceSplits(id), splits);
+}
+
+/// Retrieves the recipients of a split remittance.
+///
+/// # Returns
+///
+/// * `Vec<(Address, i128)>` - Recipients and their amounts (empty if the remittance is not a split)
+pub fn get_remittance_splits(env: &Env, id: u64) -> Vec<(Address, i128)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceSplits(id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends a remittance ID to its sender's index.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Address that created the remittance
+/// * `id` - Remittance ID to append
+pub fn add_sender_remittance(env: &Env, sender: &Address, id: u64) {
+    let key = DataKey::SenderRemittances(sender.clone());
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+}
+
+/// Retrieves the IDs of all remittances created by a sender.
+///
+/// # Returns
+///
+/// * `Vec<u64>` - Remittance IDs in creation order (empty if none)
+pub fn get_sender_remittances(env: &Env, sender: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderRemittances(sender.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn add_to_index(env: &Env, key: &DataKey, id: u64) {
+    let mut ids: Vec<u64> = env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+    if ids.first_index_of(id).is_none() {
+        ids.push_back(id);
+        env.storage().persistent().set(key, &ids);
+    }
+}
+
+fn remove_from_index(env: &Env, key: &DataKey, id: u64) {
+    let mut ids: Vec<u64> = env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+    if let Some(position) = ids.first_index_of(id) {
+        ids.remove(position);
+        if ids.is_empty() {
+            env.storage().persistent().remove(key);
+        } else {
+            env.storage().persistent().set(key, &ids);
+        }
+    }
+}
+
+/// Moves a remittance from its old status index to its new one.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `id` - Remittance ID
+/// * `old_status` - Status before the transition (`None` on creation)
+/// * `new_status` - Status after the transition
+pub fn move_status_index(env: &Env, id: u64, old_status: &Option<RemittanceStatus>, new_status: &RemittanceStatus) {
+    if old_status.as_ref() == Some(new_status) {
+        return;
+    }
+    if let Some(old_status) = old_status {
+        remove_from_index(env, &DataKey::StatusIndex(old_status.clone()), id);
+    }
+    add_to_index(env, &DataKey::StatusIndex(new_status.clone()), id);
+}
+
+/// Retrieves the IDs of remittances currently in a status.
+///
+/// # Returns
+///
+/// * `Vec<u64>` - Remittance IDs in the order they entered the status (empty if none)
+pub fn get_status_index(env: &Env, status: &RemittanceStatus) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusIndex(status.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a remittance to, or removes it from, the index of held remittances.
+pub fn set_held_index(env: &Env, id: u64, held: bool) {
+    if held {
+        add_to_index(env, &DataKey::HeldRemittances, id);
+    } else {
+        remove_from_index(env, &DataKey::HeldRemittances, id);
+    }
+}
+
+/// Retrieves the IDs of remittances currently under a compliance hold.
+pub fn get_held_remittances(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HeldRemittances)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces a remittance's index entries with those of a new record.
+///
+/// Used when a record is written wholesale, such as by a migration import,
+/// rather than through a status transition.
+pub fn reindex_remittance(env: &Env, previous: Option<&Remittance>, remittance: &Remittance) {
+    if let Some(previous) = previous {
+        remove_from_index(env, &DataKey::StatusIndex(previous.status.clone()), previous.id);
+        set_held_index(env, previous.id, false);
+    }
+    add_to_index(env, &DataKey::StatusIndex(remittance.status.clone()), remittance.id);
+    set_held_index(env, remittance.id, remittance.on_hold);
+}
+
+/// Records the remittance created under a sender's idempotency key.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `sender` - Address that created the remittance
+/// * `key` - Client-supplied idempotency key
+/// * `id` - Remittance ID created for the key
+pub fn set_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>, id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::IdempotencyKey(sender.clone(), key.clone()), &id);
+}
+
+/// Retrieves the remittance created under a sender's idempotency key, if any.
+pub fn get_idempotent_remittance(env: &Env, sender: &Address, key: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IdempotencyKey(sender.clone(), key.clone()))
+}
+
+/// Sets whether a relayer may submit remittances on a sender's behalf.
+pub fn set_relayer_approved(env: &Env, sender: &Address, relayer: &Address, approved: bool) {
+    let key = DataKey::RelayerApproval(sender.clone(), relayer.clone());
+    if approved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks whether a relayer may submit remittances on a sender's behalf.
+pub fn is_relayer_approved(env: &Env, sender: &Address, relayer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RelayerApproval(sender.clone(), relayer.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets whether a sender only pays recipients on their allowlist.
+pub fn set_recipient_allowlist_enabled(env: &Env, sender: &Address, enabled: bool) {
+    let key = DataKey::RecipientAllowlistEnabled(sender.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks whether a sender only pays recipients on their allowlist (defaults to false).
+pub fn is_recipient_allowlist_enabled(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecipientAllowlistEnabled(sender.clone()))
+        .unwrap_or(false)
+}
+
+/// Adds a recipient to, or removes it from, a sender's allowlist.
+pub fn set_recipient_allowed(env: &Env, sender: &Address, recipient: &Address, allowed: bool) {
+    let key = DataKey::AllowedRecipient(sender.clone(), recipient.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks whether a recipient is on a sender's allowlist.
+pub fn is_recipient_allowed(env: &Env, sender: &Address, recipient: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedRecipient(sender.clone(), recipient.clone()))
+        .unwrap_or(false)
+}
+
+/// Sets or clears the only token a recipient accepts.
+pub fn set_preferred_token(env: &Env, recipient: &Address, token: &Option<Address>) {
+    let key = DataKey::PreferredToken(recipient.clone());
+    match token {
+        Some(token) => env.storage().persistent().set(&key, token),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves a recipient's preferred token, if one has been set.
+pub fn get_preferred_token(env: &Env, recipient: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PreferredToken(recipient.clone()))
+}
+
+/// Sets an agent's registration status.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `registered` - Registration status (true = registered, false = removed)
+pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRegistered(agent.clone()), &registered);
+}
+
+/// Checks if an address is registered as an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address to check
+///
+/// # Returns
+///
+/// * `true` - Address is registered
+/// * `false` - Address is not registered
+pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRegistered(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Checks if an address has ever been registered as an agent.
+///
+/// # Returns
+///
+/// * `true` - Address is or was registered (including since-removed agents)
+/// * `false` - Address was never registered
+pub fn was_agent_ever_registered(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AgentRegistered(agent.clone()))
+}
+
+/// Sets an agent's operational status.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `status` - Active or suspended
+pub fn set_agent_status(env: &Env, agent: &Address, status: &AgentStatus) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStatus(agent.clone()), status);
+}
+
+/// Retrieves an agent's operational status.
+///
+/// # Returns
+///
+/// * `AgentStatus` - Current status (defaults to Active)
+pub fn get_agent_status(env: &Env, agent: &Address) -> AgentStatus {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStatus(agent.clone()))
+        .unwrap_or(AgentStatus::Active)
+}
+
+/// Sets an agent's settlement stats.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `stats` - Updated settlement count and volume
+pub fn set_agent_stats(env: &Env, agent: &Address, stats: &AgentStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStats(agent.clone()), stats);
+}
+
+/// Retrieves an agent's settlement stats.
+///
+/// # Returns
+///
+/// * `AgentStats` - Settlement count and volume (zero if the agent has not settled yet)
+pub fn get_agent_stats(env: &Env, agent: &Address) -> AgentStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStats(agent.clone()))
+        .unwrap_or(AgentStats {
+            settlement_count: 0,
+            total_volume: 0,
+        })
+}
+
+/// Records when an agent was registered.
+pub fn set_agent_registered_at(env: &Env, agent: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRegisteredAt(agent.clone()), &timestamp);
+}
+
+/// Retrieves when an agent was most recently registered.
+///
+/// # Returns
+///
+/// * `u64` - Registration timestamp (0 for agents registered before it was recorded)
+pub fn get_agent_registered_at(env: &Env, agent: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRegisteredAt(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets or clears an agent's label.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address
+/// * `label` - New label, or None to clear it
+pub fn set_agent_label(env: &Env, agent: &Address, label: &Option<Bytes>) {
+    let key = DataKey::AgentLabel(agent.clone());
+    match label {
+        Some(label) => env.storage().persistent().set(&key, label),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves an agent's label, if one has been set.
+pub fn get_agent_label(env: &Env, agent: &Address) -> Option<Bytes> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentLabel(agent.clone()))
+}
+
+/// Sets or clears the address an agent's settlement funds are paid to.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent's authorizing address
+/// * `payout` - Payout address, or None to pay the agent itself
+pub fn set_agent_payout(env: &Env, agent: &Address, payout: &Option<Address>) {
+    let key = DataKey::AgentPayout(agent.clone());
+    match payout {
+        Some(payout) => env.storage().persistent().set(&key, payout),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the address an agent's settlement funds are paid to (defaults to the agent itself).
+pub fn get_agent_payout(env: &Env, agent: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPayout(agent.clone()))
+        .unwrap_or_else(|| agent.clone())
+}
+
+/// Sets the accumulated platform fees for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the fees are denominated in
+/// * `fees` - Total accumulated fees
+pub fn set_accumulated_fees(env: &Env, token: &Address, fees: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccumulatedFees(token.clone()), &fees);
+}
+
+/// Retrieves the accumulated platform fees for a token.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `token` - Token the fees are denominated in
+///
+/// # Returns
+///
+/// * `i128` - Total accumulated fees (defaults to 0)
+pub fn get_accumulated_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccumulatedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Sets the settlement fee split.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `config` - Treasury and agent shares in basis points
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+/// Retrieves the settlement fee split.
+///
+/// # Returns
+///
+/// * `FeeConfig` - Current split (defaults to zero for both shares if not configured)
+pub fn get_fee_config(env: &Env) -> FeeConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeConfig)
+        .unwrap_or(FeeConfig {
+            treasury_bps: 0,
+            agent_bps: 0,
+        })
+}
+
+/// Sets the expiry window applied to remittances created without an explicit expiry.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `seconds` - Window after creation before settlement expires (0 disables)
+pub fn set_default_expiry(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DefaultExpiry, &seconds);
+}
+
+/// Retrieves the default expiry window.
+///
+/// # Returns
+///
+/// * `u64` - Window in seconds (defaults to 0, meaning no default expiry)
+pub fn get_default_expiry(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DefaultExpiry)
+        .unwrap_or(0)
+}
+
+/// Sets or clears the contract notified after each settlement.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `hook` - Notifier contract, or None to stop notifying
+pub fn set_settlement_hook(env: &Env, hook: &Option<Address>) {
+    match hook {
+        Some(hook) => env.storage().instance().set(&DataKey::SettlementHook, hook),
+        None => env.storage().instance().remove(&DataKey::SettlementHook),
+    }
+}
+
+/// Retrieves the contract notified after each settlement, if any.
+pub fn get_settlement_hook(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SettlementHook)
+}
+
+/// Sets the rounding applied to basis-point fees.
+pub fn set_fee_rounding(env: &Env, rounding: FeeRounding) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeRounding, &rounding);
+}
+
+/// Retrieves the rounding applied to basis-point fees.
+///
+/// # Returns
+///
+/// * `FeeRounding` - Configured mode (defaults to `FeeRounding::Down`)
+pub fn get_fee_rounding(env: &Env) -> FeeRounding {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeRounding)
+        .unwrap_or(FeeRounding::Down)
+}
+
+/// Grace period applied until an admin configures one: 90 days past expiry.
+pub const DEFAULT_RECLAIM_GRACE_PERIOD: u64 = 90 * 86_400;
+
+/// Sets how long past expiry a remittance must sit before an admin can reclaim it.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `seconds` - Grace period in seconds after the remittance's expiry
+pub fn set_reclaim_grace_period(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReclaimGracePeriod, &seconds);
+}
+
+/// Retrieves the admin reclaim grace period.
 ///
 /// # Returns
 ///
-/// * `Ok(Address)` - The USDC token contract address
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_usdc_token(env: &Env) -> Result<Address, ContractError> {
+/// * `u64` - Grace period in seconds (defaults to `DEFAULT_RECLAIM_GRACE_PERIOD`)
+pub fn get_reclaim_grace_period(env: &Env) -> u64 {
     env.storage()
         .instance()
-        .get(&DataKey::UsdcToken)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::ReclaimGracePeriod)
+        .unwrap_or(DEFAULT_RECLAIM_GRACE_PERIOD)
 }
 
-/// Sets the platform fee rate.
+/// Sets how long past expiry an agent may still settle a remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fee_bps` - Fee in basis points (1 bps = 0.01%)
-pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
+/// * `seconds` - Grace window in seconds after the remittance's expiry
+pub fn set_settlement_grace(env: &Env, seconds: u64) {
     env.storage()
         .instance()
-        .set(&DataKey::PlatformFeeBps, &fee_bps);
+        .set(&DataKey::SettlementGrace, &seconds);
 }
 
-/// Retrieves the platform fee rate.
+/// Retrieves the agent settlement grace window.
+///
+/// # Returns
+///
+/// * `u64` - Grace window in seconds (defaults to 0)
+pub fn get_settlement_grace(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SettlementGrace)
+        .unwrap_or(0)
+}
+
+/// Sets how long after settlement an admin may reverse it.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `seconds` - Reversal window after settlement (0 disables reversal)
+pub fn set_settlement_reversal_window(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementReversalWindow, &seconds);
+}
+
+/// Retrieves the settlement reversal window.
 ///
 /// # Returns
 ///
-/// * `Ok(u32)` - Fee in basis points
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_platform_fee_bps(env: &Env) -> Result<u32, ContractError> {
+/// * `u64` - Reversal window in seconds (defaults to 0 if not configured)
+pub fn get_settlement_reversal_window(env: &Env) -> u64 {
     env.storage()
         .instance()
-        .get(&DataKey::PlatformFeeBps)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::SettlementReversalWindow)
+        .unwrap_or(0)
 }
 
-/// Sets the remittance counter for ID generation.
+/// Records the payout of a settlement so it can be reversed within the window.
+pub fn set_reversible_settlement(env: &Env, remittance_id: u64, settlement: &ReversibleSettlement) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReversibleSettlement(remittance_id), settlement);
+}
+
+/// Retrieves the reversible payout of a settled remittance, if any.
+pub fn get_reversible_settlement(env: &Env, remittance_id: u64) -> Option<ReversibleSettlement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReversibleSettlement(remittance_id))
+}
+
+/// Forgets the reversible payout of a remittance.
+pub fn remove_reversible_settlement(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ReversibleSettlement(remittance_id));
+}
+
+/// Marks a remittance as restored by a settlement reversal.
+pub fn set_settlement_reversed(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReversedSettlement(remittance_id), &true);
+}
+
+/// Whether a remittance was restored by a settlement reversal.
+pub fn is_settlement_reversed(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReversedSettlement(remittance_id))
+        .unwrap_or(false)
+}
+
+/// Sets or clears a token's settlement fee split override.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `counter` - Current counter value
-pub fn set_remittance_counter(env: &Env, counter: u64) {
+/// * `token` - Token the override applies to
+/// * `config` - Override, or None to fall back to the global split
+pub fn set_token_fee_config(env: &Env, token: &Address, config: &Option<FeeConfig>) {
+    let key = DataKey::TokenFeeConfig(token.clone());
+    match config {
+        Some(config) => env.storage().instance().set(&key, config),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+/// Retrieves a token's settlement fee split override, if one is set.
+pub fn get_token_fee_config(env: &Env, token: &Address) -> Option<FeeConfig> {
     env.storage()
         .instance()
-        .set(&DataKey::RemittanceCounter, &counter);
+        .get(&DataKey::TokenFeeConfig(token.clone()))
 }
 
-/// Retrieves the current remittance counter.
+/// Retrieves the settlement fee split charged on remittances in a token:
+/// the token's override if set, otherwise the global split.
+pub fn get_effective_fee_config(env: &Env, token: &Address) -> FeeConfig {
+    get_token_fee_config(env, token).unwrap_or_else(|| get_fee_config(env))
+}
+
+/// Sets the ceiling on the principal of a single remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `amount` - Largest principal accepted by create_remittance
+pub fn set_max_remittance_amount(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxRemittanceAmount, &amount);
+}
+
+/// Retrieves the ceiling on the principal of a single remittance.
 ///
 /// # Returns
 ///
-/// * `Ok(u64)` - Current counter value
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
+/// * `i128` - Current ceiling (defaults to `i128::MAX` if not configured)
+pub fn get_max_remittance_amount(env: &Env) -> i128 {
     env.storage()
         .instance()
-        .get(&DataKey::RemittanceCounter)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::MaxRemittanceAmount)
+        .unwrap_or(i128::MAX)
 }
 
-/// Stores a remittance record.
+/// Sets the floor on the principal of a single remittance.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `id` - Remittance ID
-/// * `remittance` - Remittance record to store
-pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
+/// * `amount` - Smallest principal accepted by create_remittance
+pub fn set_min_remittance_amount(env: &Env, amount: i128) {
     env.storage()
-        .persistent()
-        .set(&DataKey::Remittance(id), remittance);
+        .instance()
+        .set(&DataKey::MinRemittanceAmount, &amount);
 }
 
-/// Retrieves a remittance record by ID.
+/// Retrieves the floor on the principal of a single remittance.
 ///
-/// # Arguments
+/// # Returns
 ///
-/// * `env` - The contract execution environment
-/// * `id` - Remittance ID to retrieve
+/// * `i128` - Current floor (defaults to 0 if not configured)
+pub fn get_min_remittance_amount(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinRemittanceAmount)
+        .unwrap_or(0)
+}
+
+/// Sets or clears the lock held while a settlement executes token transfers.
+pub fn set_settlement_lock(env: &Env, locked: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementLock, &locked);
+}
+
+/// Checks whether a settlement is currently executing token transfers.
+pub fn is_settlement_locked(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::SettlementLock)
+        .unwrap_or(false)
+}
+
+/// Sets the delay in seconds a queued sensitive admin action must wait.
+pub fn set_timelock_delay(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TimelockDelay, &seconds);
+}
+
+/// Retrieves the timelock delay in seconds (defaults to 0, meaning no timelock).
+pub fn get_timelock_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(0)
+}
+
+/// Retrieves the ID of the most recently queued action (defaults to 0).
+pub fn get_queued_action_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::QueuedActionCounter)
+        .unwrap_or(0)
+}
+
+/// Sets the ID of the most recently queued action.
+pub fn set_queued_action_counter(env: &Env, counter: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::QueuedActionCounter, &counter);
+}
+
+/// Retrieves a queued action by ID.
 ///
 /// # Returns
 ///
-/// * `Ok(Remittance)` - The remittance record
-/// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
-pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
+/// * `Ok(QueuedAction)` - The queued action
+/// * `Err(ContractError::ActionNotFound)` - No action is queued under this ID
+pub fn get_queued_action(env: &Env, action_id: u64) -> Result<QueuedAction, ContractError> {
     env.storage()
         .persistent()
-        .get(&DataKey::Remittance(id))
-        .ok_or(ContractError::RemittanceNotFound)
+        .get(&DataKey::QueuedAction(action_id))
+        .ok_or(ContractError::ActionNotFound)
 }
 
-/// Sets an agent's registration status.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-/// * `agent` - Agent address
-/// * `registered` - Registration status (true = registered, false = removed)
-pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
+/// Stores a queued action under its ID.
+pub fn set_queued_action(env: &Env, action: &QueuedAction) {
     env.storage()
         .persistent()
-        .set(&DataKey::AgentRegistered(agent.clone()), &registered);
+        .set(&DataKey::QueuedAction(action.id), action);
 }
 
-/// Checks if an address is registered as an agent.
+/// Removes a queued action once it has been executed or cancelled.
+pub fn remove_queued_action(env: &Env, action_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QueuedAction(action_id));
+}
+
+/// Sets the next batch number a batched migration import will accept.
+pub fn set_migration_next_batch(env: &Env, batch_number: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationNextBatch, &batch_number);
+}
+
+/// Retrieves the next batch number a batched migration import will accept (defaults to 0).
+pub fn get_migration_next_batch(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationNextBatch)
+        .unwrap_or(0)
+}
+
+/// Marks whether a batched migration import is underway.
+pub fn set_migration_in_progress(env: &Env, in_progress: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationInProgress, &in_progress);
+}
+
+/// Checks whether a batched migration import is underway.
+pub fn is_migration_in_progress(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationInProgress)
+        .unwrap_or(false)
+}
+
+/// Sets the treasury share of settlement fees held by the contract for a token.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `agent` - Agent address to check
+/// * `token` - Token the balance is denominated in
+/// * `balance` - Total treasury balance
+pub fn set_treasury_balance(env: &Env, token: &Address, balance: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TreasuryBalance(token.clone()), &balance);
+}
+
+/// Retrieves the treasury share of settlement fees held by the contract for a token.
 ///
 /// # Returns
 ///
-/// * `true` - Address is registered
-/// * `false` - Address is not registered
-pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+/// * `i128` - Total treasury balance (defaults to 0)
+pub fn get_treasury_balance(env: &Env, token: &Address) -> i128 {
     env.storage()
-        .persistent()
-        .get(&DataKey::AgentRegistered(agent.clone()))
-        .unwrap_or(false)
+        .instance()
+        .get(&DataKey::TreasuryBalance(token.clone()))
+        .unwrap_or(0)
 }
 
-/// Sets the accumulated platform fees.
+/// Sets the rate for paying out `from_token` escrow in `to_token`, or clears it when zero.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
-/// * `fees` - Total accumulated fees
-pub fn set_accumulated_fees(env: &Env, fees: i128) {
+/// * `from_token` - Escrow token
+/// * `to_token` - Payout token
+/// * `rate_bps` - Units of `to_token` per unit of `from_token`, in basis points
+pub fn set_conversion_rate(env: &Env, from_token: &Address, to_token: &Address, rate_bps: u32) {
+    let key = DataKey::ConversionRate(from_token.clone(), to_token.clone());
+    if rate_bps == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &rate_bps);
+    }
+}
+
+/// Retrieves the rate for paying out `from_token` escrow in `to_token`.
+///
+/// # Returns
+///
+/// * `Some(u32)` - Rate in basis points
+/// * `None` - No rate configured for the pair
+pub fn get_conversion_rate(env: &Env, from_token: &Address, to_token: &Address) -> Option<u32> {
     env.storage()
-        .instance()
-        .set(&DataKey::AccumulatedFees, &fees);
+        .persistent()
+        .get(&DataKey::ConversionRate(from_token.clone(), to_token.clone()))
 }
 
-/// Retrieves the accumulated platform fees.
+/// Sets the total value locked in open remittances for a token.
 ///
 /// # Arguments
 ///
 /// * `env` - The contract execution environment
+/// * `token` - Token the value is denominated in
+/// * `tvl` - Escrowed principal plus reserved settlement fees
+pub fn set_tvl(env: &Env, token: &Address, tvl: i128) {
+    env.storage().instance().set(&DataKey::Tvl(token.clone()), &tvl);
+}
+
+/// Retrieves the total value locked in open remittances for a token.
 ///
 /// # Returns
 ///
-/// * `Ok(i128)` - Total accumulated fees
-/// * `Err(ContractError::NotInitialized)` - Contract not initialized
-pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+/// * `i128` - Total value locked (defaults to 0)
+pub fn get_tvl(env: &Env, token: &Address) -> i128 {
     env.storage()
         .instance()
-        .get(&DataKey::AccumulatedFees)
-        .ok_or(ContractError::NotInitialized)
+        .get(&DataKey::Tvl(token.clone()))
+        .unwrap_or(0)
 }
 
 /// Checks if a settlement hash exists for duplicate detection.
@@ -396,6 +1427,15 @@ pub fn has_settlement_hash(env: &Env, remittance_id: u64) -> bool {
     data.executed
 }
 
+/// Forgets that a settlement was executed, so a reversed remittance can settle again.
+pub fn clear_settlement_hash(env: &Env, remittance_id: u64) {
+    // Reading first removes any legacy keys along the way
+    load_or_migrate_settlement_data(env, remittance_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SettlementData(remittance_id));
+}
+
 /// Marks a settlement as executed for duplicate prevention.
 pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
     let mut data = load_or_migrate_settlement_data(env, remittance_id);
@@ -520,6 +1560,74 @@ pub fn set_admin_count(env: &Env, count: u32) {
     env.storage().instance().set(&DataKey::AdminCount, &count);
 }
 
+/// Records who initialized the contract and when.
+pub fn set_init_info(env: &Env, admin: &Address, initialized_at: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::InitInfo, &(admin.clone(), initialized_at));
+}
+
+/// Retrieves the initializing admin and the ledger timestamp of initialization.
+///
+/// # Returns
+///
+/// * `Ok((Address, u64))` - Initializing admin and timestamp
+/// * `Err(ContractError::NotInitialized)` - Contract has not been initialized
+pub fn get_init_info(env: &Env) -> Result<(Address, u64), ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::InitInfo)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Sets the schema version of the deployed contract.
+pub fn set_contract_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::ContractVersion, &version);
+}
+
+/// Retrieves the schema version of the deployed contract.
+///
+/// # Returns
+///
+/// * `u32` - Live schema version (0 before initialization)
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractVersion)
+        .unwrap_or(0)
+}
+
+/// Sets the storage layout version of the deployed contract.
+pub fn set_data_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::DataVersion, &version);
+}
+
+/// Retrieves the storage layout version of the deployed contract.
+///
+/// Contracts deployed before the version was recorded report the legacy layout.
+///
+/// # Returns
+///
+/// * `u32` - Live layout version (`LEGACY_DATA_VERSION` when never recorded)
+pub fn get_data_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DataVersion)
+        .unwrap_or(crate::LEGACY_DATA_VERSION)
+}
+
+pub fn set_pending_admin(env: &Env, pending: &PendingAdmin) {
+    env.storage().instance().set(&DataKey::PendingAdmin, pending);
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
 pub fn require_admin(env: &Env, address: &Address) -> Result<(), ContractError> {
     address.require_auth();
 
@@ -539,10 +1647,46 @@ pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
         .unwrap_or(false)
 }
 
+/// Sets a token's whitelist status and keeps the `get_whitelisted_tokens` index in step.
 pub fn set_token_whitelisted(env: &Env, token: &Address, whitelisted: bool) {
     env.storage()
         .persistent()
         .set(&DataKey::TokenWhitelisted(token.clone()), &whitelisted);
+
+    let mut tokens = get_whitelisted_tokens(env);
+    match (tokens.first_index_of(token), whitelisted) {
+        (None, true) => tokens.push_back(token.clone()),
+        (Some(position), false) => {
+            tokens.remove(position);
+        }
+        _ => return,
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::WhitelistedTokens, &tokens);
+}
+
+/// Retrieves every currently whitelisted token, in the order they were whitelisted.
+pub fn get_whitelisted_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WhitelistedTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Sets whether new remittances must use a whitelisted token.
+pub fn set_require_token_whitelist(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RequireTokenWhitelist, &enabled);
+}
+
+/// Checks whether new remittances must use a whitelisted token (defaults to true).
+pub fn is_token_whitelist_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireTokenWhitelist)
+        .unwrap_or(true)
 }
 
 // === Settlement Event Emission Tracking ===
@@ -719,6 +1863,17 @@ pub fn require_role_admin(env: &Env, address: &Address) -> Result<(), ContractEr
     Ok(())
 }
 
+/// Requires that the caller is an admin or holds the Operator role
+pub fn require_operator(env: &Env, address: &Address) -> Result<(), ContractError> {
+    address.require_auth();
+
+    if !is_admin(env, address) && !has_role(env, address, &crate::Role::Operator) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
 /// Requires that the caller has Settler role
 pub fn require_role_settler(env: &Env, address: &Address) -> Result<(), ContractError> {
     if !has_role(env, address, &crate::Role::Settler) {
@@ -737,6 +1892,13 @@ pub fn get_transfer_state(env: &Env, transfer_id: u64) -> Option<crate::Transfer
         .get(&DataKey::TransferState(transfer_id))
 }
 
+/// Removes the transfer state, so a reversed remittance starts over from none
+pub fn clear_transfer_state(env: &Env, transfer_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TransferState(transfer_id));
+}
+
 /// Sets the transfer state with validation
 pub fn set_transfer_state(
     env: &Env,
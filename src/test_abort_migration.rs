@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use crate::{ContractError, MigrationBatch, SwiftRemitContract, SwiftRemitContractClient, INITIAL_CONTRACT_VERSION};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+/// Returns a source contract holding six remittances, an initialized migration
+/// target and the target's admin.
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, SwiftRemitContractClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let source = create_swiftremit_contract(env);
+    let target = create_swiftremit_contract(env);
+    for contract in [&source, &target] {
+        env.as_contract(&contract.address, || {
+            crate::storage::set_token_whitelisted(env, &token.address, true);
+        });
+        contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    }
+    source.register_agent(&agent, &None);
+
+    for _ in 0..6 {
+        source.create_remittance(&sender, &agent, &token.address, &100, &None, &None, &None, &None);
+    }
+
+    (source, target, admin)
+}
+
+fn export_batch(env: &Env, contract: &SwiftRemitContractClient, batch_number: u32) -> MigrationBatch {
+    env.as_contract(&contract.address, || crate::export_batch(env, batch_number, 2).unwrap())
+}
+
+fn import_batch(env: &Env, contract: &SwiftRemitContractClient, batch: MigrationBatch) -> Result<(), ContractError> {
+    env.as_contract(&contract.address, || crate::import_batch(env, batch))
+}
+
+fn cursor(env: &Env, contract: &SwiftRemitContractClient) -> (bool, u32) {
+    env.as_contract(&contract.address, || {
+        (
+            crate::storage::is_migration_in_progress(env),
+            crate::storage::get_migration_next_batch(env),
+        )
+    })
+}
+
+#[test]
+fn test_abort_resumes_from_first_uncommitted_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup(&env);
+
+    import_batch(&env, &target, export_batch(&env, &source, 0)).unwrap();
+    assert_eq!(cursor(&env, &target), (true, 1));
+
+    // A bad batch is rejected without moving the cursor
+    let mut bad = export_batch(&env, &source, 1);
+    bad.remittances.pop_back();
+    assert_eq!(import_batch(&env, &target, bad), Err(ContractError::InvalidMigrationHash));
+
+    assert_eq!(target.abort_migration(&admin), 1);
+    assert_eq!(cursor(&env, &target), (false, 1));
+
+    // Committed batches stay in place
+    assert_eq!(target.get_remittance(&1).amount, 100);
+    assert_eq!(target.get_remittance(&2).amount, 100);
+
+    // Restarting cannot re-apply batch one, and picks up at batch two
+    assert_eq!(
+        import_batch(&env, &target, export_batch(&env, &source, 0)),
+        Err(ContractError::InvalidMigrationBatch)
+    );
+    import_batch(&env, &target, export_batch(&env, &source, 1)).unwrap();
+    assert_eq!(cursor(&env, &target), (true, 2));
+
+    import_batch(&env, &target, export_batch(&env, &source, 2)).unwrap();
+    assert_eq!(cursor(&env, &target), (false, 0));
+    assert_eq!(target.get_remittance(&6).amount, 100);
+}
+
+#[test]
+fn test_abort_unblocks_version_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup(&env);
+
+    import_batch(&env, &target, export_batch(&env, &source, 0)).unwrap();
+    let result = target.try_migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(result, Err(Ok(ContractError::MigrationInProgress)));
+
+    target.abort_migration(&admin);
+    target.migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(target.version(), INITIAL_CONTRACT_VERSION + 1);
+}
+
+#[test]
+fn test_abort_migration_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup(&env);
+
+    import_batch(&env, &target, export_batch(&env, &source, 0)).unwrap();
+
+    let operator = Address::generate(&env);
+    target.assign_role(&admin, &operator, &crate::Role::Operator);
+    let result = target.try_abort_migration(&operator);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(cursor(&env, &target), (true, 1));
+}
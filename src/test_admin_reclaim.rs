@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+const EXPIRY: u64 = 2_000;
+const GRACE: u64 = 10_000;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.set_fee_config(&60, &40);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    contract.set_reclaim_grace_period(&admin, &GRACE);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+#[test]
+fn test_admin_reclaim_only_after_grace_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    let treasury = Address::generate(&env);
+
+    let remittance_id =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(EXPIRY), &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE - 1);
+    let result = contract.try_admin_reclaim(&admin, &remittance_id, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE);
+    let result = contract.try_admin_reclaim(&admin, &remittance_id, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+    contract.admin_reclaim(&admin, &remittance_id, &treasury);
+
+    // Principal plus the 6 treasury and 4 agent settlement fees reserved at creation
+    assert_eq!(token.balance(&treasury), 1010);
+    assert_eq!(contract.get_tvl(&token.address), 0);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Reclaimed);
+
+    let result = contract.try_refund_remittance(&sender, &remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_admin_reclaim_swept_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    let treasury = Address::generate(&env);
+
+    let remittance_id =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(EXPIRY), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    contract.sweep_expired(&vec![&env, remittance_id]);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+    contract.admin_reclaim(&admin, &remittance_id, &treasury);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Reclaimed);
+}
+
+#[test]
+fn test_admin_reclaim_rejects_remittance_without_expiry_or_settled() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    let treasury = Address::generate(&env);
+
+    let open_ended = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let settled =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(EXPIRY), &None, &None, &None);
+    contract.confirm_payout(&settled);
+
+    env.ledger().with_mut(|li| li.timestamp = u64::MAX / 2);
+    let result = contract.try_admin_reclaim(&admin, &open_ended, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+    let result = contract.try_admin_reclaim(&admin, &settled, &treasury);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_admin_reclaim_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(EXPIRY), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+
+    let result = contract.try_admin_reclaim(&sender, &remittance_id, &sender);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
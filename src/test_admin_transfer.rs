@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, admin)
+}
+
+#[test]
+fn test_propose_and_accept_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    contract.propose_admin(&admin, &new_admin);
+    assert!(!contract.is_admin(&new_admin));
+    assert_eq!(contract.get_pending_admin().unwrap().address, new_admin);
+
+    contract.accept_admin();
+    assert!(contract.is_admin(&new_admin));
+    assert!(contract.get_pending_admin().is_none());
+
+    // The new admin can now act, including removing the original admin
+    contract.remove_admin(&new_admin, &admin);
+    assert!(!contract.is_admin(&admin));
+    contract.update_fee(&300);
+    assert_eq!(contract.get_platform_fee_bps(), 300);
+}
+
+#[test]
+fn test_proposal_is_overwritten() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    contract.propose_admin(&admin, &first);
+    contract.propose_admin(&admin, &second);
+    contract.accept_admin();
+
+    assert!(!contract.is_admin(&first));
+    assert!(contract.is_admin(&second));
+}
+
+#[test]
+fn test_accept_admin_after_expiry_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    contract.propose_admin(&admin, &new_admin);
+    let expires_at = contract.get_pending_admin().unwrap().expires_at;
+    env.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+
+    let result = contract.try_accept_admin();
+    assert_eq!(result, Err(Ok(ContractError::AdminProposalExpired)));
+    assert!(!contract.is_admin(&new_admin));
+}
+
+#[test]
+fn test_accept_admin_without_proposal_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin) = setup(&env);
+
+    let result = contract.try_accept_admin();
+    assert_eq!(result, Err(Ok(ContractError::NoPendingAdmin)));
+}
+
+#[test]
+fn test_cannot_remove_last_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+
+    let result = contract.try_remove_admin(&admin, &admin);
+    assert_eq!(result, Err(Ok(ContractError::CannotRemoveLastAdmin)));
+}
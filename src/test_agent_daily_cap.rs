@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use crate::{BatchSettlementEntry, ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient, SECONDS_PER_DAY};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+#[test]
+fn test_agent_daily_cap_is_enforced() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_agent_daily_cap(&admin, &agent, &1_500);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (1_000, 1_500));
+
+    let result = contract.try_confirm_payout(&second);
+    assert_eq!(result, Err(Ok(ContractError::AgentDailyCapExceeded)));
+
+    // Partial settlements count towards the cap as they are paid out
+    contract.settle_partial(&second, &500);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (1_500, 1_500));
+}
+
+#[test]
+fn test_settle_batch_reports_cap_per_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_agent_daily_cap(&admin, &agent, &2_500);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let third = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let fourth = contract.create_remittance(&sender, &agent, &token, &500, &None, &None, &None, &None);
+
+    // The third ID would take the agent to 3000; the fourth still fits
+    let results = contract.settle_batch(&vec![&env, first, second, third, fourth]);
+    assert_eq!(results.get_unchecked(0), (first, Ok(())));
+    assert_eq!(results.get_unchecked(1), (second, Ok(())));
+    assert_eq!(results.get_unchecked(2), (third, Err(ContractError::AgentDailyCapExceeded)));
+    assert_eq!(results.get_unchecked(3), (fourth, Ok(())));
+
+    assert_eq!(contract.get_agent_daily_usage(&agent), (2_500, 2_500));
+    assert_eq!(contract.get_remittance(&third).status, RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&fourth).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_netting_batch_over_cap_fails_before_any_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_agent_daily_cap(&admin, &agent, &1_500);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let entries = vec![
+        &env,
+        BatchSettlementEntry { remittance_id: first },
+        BatchSettlementEntry { remittance_id: second },
+    ];
+
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert!(matches!(result, Err(Ok(ContractError::AgentDailyCapExceeded))));
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, 1_500));
+    assert_eq!(token::Client::new(&env, &token).balance(&agent), 0);
+}
+
+#[test]
+fn test_agent_daily_cap_resets_at_day_boundary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY - 10);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_agent_daily_cap(&admin, &agent, &1_000);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, 1_000));
+    contract.confirm_payout(&second);
+}
+
+#[test]
+fn test_agent_without_cap_is_unlimited() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, i128::MAX));
+
+    contract.set_agent_daily_cap(&admin, &agent, &100);
+    contract.set_agent_daily_cap(&admin, &agent, &0);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &50_000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (50_000, i128::MAX));
+}
+
+#[test]
+fn test_set_agent_daily_cap_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, agent, _admin) = setup(&env);
+
+    let result = contract.try_set_agent_daily_cap(&agent, &agent, &1_000);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
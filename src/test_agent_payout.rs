@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    // 1% settlement fee for the agent on top of the principal
+    contract.set_fee_config(&0, &100);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_payout_defaults_to_agent_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    assert_eq!(contract.get_agent_payout(&agent), agent);
+
+    // The agent is paid its 975 share of the principal plus the 10 agent fee
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(token.balance(&agent), 985);
+}
+
+#[test]
+fn test_fees_land_at_payout_address_while_agent_authorizes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let vault = Address::generate(&env);
+    contract.set_agent_payout(&agent, &Some(vault.clone()));
+    assert_eq!(env.auths()[0].0, agent);
+    assert_eq!(contract.get_agent_payout(&agent), vault);
+    assert_eq!(contract.get_agent(&agent).payout, vault);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+
+    // Settlement was authorized by the agent's own key, not the payout address
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == agent));
+    assert!(!auths.iter().any(|(address, _)| *address == vault));
+
+    assert_eq!(token.balance(&vault), 985);
+    assert_eq!(token.balance(&agent), 0);
+
+    // Clearing the payout address pays the agent itself again
+    contract.set_agent_payout(&agent, &None);
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(token.balance(&agent), 985);
+    assert_eq!(token.balance(&vault), 985);
+}
+
+#[test]
+fn test_payout_address_receives_only_the_fee_for_third_party_recipients() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let vault = Address::generate(&env);
+    contract.set_agent_payout(&agent, &Some(vault.clone()));
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    let recipient = Address::generate(&env);
+    contract.update_recipient(&id, &recipient);
+    contract.confirm_payout(&id);
+
+    assert_eq!(token.balance(&recipient), 975);
+    assert_eq!(token.balance(&vault), 10);
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_set_agent_payout_validates_agent_and_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, agent) = setup(&env);
+
+    let result = contract.try_set_agent_payout(&agent, &Some(contract.address.clone()));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+
+    let stranger = Address::generate(&env);
+    let result = contract.try_set_agent_payout(&stranger, &Some(Address::generate(&env)));
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent)
+}
+
+#[test]
+fn test_agent_stats_track_settlements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    assert_eq!(contract.get_agent_stats(&agent), (0, 0));
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+    assert_eq!(contract.get_agent_stats(&agent), (1, 1000));
+
+    // Partial tranches add volume but only count once the remittance completes
+    let second = contract.create_remittance(&sender, &agent, &token, &500, &None, &None, &None, &None);
+    contract.settle_partial(&second, &200);
+    assert_eq!(contract.get_agent_stats(&agent), (1, 1200));
+    contract.settle_partial(&second, &300);
+    assert_eq!(contract.get_agent_stats(&agent), (2, 1500));
+}
+
+#[test]
+fn test_agent_stats_unknown_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent) = setup(&env);
+
+    let result = contract.try_get_agent_stats(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+}
+
+#[test]
+fn test_agent_stats_kept_after_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    contract.remove_agent(&agent);
+
+    assert_eq!(contract.get_agent_stats(&agent), (1, 1000));
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::{AgentStatus, ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent)
+}
+
+#[test]
+fn test_suspended_agent_cannot_settle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.suspend_agent(&agent);
+    assert_eq!(contract.get_agent_status(&agent), AgentStatus::Suspended);
+
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::AgentSuspended)));
+    let result = contract.try_settle_partial(&remittance_id, &100);
+    assert_eq!(result, Err(Ok(ContractError::AgentSuspended)));
+
+    // Suspension keeps the agent registered
+    assert!(contract.is_agent_registered(&agent));
+}
+
+#[test]
+fn test_reinstated_agent_can_settle_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.suspend_agent(&agent);
+    contract.reinstate_agent(&agent);
+    assert_eq!(contract.get_agent_status(&agent), AgentStatus::Active);
+
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_suspend_unknown_agent_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, agent) = setup(&env);
+
+    let result = contract.try_suspend_agent(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+
+    // Removing a suspended agent still works
+    contract.suspend_agent(&agent);
+    contract.remove_agent(&agent);
+    assert!(!contract.is_agent_registered(&agent));
+}
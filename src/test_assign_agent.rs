@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(
+    env: &'a Env,
+) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+    let other_agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    for a in [&agent, &other_agent] {
+        contract.register_agent(a, &None);
+        contract.assign_role(&admin, a, &Role::Settler);
+    }
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent, other_agent)
+}
+
+#[test]
+fn test_assigned_agent_settles() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent, other_agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.assign_agent(&sender, &remittance_id, &other_agent);
+    assert_eq!(contract.get_remittance(&remittance_id).assigned_agent, Some(other_agent.clone()));
+
+    contract.confirm_payout(&remittance_id);
+
+    // The assigned agent authorized the settlement and is credited with it
+    assert_eq!(env.auths()[0].0, other_agent);
+    assert_eq!(contract.get_agent_stats(&other_agent), (1, 1000));
+    assert_eq!(contract.get_agent_stats(&agent), (0, 0));
+
+    // The payout goes to the assigned agent, not the one it replaced
+    assert_eq!(token.balance(&other_agent), 975);
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_assign_agent_keeps_redirected_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent, other_agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.update_recipient(&remittance_id, &recipient);
+    contract.assign_agent(&sender, &remittance_id, &other_agent);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(token.balance(&recipient), 975);
+    assert_eq!(token.balance(&other_agent), 0);
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_unassigned_agent_cannot_settle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin, sender, agent, other_agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    // Admins may reassign too
+    contract.assign_agent(&admin, &remittance_id, &other_agent);
+
+    // Only the original agent signs, so settlement is rejected
+    let result = contract
+        .mock_auths(&[MockAuth {
+            address: &agent,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address,
+                fn_name: "confirm_payout",
+                args: (remittance_id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_confirm_payout(&remittance_id);
+    assert!(result.is_err());
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_assign_agent_requires_sender_or_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent, other_agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let result = contract.try_assign_agent(&Address::generate(&env), &remittance_id, &other_agent);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = contract.try_assign_agent(&sender, &remittance_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+}
+
+#[test]
+fn test_assignment_cleared_on_cancel_and_locked_after_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent, other_agent) = setup(&env);
+
+    let cancelled = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.assign_agent(&sender, &cancelled, &other_agent);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(contract.get_remittance(&cancelled).assigned_agent, None);
+
+    let settled = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&settled, &400);
+    let result = contract.try_assign_agent(&sender, &settled, &other_agent);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::{
+    BatchSettlementEntry, ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient,
+    MAX_BATCH_SIZE,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, Vec,
+};
+
+const EXPIRY: u64 = 2_000;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+/// One more ID than the limit, led by a remittance that would otherwise be processed
+fn oversized_ids(env: &Env, first: u64) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    ids.push_back(first);
+    for id in 1..=MAX_BATCH_SIZE as u64 {
+        ids.push_back(first + id);
+    }
+    ids
+}
+
+fn oversized_addresses(env: &Env) -> Vec<Address> {
+    let mut addresses = Vec::new(env);
+    for _ in 0..=MAX_BATCH_SIZE {
+        addresses.push_back(Address::generate(env));
+    }
+    addresses
+}
+
+#[test]
+fn test_settle_batch_rejects_oversized_batch_before_paying_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+
+    let result = contract.try_settle_batch(&oversized_ids(&env, id));
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Pending);
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_netting_settlement_rejects_oversized_batch_before_paying_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    let mut entries = Vec::new(&env);
+    for remittance_id in oversized_ids(&env, id).iter() {
+        entries.push_back(BatchSettlementEntry { remittance_id });
+    }
+
+    let result = contract.try_batch_settle_with_netting(&entries);
+    assert!(matches!(result, Err(Ok(ContractError::BatchTooLarge))));
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Pending);
+    assert_eq!(token.balance(&agent), 0);
+}
+
+#[test]
+fn test_sweep_expired_rejects_oversized_batch_before_expiring() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &Some(EXPIRY), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+
+    let result = contract.try_sweep_expired(&oversized_ids(&env, id));
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_register_agents_rejects_oversized_batch_before_registering() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, _admin) = setup(&env);
+
+    let agents = oversized_addresses(&env);
+    let result = contract.try_register_agents(&agents);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+    assert!(!contract.is_agent_registered(&agents.get_unchecked(0)));
+}
+
+#[test]
+fn test_whitelist_tokens_rejects_oversized_batch_before_whitelisting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, admin) = setup(&env);
+
+    let tokens = oversized_addresses(&env);
+    let result = contract.try_whitelist_tokens(&admin, &tokens);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+    assert!(!contract.is_token_whitelisted(&tokens.get_unchecked(0)));
+}
+
+#[test]
+fn test_split_remittance_rejects_oversized_batch_before_escrowing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let mut recipients = Vec::new(&env);
+    for recipient in oversized_addresses(&env).iter() {
+        recipients.push_back((recipient, 10i128));
+    }
+
+    let result = contract.try_create_split_remittance(&sender, &agent, &token.address, &recipients, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+    assert_eq!(token.balance(&sender), 100_000);
+    assert_eq!(token.balance(&contract.address), 0);
+}
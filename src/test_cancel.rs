@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_cancel_pending_remittance_refunds_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.cancel_remittance(&remittance_id);
+
+    assert_eq!(token.balance(&sender), 10_000);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Cancelled);
+
+    // Cancelled remittances can no longer be settled or cancelled again
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    let result = contract.try_cancel_remittance(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_cancel_after_partial_settlement_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &400);
+
+    let result = contract.try_cancel_remittance(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::PartiallySettled);
+    assert_eq!(remittance.remaining, 600);
+}
+
+#[test]
+fn test_cancel_after_settlement_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let result = contract.try_cancel_remittance(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(token.balance(&sender), 9_000);
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use crate::{CircuitBreakerConfig, ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, IntoVal, Symbol,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &1_000_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+/// Creates and fully settles a remittance, returning the settlement's contract error if any.
+fn settle(contract: &SwiftRemitContractClient, token: &Address, sender: &Address, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let id = contract.create_remittance(sender, agent, token, &amount, &None, &None, &None, &None);
+    match contract.try_confirm_payout(&id) {
+        Ok(Ok(_)) => Ok(()),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected host error"),
+    }
+}
+
+fn tripped(env: &Env) -> bool {
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics == (symbol_short!("contract"), Symbol::new(env, "circuit_tripped")).into_val(env)
+    })
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    assert_eq!(contract.get_volume_circuit_breaker(), CircuitBreakerConfig { threshold: 0, window_seconds: 0 });
+    settle(&contract, &token, &sender, &agent, 500_000).unwrap();
+    assert!(!contract.is_paused());
+}
+
+#[test]
+fn test_surge_trips_breaker_and_pauses() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    contract.set_volume_circuit_breaker(&admin, &2_000, &3_600);
+
+    // Reaching the threshold exactly does not trip
+    settle(&contract, &token, &sender, &agent, 1_000).unwrap();
+    settle(&contract, &token, &sender, &agent, 1_000).unwrap();
+    assert!(!contract.is_paused());
+
+    // The settlement that crosses the threshold completes, then the contract pauses
+    settle(&contract, &token, &sender, &agent, 1).unwrap();
+    assert!(tripped(&env));
+    assert!(contract.is_paused());
+
+    let result = contract.try_create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+    // Admins can resume manually
+    contract.unpause(&admin);
+    assert!(!contract.is_paused());
+}
+
+#[test]
+fn test_window_boundary_does_not_false_trip_or_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    contract.set_volume_circuit_breaker(&admin, &2_000, &3_600);
+
+    settle(&contract, &token, &sender, &agent, 1_500).unwrap();
+
+    // Halfway into the next window, half the earlier volume still counts: 750 + 1_250
+    env.ledger().with_mut(|li| li.timestamp = 3_600 * 2 + 1_800);
+    settle(&contract, &token, &sender, &agent, 1_250).unwrap();
+    assert!(!contract.is_paused());
+
+    // A full window later the old volume has drained away entirely
+    env.ledger().with_mut(|li| li.timestamp = 3_600 * 4);
+    settle(&contract, &token, &sender, &agent, 2_000).unwrap();
+    assert!(!contract.is_paused());
+}
+
+#[test]
+fn test_burst_straddling_boundary_trips() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 3_600 * 2 - 10);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    contract.set_volume_circuit_breaker(&admin, &2_000, &3_600);
+
+    settle(&contract, &token, &sender, &agent, 1_500).unwrap();
+    env.ledger().with_mut(|li| li.timestamp = 3_600 * 2 + 10);
+    settle(&contract, &token, &sender, &agent, 600).unwrap();
+
+    assert!(contract.is_paused());
+}
+
+#[test]
+fn test_batch_settlement_stops_once_tripped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    contract.set_volume_circuit_breaker(&admin, &1_500, &3_600);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    let third = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+
+    let outcomes = contract.settle_batch(&vec![&env, first, second, third]);
+    assert_eq!(outcomes.get_unchecked(0), (first, Ok(())));
+    assert_eq!(outcomes.get_unchecked(1), (second, Ok(())));
+    assert_eq!(outcomes.get_unchecked(2), (third, Err(ContractError::ContractPaused)));
+}
+
+#[test]
+fn test_threshold_zero_disables_and_config_is_validated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let result = contract.try_set_volume_circuit_breaker(&admin, &-1, &3_600);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    let result = contract.try_set_volume_circuit_breaker(&admin, &1_000, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    let result = contract.try_set_volume_circuit_breaker(&agent, &1_000, &3_600);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    contract.set_volume_circuit_breaker(&admin, &1_000, &3_600);
+    contract.set_volume_circuit_breaker(&admin, &0, &0);
+    settle(&contract, &token, &sender, &agent, 5_000).unwrap();
+    assert!(!contract.is_paused());
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceMode, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent)
+}
+
+#[test]
+fn test_recipient_claims_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let remittance_id = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.mode, RemittanceMode::Claim);
+    assert_eq!(remittance.recipient, recipient);
+
+    contract.claim_remittance(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(remittance.remaining, 0);
+    assert_eq!(token.balance(&recipient), 975);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+}
+
+#[test]
+fn test_claim_requires_recipient_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let remittance_id = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    contract.claim_remittance(&remittance_id);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, recipient);
+}
+
+#[test]
+fn test_claim_rejects_agent_mode_and_agent_rejects_claim_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let agent_mode = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let claim_mode = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+
+    let result = contract.try_claim_remittance(&agent_mode);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+
+    assert_eq!(contract.try_confirm_payout(&claim_mode), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.try_settle_partial(&claim_mode, &100), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(
+        contract.try_assign_agent(&sender, &claim_mode, &agent),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        contract.settle_batch(&vec![&env, claim_mode]),
+        vec![&env, (claim_mode, Err(ContractError::InvalidStatus))]
+    );
+    assert_eq!(contract.get_remittance(&claim_mode).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_claim_after_expiry_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, _admin, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let remittance_id =
+        contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &Some(2_000), &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    let result = contract.try_claim_remittance(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+}
+
+#[test]
+fn test_claim_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let remittance_id = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    contract.claim_remittance(&remittance_id);
+
+    let result = contract.try_claim_remittance(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(token.balance(&recipient), 975);
+}
+
+#[test]
+fn test_claimable_remittance_reserves_no_agent_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+    contract.set_fee_config(&100, &50);
+
+    let remittance_id = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.treasury_fee, 10);
+    assert_eq!(remittance.agent_fee, 0);
+    assert_eq!(token.balance(&sender), 10_000 - 1010);
+
+    contract.claim_remittance(&remittance_id);
+    assert_eq!(token.balance(&recipient), 975);
+    assert_eq!(contract.get_treasury_balance(&token.address), 10);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::{Config, FeeRounding, SwiftRemitContract, SwiftRemitContractClient, DEFAULT_RECLAIM_GRACE_PERIOD};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, admin)
+}
+
+#[test]
+fn test_get_config_returns_defaults() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin) = setup(&env);
+
+    assert_eq!(
+        contract.get_config(),
+        Config {
+            paused: false,
+            platform_fee_bps: 250,
+            protocol_fee_bps: 0,
+            treasury_bps: 0,
+            agent_bps: 0,
+            min_remittance_amount: 0,
+            max_remittance_amount: i128::MAX,
+            default_daily_limit: i128::MAX,
+            rate_limit_cooldown: 0,
+            default_expiry: 0,
+            timelock_delay: 0,
+            reclaim_grace_period: DEFAULT_RECLAIM_GRACE_PERIOD,
+            settlement_grace: 0,
+            settlement_reversal_window: 0,
+            require_token_whitelist: true,
+            fee_rounding: FeeRounding::Down,
+        }
+    );
+}
+
+#[test]
+fn test_get_config_reflects_updates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+
+    contract.pause(&admin);
+    contract.update_fee(&300);
+    contract.set_fee_config(&100, &50);
+    contract.set_max_remittance_amount(&admin, &50_000);
+    contract.set_default_daily_limit(&admin, &100_000);
+    contract.set_rate_limit(&admin, &60);
+    contract.set_default_expiry(&86_400);
+    contract.set_reclaim_grace_period(&admin, &604_800);
+    contract.set_settlement_grace(&admin, &30);
+    contract.set_settlement_reversal_window(&admin, &600);
+    contract.set_require_token_whitelist(&admin, &false);
+    contract.set_fee_rounding(&admin, &FeeRounding::Up);
+
+    assert_eq!(
+        contract.get_config(),
+        Config {
+            paused: true,
+            platform_fee_bps: 300,
+            protocol_fee_bps: 0,
+            treasury_bps: 100,
+            agent_bps: 50,
+            min_remittance_amount: 0,
+            max_remittance_amount: 50_000,
+            default_daily_limit: 100_000,
+            rate_limit_cooldown: 60,
+            default_expiry: 86_400,
+            timelock_delay: 0,
+            reclaim_grace_period: 604_800,
+            settlement_grace: 30,
+            settlement_reversal_window: 600,
+            require_token_whitelist: false,
+            fee_rounding: FeeRounding::Up,
+        }
+    );
+}
@@ -0,0 +1,229 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+struct Setup<'a> {
+    contract: SwiftRemitContractClient<'a>,
+    usdc: token::Client<'a>,
+    local: token::Client<'a>,
+    admin: Address,
+    sender: Address,
+    agent: Address,
+    recipient: Address,
+}
+
+fn setup<'a>(env: &'a Env, fee_bps: u32, sender_funds: i128) -> Setup<'a> {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+    let recipient = Address::generate(env);
+
+    let usdc = create_token_contract(env, &admin);
+    usdc.mint(&sender, &sender_funds);
+    let local = create_token_contract(env, &admin);
+    local.mint(&agent, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &usdc.address, true);
+    });
+    contract.initialize(&admin, &usdc.address, &fee_bps, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    Setup {
+        contract,
+        usdc: token::Client::new(env, &usdc.address),
+        local: token::Client::new(env, &local.address),
+        admin,
+        sender,
+        agent,
+        recipient,
+    }
+}
+
+/// Creates a remittance whose payout is redirected to the recipient.
+fn create_for_recipient(s: &Setup, amount: i128) -> u64 {
+    let id = s.contract.create_remittance(&s.sender, &s.agent, &s.usdc.address, &amount, &None, &None, &None, &None);
+    s.contract.update_recipient(&id, &s.recipient);
+    id
+}
+
+#[test]
+fn test_converted_payout_pays_recipient_in_out_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &15_000);
+
+    let id = create_for_recipient(&s, 1000);
+    s.contract.confirm_payout_in_token(&id, &s.local.address, &0);
+
+    // 975 net at 1.5x is 1462.5, rounded down
+    assert_eq!(s.local.balance(&s.recipient), 1462);
+    assert_eq!(s.local.balance(&s.agent), 100_000 - 1462);
+    assert_eq!(s.usdc.balance(&s.agent), 975);
+    assert_eq!(s.usdc.balance(&s.recipient), 0);
+    assert_eq!(s.contract.get_accumulated_fees(&s.usdc.address), 25);
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_converted_payout_rounds_down() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+
+    // 975 * 0.3333 = 324.9675
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &3_333);
+    let id = create_for_recipient(&s, 1000);
+    s.contract.confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(s.local.balance(&s.recipient), 324);
+
+    // A rate that converts a tiny remittance to less than one unit delivers nothing
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &1);
+    let id = create_for_recipient(&s, 100);
+    s.contract.confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(s.local.balance(&s.recipient), 324);
+    assert_eq!(s.usdc.balance(&s.agent), 975 + 98);
+}
+
+#[test]
+fn test_converted_payout_succeeds_when_min_out_is_met() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &15_000);
+
+    let id = create_for_recipient(&s, 1000);
+    s.contract.confirm_payout_in_token(&id, &s.local.address, &1462);
+
+    assert_eq!(s.local.balance(&s.recipient), 1462);
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_converted_payout_rejects_agent_short_of_out_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 100_000);
+
+    // 97_500 net at 1.5x needs 146_250 of the agent's 100_000
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &15_000);
+    let id = create_for_recipient(&s, 100_000);
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(result, Err(Ok(ContractError::InsufficientLiquidity)));
+
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+    assert_eq!(s.local.balance(&s.agent), 100_000);
+    assert_eq!(s.usdc.balance(&s.contract.address), 100_000);
+}
+
+#[test]
+fn test_converted_payout_below_min_out_changes_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &15_000);
+    let id = create_for_recipient(&s, 1000);
+
+    // A rate cut lands before the agent's settlement quoted at 1.5x
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &14_000);
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &1462);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+
+    let remittance = s.contract.get_remittance(&id);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(remittance.remaining, 1000);
+    assert_eq!(s.local.balance(&s.recipient), 0);
+    assert_eq!(s.local.balance(&s.agent), 100_000);
+    assert_eq!(s.usdc.balance(&s.agent), 0);
+    assert_eq!(s.usdc.balance(&s.contract.address), 1000);
+    assert_eq!(s.contract.get_accumulated_fees(&s.usdc.address), 0);
+
+    // The same guard applies when paying out in the escrow token
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.usdc.address, &976);
+    assert_eq!(result, Err(Ok(ContractError::SlippageExceeded)));
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_same_token_payout_matches_confirm_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+
+    let id = create_for_recipient(&s, 1000);
+    s.contract.confirm_payout_in_token(&id, &s.usdc.address, &0);
+
+    assert_eq!(s.usdc.balance(&s.recipient), 975);
+    assert_eq!(s.local.balance(&s.recipient), 0);
+}
+
+#[test]
+fn test_unconfigured_pair_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+    let id = create_for_recipient(&s, 1000);
+
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    // Rates are directional
+    s.contract.set_conversion_rate(&s.admin, &s.local.address, &s.usdc.address, &10_000);
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    // Clearing a rate removes the pair
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &10_000);
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &0);
+    assert_eq!(s.contract.get_conversion_rate(&s.usdc.address, &s.local.address), None);
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_converted_amount_overflow_leaves_remittance_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let amount = i128::MAX / 2;
+    let s = setup(&env, 0, amount);
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &20_000);
+
+    let id = create_for_recipient(&s, amount);
+    let result = s.contract.try_confirm_payout_in_token(&id, &s.local.address, &0);
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+
+    let remittance = s.contract.get_remittance(&id);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(remittance.remaining, amount);
+    assert_eq!(s.usdc.balance(&s.contract.address), amount);
+}
+
+#[test]
+fn test_set_conversion_rate_requires_admin_and_distinct_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env, 250, 10_000);
+
+    let result = s.contract.try_set_conversion_rate(&s.agent, &s.usdc.address, &s.local.address, &10_000);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = s.contract.try_set_conversion_rate(&s.admin, &s.usdc.address, &s.usdc.address, &10_000);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+
+    s.contract.set_conversion_rate(&s.admin, &s.usdc.address, &s.local.address, &12_500);
+    assert_eq!(s.contract.get_conversion_rate(&s.usdc.address, &s.local.address), Some(12_500));
+}
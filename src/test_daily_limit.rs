@@ -0,0 +1,213 @@
+#![cfg(test)]
+
+use crate::storage::DataKey;
+use crate::{
+    ContractError, Role, SwiftRemitContract, SwiftRemitContractClient, SECONDS_PER_DAY, USAGE_TTL_LEDGERS,
+};
+use soroban_sdk::{
+    testutils::{storage::Temporary as _, Address as _, Ledger},
+    token, vec, Address, Env, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(
+    env: &'a Env,
+) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let usdc = create_token_contract(env, &admin);
+    usdc.mint(&sender, &100_000);
+    let eurc = create_token_contract(env, &admin);
+    eurc.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &usdc.address, true);
+    });
+    contract.initialize(&admin, &usdc.address, &250, &0, &admin, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, usdc.address, eurc.address, sender, agent, admin)
+}
+
+#[test]
+fn test_daily_limit_is_enforced_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent, admin) = setup(&env);
+
+    contract.set_daily_limit(&admin, &usdc, &1_500);
+    contract.set_daily_limit(&admin, &eurc, &5_000);
+
+    contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    let result = contract.try_create_remittance(&sender, &agent, &usdc, &501, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::DailySendLimitExceeded)));
+
+    // Usage in one token does not count against another
+    contract.create_remittance(&sender, &agent, &eurc, &4_000, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &usdc, &500, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_daily_limit_resets_at_day_boundary() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY - 10);
+    let (contract, usdc, _eurc, sender, agent, admin) = setup(&env);
+
+    contract.set_daily_limit(&admin, &usdc, &1_000);
+    contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    let result = contract.try_create_remittance(&sender, &agent, &usdc, &1, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::DailySendLimitExceeded)));
+
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY);
+    contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_default_daily_limit_applies_without_explicit_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent, admin) = setup(&env);
+
+    // Unlimited until a default is configured
+    assert_eq!(contract.get_daily_limit(&eurc), i128::MAX);
+
+    contract.set_default_daily_limit(&admin, &2_000);
+    contract.set_daily_limit(&admin, &usdc, &10_000);
+    assert_eq!(contract.get_daily_limit(&eurc), 2_000);
+
+    let result = contract.try_create_remittance(&sender, &agent, &eurc, &2_001, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::DailySendLimitExceeded)));
+    contract.create_remittance(&sender, &agent, &usdc, &5_000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_create_remittance_rejects_non_whitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _usdc, _eurc, sender, agent, _admin) = setup(&env);
+
+    let admin = Address::generate(&env);
+    let other = create_token_contract(&env, &admin);
+    other.mint(&sender, &1_000);
+
+    let result = contract.try_create_remittance(&sender, &agent, &other.address, &100, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+}
+
+#[test]
+fn test_settlement_pays_out_in_remittance_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &eurc, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(token::Client::new(&env, &eurc).balance(&agent), 975);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&agent), 0);
+    assert_eq!(contract.get_accumulated_fees(&eurc), 25);
+    assert_eq!(contract.get_accumulated_fees(&usdc), 0);
+}
+
+#[test]
+fn test_get_daily_usage_matches_enforcement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY - 10);
+    let (contract, usdc, _eurc, sender, agent, admin) = setup(&env);
+
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (0, i128::MAX));
+
+    contract.set_default_daily_limit(&admin, &2_000);
+    contract.create_remittance(&sender, &agent, &usdc, &1_200, &None, &None, &None, &None);
+    let (used, limit) = contract.get_daily_usage(&sender, &usdc);
+    assert_eq!((used, limit), (1_200, 2_000));
+
+    // The reported allowance is exactly what can still be sent
+    let result = contract.try_create_remittance(&sender, &agent, &usdc, &(limit - used + 1), &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::DailySendLimitExceeded)));
+    contract.create_remittance(&sender, &agent, &usdc, &(limit - used), &None, &None, &None, &None);
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (2_000, 2_000));
+
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY - 1);
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (2_000, 2_000));
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY);
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (0, 2_000));
+}
+
+#[test]
+fn test_usage_buckets_are_temporary_and_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, _eurc, sender, agent, _admin) = setup(&env);
+
+    contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+
+    env.as_contract(&contract.address, || {
+        let key = DataKey::DailyUsage(sender.clone(), 0);
+        assert!(env.storage().temporary().has(&key));
+        assert_eq!(env.storage().temporary().get_ttl(&key), USAGE_TTL_LEDGERS);
+    });
+}
+
+#[test]
+fn test_prune_daily_usage_removes_only_stale_buckets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY - 10);
+    let (contract, usdc, _eurc, sender, agent, admin) = setup(&env);
+
+    contract.set_daily_limit(&admin, &usdc, &1_000);
+    contract.set_agent_daily_cap(&admin, &agent, &5_000);
+    let remittance_id = contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    // Nothing is stale yet
+    let users = vec![&env, sender.clone(), agent.clone()];
+    assert_eq!(contract.prune_daily_usage(&users), 0);
+
+    // Across the boundary the old buckets no longer count, even before pruning
+    env.ledger().with_mut(|li| li.timestamp = SECONDS_PER_DAY);
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (0, 1_000));
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, 5_000));
+    contract.create_remittance(&sender, &agent, &usdc, &400, &None, &None, &None, &None);
+
+    assert_eq!(contract.prune_daily_usage(&users), 2);
+    assert_eq!(contract.prune_daily_usage(&users), 0);
+
+    env.as_contract(&contract.address, || {
+        let storage = env.storage().temporary();
+        assert!(!storage.has(&DataKey::DailyUsage(sender.clone(), 0)));
+        assert!(!storage.has(&DataKey::AgentDailyUsage(agent.clone(), 0)));
+        assert!(storage.has(&DataKey::DailyUsage(sender.clone(), 1)));
+    });
+    assert_eq!(contract.get_daily_usage(&sender, &usdc), (400, 1_000));
+}
+
+#[test]
+fn test_prune_daily_usage_rejects_oversized_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _usdc, _eurc, _sender, _agent, _admin) = setup(&env);
+
+    let mut users = Vec::new(&env);
+    for _ in 0..101 {
+        users.push_back(Address::generate(&env));
+    }
+
+    let result = contract.try_prune_daily_usage(&users);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+}
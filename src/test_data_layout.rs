@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use crate::migration::LegacyDataKey;
+use crate::storage::DataKey;
+use crate::{
+    CircuitBreakerConfig, ContractError, SwiftRemitContract, SwiftRemitContractClient,
+    CURRENT_DATA_VERSION, LEGACY_DATA_VERSION,
+};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, admin, token.address)
+}
+
+/// Rewind a freshly initialized contract to the legacy layout with one token
+/// limit, a default limit, an agent cap and a breaker config under old keys.
+fn write_legacy_layout(env: &Env, contract: &Address, token: &Address, agent: &Address) {
+    env.as_contract(contract, || {
+        crate::storage::set_data_version(env, LEGACY_DATA_VERSION);
+        env.storage().instance().set(&LegacyDataKey::Default, &900i128);
+        env.storage().instance().set(
+            &LegacyDataKey::BreakerConfig,
+            &CircuitBreakerConfig {
+                threshold: 50_000,
+                window_seconds: 3_600,
+            },
+        );
+        env.storage()
+            .persistent()
+            .set(&LegacyDataKey::Token(token.clone()), &500i128);
+        env.storage()
+            .persistent()
+            .set(&LegacyDataKey::AgentCap(agent.clone()), &700i128);
+    });
+}
+
+#[test]
+fn test_initialize_records_current_data_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin, _token) = setup(&env);
+
+    assert_eq!(contract.contract_data_version(), CURRENT_DATA_VERSION);
+}
+
+#[test]
+fn test_legacy_keys_are_read_until_migrated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, token) = setup(&env);
+    let other = Address::generate(&env);
+    let agent = Address::generate(&env);
+    write_legacy_layout(&env, &contract.address, &token, &agent);
+
+    assert_eq!(contract.contract_data_version(), LEGACY_DATA_VERSION);
+    assert_eq!(contract.get_daily_limit(&token), 500);
+    assert_eq!(contract.get_daily_limit(&other), 900);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, 700));
+    assert_eq!(contract.get_volume_circuit_breaker().threshold, 50_000);
+
+    contract.migrate_data(&admin, &CURRENT_DATA_VERSION, &vec![&env, token.clone()], &vec![&env, agent.clone()]);
+
+    // Same values, now served from the new layout
+    assert_eq!(contract.contract_data_version(), CURRENT_DATA_VERSION);
+    assert_eq!(contract.get_daily_limit(&token), 500);
+    assert_eq!(contract.get_daily_limit(&other), 900);
+    assert_eq!(contract.get_agent_daily_usage(&agent), (0, 700));
+    assert_eq!(contract.get_volume_circuit_breaker().threshold, 50_000);
+
+    env.as_contract(&contract.address, || {
+        assert!(!env.storage().instance().has(&LegacyDataKey::Default));
+        assert!(!env.storage().instance().has(&LegacyDataKey::BreakerConfig));
+        assert!(!env.storage().persistent().has(&LegacyDataKey::Token(token.clone())));
+        assert!(!env.storage().persistent().has(&LegacyDataKey::AgentCap(agent.clone())));
+        assert!(env.storage().instance().has(&DataKey::DefaultDailyLimit));
+        assert!(env.storage().persistent().has(&DataKey::TokenDailyLimit(token.clone())));
+        assert!(env.storage().persistent().has(&DataKey::AgentDailyCap(agent.clone())));
+    });
+}
+
+#[test]
+fn test_migration_keeps_values_written_under_new_layout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, token) = setup(&env);
+    let agent = Address::generate(&env);
+    write_legacy_layout(&env, &contract.address, &token, &agent);
+
+    // A write made after the upgrade but before the migration wins over the legacy copy
+    contract.set_daily_limit(&admin, &token, &300);
+    assert_eq!(contract.get_daily_limit(&token), 300);
+
+    contract.migrate_data(&admin, &CURRENT_DATA_VERSION, &vec![&env, token.clone()], &Vec::new(&env));
+    assert_eq!(contract.get_daily_limit(&token), 300);
+
+    env.as_contract(&contract.address, || {
+        assert!(!env.storage().persistent().has(&LegacyDataKey::Token(token.clone())));
+    });
+}
+
+#[test]
+fn test_legacy_keys_ignored_after_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin, token) = setup(&env);
+
+    env.as_contract(&contract.address, || {
+        env.storage()
+            .persistent()
+            .set(&LegacyDataKey::Token(token.clone()), &500i128);
+    });
+
+    assert_eq!(contract.get_daily_limit(&token), i128::MAX);
+}
+
+#[test]
+fn test_migrate_data_rejects_wrong_target_and_import_in_progress() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, token) = setup(&env);
+    let agent = Address::generate(&env);
+
+    // Already on the current layout, and no later layout exists
+    let result = contract.try_migrate_data(&admin, &(CURRENT_DATA_VERSION + 1), &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+
+    write_legacy_layout(&env, &contract.address, &token, &agent);
+    let result = contract.try_migrate_data(&admin, &LEGACY_DATA_VERSION, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+
+    env.as_contract(&contract.address, || {
+        crate::storage::set_migration_in_progress(&env, true);
+    });
+    let result = contract.try_migrate_data(&admin, &CURRENT_DATA_VERSION, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::MigrationInProgress)));
+
+    // Nothing moved while the guard was up
+    assert_eq!(contract.contract_data_version(), LEGACY_DATA_VERSION);
+    assert_eq!(contract.get_daily_limit(&token), 500);
+}
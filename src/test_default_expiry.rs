@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_default_expiry_applied_when_no_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    // No default configured: remittances never expire
+    let open_ended = contract.create_remittance(&sender, &agent, &token.address, &100, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&open_ended).expiry, None);
+
+    contract.set_default_expiry(&3_600);
+    assert_eq!(contract.get_default_expiry(), 3_600);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &100, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).expiry, Some(4_600));
+}
+
+#[test]
+fn test_expiry_override_takes_precedence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.set_default_expiry(&3_600);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &100, &Some(1_001), &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).expiry, Some(1_001));
+}
+
+#[test]
+fn test_expiry_override_in_past_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    for expiry in [999, 1_000] {
+        let result = contract.try_create_remittance(&sender, &agent, &token.address, &100, &Some(expiry), &None, &None, &None);
+        assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+    }
+}
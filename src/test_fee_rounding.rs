@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::{ContractError, FeeRounding, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+/// 2.5% platform fee and 1% protocol fee paid to a separate treasury.
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+    let treasury = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &treasury, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, treasury, admin)
+}
+
+/// Creates and settles a remittance, returning (platform fee, protocol fee, agent payout).
+fn settle(
+    contract: &SwiftRemitContractClient,
+    token: &token::Client,
+    sender: &Address,
+    agent: &Address,
+    treasury: &Address,
+    amount: i128,
+) -> (i128, i128, i128) {
+    let agent_before = token.balance(agent);
+    let treasury_before = token.balance(treasury);
+
+    let remittance_id = contract.create_remittance(sender, agent, &token.address, &amount, &None, &None, &None, &None);
+    let fee = contract.get_remittance(&remittance_id).fee;
+    contract.confirm_payout(&remittance_id);
+
+    (
+        fee,
+        token.balance(treasury) - treasury_before,
+        token.balance(agent) - agent_before,
+    )
+}
+
+#[test]
+fn test_default_rounding_truncates_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, treasury, _admin) = setup(&env);
+
+    assert_eq!(contract.get_fee_rounding(), FeeRounding::Down);
+
+    // Platform fee 25.475, protocol fee 10.19
+    assert_eq!(settle(&contract, &token, &sender, &agent, &treasury, 1_019), (25, 10, 984));
+}
+
+#[test]
+fn test_round_up_keeps_fraction_for_the_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, treasury, admin) = setup(&env);
+
+    contract.set_fee_rounding(&admin, &FeeRounding::Up);
+
+    // Platform fee 25.475, protocol fee 10.19
+    assert_eq!(settle(&contract, &token, &sender, &agent, &treasury, 1_019), (26, 11, 982));
+}
+
+#[test]
+fn test_round_nearest_rounds_halves_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, treasury, admin) = setup(&env);
+
+    contract.set_fee_rounding(&admin, &FeeRounding::Nearest);
+
+    // Platform fee 25.475, protocol fee 10.19
+    assert_eq!(settle(&contract, &token, &sender, &agent, &treasury, 1_019), (25, 10, 984));
+    // Platform fee 25.5, protocol fee 10.2
+    assert_eq!(settle(&contract, &token, &sender, &agent, &treasury, 1_020), (26, 10, 984));
+    // Platform fee 26.25, protocol fee 10.5
+    assert_eq!(settle(&contract, &token, &sender, &agent, &treasury, 1_050), (26, 11, 1_013));
+}
+
+#[test]
+fn test_set_fee_rounding_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, _treasury, _admin) = setup(&env);
+
+    let outsider = Address::generate(&env);
+    let result = contract.try_set_fee_rounding(&outsider, &FeeRounding::Up);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(contract.get_fee_rounding(), FeeRounding::Down);
+}
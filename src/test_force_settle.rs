@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.set_rate_limit(&admin, &3_600);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+#[test]
+fn test_force_settle_pays_chosen_address_and_logs_audit_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let stuck_agent = Address::generate(&env);
+    contract.register_agent(&stuck_agent, &None);
+    contract.assign_role(&admin, &stuck_agent, &Role::Settler);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.assign_agent(&sender, &remittance_id, &stuck_agent);
+
+    let rescue = Address::generate(&env);
+    contract.force_settle(&admin, &remittance_id, &rescue);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(remittance.remaining, 0);
+    assert_eq!(token.balance(&rescue), 975);
+    assert_eq!(token.balance(&stuck_agent), 0);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics
+                == (
+                    Symbol::new(&env, "remittance"),
+                    Symbol::new(&env, "force_settled"),
+                    remittance.recipient.clone(),
+                    token.address.clone(),
+                )
+                    .into_val(&env)
+        })
+        .expect("force_settled event");
+    let data: Vec<Val> = Vec::try_from_val(&env, &event.2).unwrap();
+    let id: u64 = u64::try_from_val(&env, &data.get(3).unwrap()).unwrap();
+    let forced_by: Address = Address::try_from_val(&env, &data.get(4).unwrap()).unwrap();
+    let original_agent: Address = Address::try_from_val(&env, &data.get(5).unwrap()).unwrap();
+    let to: Address = Address::try_from_val(&env, &data.get(6).unwrap()).unwrap();
+    assert_eq!(id, remittance_id);
+    assert_eq!(forced_by, admin);
+    assert_eq!(original_agent, stuck_agent);
+    assert_eq!(to, rescue);
+
+    // The remittance cannot be settled a second time
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_force_settle_bypasses_rate_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let first = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+
+    let result = contract.try_confirm_payout(&second);
+    assert_eq!(result, Err(Ok(ContractError::RateLimitExceeded)));
+
+    contract.force_settle(&admin, &second, &agent);
+    assert_eq!(contract.get_remittance(&second).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_force_settle_respects_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(20_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 20_001);
+
+    let result = contract.try_force_settle(&admin, &remittance_id, &agent);
+    assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+
+    // Expired funds stay refundable to the sender
+    contract.refund_remittance(&sender, &remittance_id);
+}
+
+#[test]
+fn test_force_settle_requires_admin_and_pending_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let result = contract.try_force_settle(&agent, &remittance_id, &agent);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    contract.settle_partial(&remittance_id, &400);
+    let result = contract.try_force_settle(&admin, &remittance_id, &agent);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
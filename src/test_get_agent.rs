@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use crate::{AgentStatus, ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Bytes, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, token::Client::new(env, &token.address), admin, sender)
+}
+
+#[test]
+fn test_get_agent_returns_profile() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let (contract, token, admin, sender) = setup(&env);
+
+    let agent = Address::generate(&env);
+    let label = Bytes::from_slice(&env, b"US-NG corridor");
+    contract.register_agent(&agent, &Some(label.clone()));
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let profile = contract.get_agent(&agent);
+    assert_eq!(profile.address, agent);
+    assert!(profile.registered);
+    assert_eq!(profile.registered_at, 5_000);
+    assert_eq!(profile.status, AgentStatus::Active);
+    assert_eq!(profile.stats.settlement_count, 1);
+    assert_eq!(profile.stats.total_volume, 1000);
+    assert_eq!(profile.label, Some(label));
+}
+
+#[test]
+fn test_get_agent_not_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _admin, _sender) = setup(&env);
+
+    let result = contract.try_get_agent(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+}
+
+#[test]
+fn test_removed_agent_profile_is_kept() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _admin, _sender) = setup(&env);
+
+    let agent = Address::generate(&env);
+    contract.register_agents(&vec![&env, agent.clone()]);
+    contract.suspend_agent(&agent);
+    contract.remove_agent(&agent);
+
+    let profile = contract.get_agent(&agent);
+    assert!(!profile.registered);
+    assert_eq!(profile.status, AgentStatus::Suspended);
+    assert_eq!(profile.label, None);
+}
+
+#[test]
+fn test_set_agent_label_updates_and_clears() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _sender) = setup(&env);
+
+    let agent = Address::generate(&env);
+    contract.register_agent(&agent, &None);
+
+    let label = Bytes::from_slice(&env, b"Lagos payouts");
+    contract.set_agent_label(&admin, &agent, &Some(label.clone()));
+    assert_eq!(contract.get_agent(&agent).label, Some(label));
+
+    contract.set_agent_label(&admin, &agent, &None);
+    assert_eq!(contract.get_agent(&agent).label, None);
+}
+
+#[test]
+fn test_agent_label_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _sender) = setup(&env);
+
+    let agent = Address::generate(&env);
+    let too_long = Some(Bytes::from_array(&env, &[b'a'; 65]));
+    let result = contract.try_register_agent(&agent, &too_long);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    assert!(!contract.is_agent_registered(&agent));
+
+    let label = Some(Bytes::from_slice(&env, b"Lagos payouts"));
+    contract.register_agent(&agent, &label);
+    let result = contract.try_set_agent_label(&admin, &agent, &too_long);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    assert_eq!(contract.get_agent(&agent).label, label);
+
+    // A label of exactly the maximum length is accepted
+    let longest = Some(Bytes::from_array(&env, &[b'a'; 64]));
+    contract.set_agent_label(&admin, &agent, &longest);
+    assert_eq!(contract.get_agent(&agent).label, longest);
+
+    let stranger = Address::generate(&env);
+    let result = contract.try_set_agent_label(&admin, &stranger, &None);
+    assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
+
+    let outsider = Address::generate(&env);
+    let result = contract.try_set_agent_label(&outsider, &agent, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use crate::{GlobalStats, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+const EXPIRY: u64 = 2_000;
+const GRACE: u64 = 10_000;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    contract.set_reclaim_grace_period(&admin, &GRACE);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+/// Every created remittance is either still active or in exactly one terminal bucket.
+fn assert_balanced(stats: &GlobalStats) {
+    assert_eq!(
+        stats.total_created,
+        stats.active + stats.total_settled + stats.total_cancelled + stats.total_refunded + stats.total_reclaimed
+    );
+}
+
+#[test]
+fn test_global_stats_start_at_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _sender, _agent, _admin) = setup(&env);
+
+    assert_eq!(contract.get_global_stats(), GlobalStats::default());
+    assert_eq!(contract.get_token_volume(&token), 0);
+}
+
+#[test]
+fn test_global_stats_balance_across_full_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let create = |amount: i128, expiry: Option<u64>| {
+        contract.create_remittance(&sender, &agent, &token, &amount, &expiry, &None, &None, &None)
+    };
+    let partial = create(1_000, None);
+    let settled = create(200, None);
+    let cancelled = create(300, None);
+    let forced = create(400, None);
+    let refunded = create(500, Some(EXPIRY));
+    let reclaimed = create(600, Some(EXPIRY));
+    let open = create(700, None);
+    let claimable = contract.create_claimable_remittance(&sender, &recipient, &token, &800, &None, &None);
+    let split = contract.create_split_remittance(
+        &sender,
+        &agent,
+        &token,
+        &vec![&env, (Address::generate(&env), 450i128), (Address::generate(&env), 450i128)],
+        &None,
+        &None,
+    );
+
+    let stats = contract.get_global_stats();
+    assert_eq!((stats.total_created, stats.active), (9, 9));
+    assert_eq!(contract.get_token_volume(&token), 5_400);
+
+    // Topping up adds to the volume without creating a remittance
+    contract.top_up_remittance(&open, &100);
+    assert_eq!(contract.get_global_stats().total_created, 9);
+    assert_eq!(contract.get_token_volume(&token), 5_500);
+
+    // A partial tranche leaves the remittance active
+    contract.settle_partial(&partial, &400);
+    assert_eq!(contract.get_global_stats().active, 9);
+    contract.confirm_payout(&partial);
+    contract.confirm_payout(&settled);
+    contract.claim_remittance(&claimable);
+    contract.settle_split(&split);
+    contract.cancel_remittance(&cancelled);
+    contract.force_settle(&admin, &forced, &Address::generate(&env));
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    contract.sweep_expired(&vec![&env, refunded, reclaimed, open]);
+    contract.refund_remittance(&sender, &refunded);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+    contract.admin_reclaim(&admin, &reclaimed, &admin);
+
+    let stats = contract.get_global_stats();
+    assert_eq!(
+        stats,
+        GlobalStats {
+            total_created: 9,
+            total_settled: 5,
+            total_cancelled: 1,
+            total_refunded: 1,
+            total_reclaimed: 1,
+            total_expired: 2,
+            active: 1,
+        }
+    );
+    assert_balanced(&stats);
+
+    // Volume does not move as remittances resolve
+    assert_eq!(contract.get_token_volume(&token), 5_500);
+}
+
+#[test]
+fn test_rejected_transition_leaves_stats_untouched() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    let before = contract.get_global_stats();
+
+    assert!(contract.try_cancel_remittance(&remittance_id).is_err());
+    assert!(contract.try_confirm_payout(&remittance_id).is_err());
+
+    assert_eq!(contract.get_global_stats(), before);
+    assert_balanced(&before);
+}
@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+#[test]
+fn test_hold_blocks_settlement_and_cancellation_until_released() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.place_hold(&admin, &remittance_id);
+    assert!(contract.get_remittance(&remittance_id).on_hold);
+
+    assert_eq!(contract.try_confirm_payout(&remittance_id), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.try_settle_partial(&remittance_id, &100), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.try_cancel_remittance(&remittance_id), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Pending);
+
+    contract.release_hold(&admin, &remittance_id);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_hold_blocks_changes_to_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let other_agent = Address::generate(&env);
+    contract.register_agent(&other_agent, &None);
+    let recipient = Address::generate(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.place_hold(&admin, &remittance_id);
+
+    assert_eq!(contract.try_top_up_remittance(&remittance_id, &100), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.try_update_recipient(&remittance_id, &recipient), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(
+        contract.try_assign_agent(&sender, &remittance_id, &other_agent),
+        Err(Ok(ContractError::RemittanceOnHold))
+    );
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!((remittance.amount, remittance.recipient), (1000, agent));
+    assert_eq!(remittance.assigned_agent, None);
+
+    contract.release_hold(&admin, &remittance_id);
+    contract.top_up_remittance(&remittance_id, &100);
+    contract.update_recipient(&remittance_id, &recipient);
+    contract.assign_agent(&sender, &remittance_id, &other_agent);
+    assert_eq!(contract.get_remittance(&remittance_id).amount, 1100);
+}
+
+#[test]
+fn test_hold_freezes_partially_settled_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &400);
+    contract.place_hold(&admin, &remittance_id);
+
+    assert_eq!(contract.try_settle_partial(&remittance_id, &100), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.try_confirm_payout(&remittance_id), Err(Ok(ContractError::RemittanceOnHold)));
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::PartiallySettled);
+    assert_eq!(remittance.remaining, 600);
+}
+
+#[test]
+fn test_hold_blocks_claim_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, _agent, admin) = setup(&env);
+
+    let recipient = Address::generate(&env);
+    let claimable = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    contract.place_hold(&admin, &claimable);
+    assert_eq!(contract.try_claim_remittance(&claimable), Err(Ok(ContractError::RemittanceOnHold)));
+
+    let agent = Address::generate(&env);
+    contract.register_agent(&agent, &None);
+    let expiring = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    contract.place_hold(&admin, &expiring);
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    assert_eq!(contract.try_refund_remittance(&sender, &expiring), Err(Ok(ContractError::RemittanceOnHold)));
+
+    contract.release_hold(&admin, &expiring);
+    contract.refund_remittance(&sender, &expiring);
+    assert_eq!(token.balance(&sender), 10_000 - 1000);
+}
+
+#[test]
+fn test_hold_events_record_acting_operator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let operator = Address::generate(&env);
+    contract.assign_role(&admin, &operator, &Role::Operator);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.place_hold(&operator, &remittance_id);
+    contract.release_hold(&admin, &remittance_id);
+
+    for (action, actor) in [("placed", &operator), ("released", &admin)] {
+        let event = env
+            .events()
+            .all()
+            .iter()
+            .find(|(address, topics, _)| {
+                *address == contract.address
+                    && *topics == (Symbol::new(&env, "hold"), Symbol::new(&env, action)).into_val(&env)
+            })
+            .expect("hold event not emitted");
+        let data = <(u32, u32, u64, u64, Address)>::try_from_val(&env, &event.2).unwrap();
+        assert_eq!(data.3, remittance_id);
+        assert_eq!(data.4, *actor);
+    }
+}
+
+#[test]
+fn test_hold_rejects_invalid_requests() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(contract.try_place_hold(&outsider, &remittance_id), Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(contract.try_release_hold(&admin, &remittance_id), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.try_place_hold(&admin, &99), Err(Ok(ContractError::RemittanceNotFound)));
+
+    contract.place_hold(&admin, &remittance_id);
+    assert_eq!(contract.try_place_hold(&admin, &remittance_id), Err(Ok(ContractError::RemittanceOnHold)));
+    assert_eq!(contract.try_release_hold(&outsider, &remittance_id), Err(Ok(ContractError::Unauthorized)));
+
+    let settled = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&settled);
+    assert_eq!(contract.try_place_hold(&admin, &settled), Err(Ok(ContractError::InvalidStatus)));
+}
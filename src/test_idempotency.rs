@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::{Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_retry_with_same_key_returns_existing_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let key = BytesN::from_array(&env, &[7; 32]);
+
+    let first = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &Some(key.clone()), &None);
+    let retry = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &Some(key), &None);
+
+    assert_eq!(first, retry);
+    assert_eq!(contract.list_remittances_by_sender(&sender, &0, &10).len(), 1);
+    assert_eq!(token.balance(&sender), 9_000);
+    assert_eq!(token.balance(&contract.address), 1000);
+}
+
+#[test]
+fn test_distinct_keys_create_distinct_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let first = contract.create_remittance(
+        &sender, &agent, &token.address, &1000, &None, &None, &Some(BytesN::from_array(&env, &[1; 32])), &None,
+    );
+    let second = contract.create_remittance(
+        &sender, &agent, &token.address, &1000, &None, &None, &Some(BytesN::from_array(&env, &[2; 32])), &None,
+    );
+
+    assert_ne!(first, second);
+    assert_eq!(token.balance(&sender), 8_000);
+}
+
+#[test]
+fn test_idempotency_key_is_scoped_to_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let other_sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token.address).mint(&other_sender, &10_000);
+    let key = BytesN::from_array(&env, &[9; 32]);
+
+    let first = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &Some(key.clone()), &None);
+    let second = contract.create_remittance(&other_sender, &agent, &token.address, &1000, &None, &None, &Some(key), &None);
+
+    assert_ne!(first, second);
+    assert_eq!(token.balance(&other_sender), 9_000);
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use crate::{ContractError, InitConfig, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn base_config(tokens: Vec<Address>) -> InitConfig {
+    InitConfig {
+        treasury_bps: 60,
+        agent_bps: 40,
+        min_remittance_amount: 100,
+        max_remittance_amount: 50_000,
+        default_daily_limit: 200_000,
+        rate_limit_cooldown: 30,
+        default_expiry: 3_600,
+        whitelisted_tokens: tokens,
+    }
+}
+
+#[test]
+fn test_initialize_applies_every_config_field() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin).address;
+    let other = create_token_contract(&env, &admin).address;
+    let contract = create_swiftremit_contract(&env);
+
+    // The initial token is whitelisted by the bundle itself
+    let config = base_config(vec![&env, token.clone(), other.clone()]);
+    contract.initialize(&admin, &token, &250, &100, &admin, &0, &Some(config.clone()));
+
+    let applied = contract.get_config();
+    assert_eq!(applied.platform_fee_bps, 250);
+    assert_eq!(applied.protocol_fee_bps, 100);
+    assert_eq!(applied.rate_limit_cooldown, 30);
+    assert_eq!((applied.treasury_bps, applied.agent_bps), (60, 40));
+    assert_eq!((applied.min_remittance_amount, applied.max_remittance_amount), (100, 50_000));
+    assert_eq!(applied.default_daily_limit, 200_000);
+    assert_eq!(applied.default_expiry, 3_600);
+    assert!(contract.is_token_whitelisted(&token));
+    assert!(contract.is_token_whitelisted(&other));
+
+    let result = contract.try_initialize(&admin, &token, &250, &100, &admin, &0, &Some(config));
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+    let result = contract.try_initialize(&admin, &token, &250, &100, &admin, &0, &None);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_invalid_config_leaves_contract_uninitialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin).address;
+    let contract = create_swiftremit_contract(&env);
+    let tokens = vec![&env, token.clone()];
+
+    let mut over_bps = base_config(tokens.clone());
+    over_bps.treasury_bps = 6_000;
+    over_bps.agent_bps = 4_001;
+    let result = contract.try_initialize(&admin, &token, &250, &0, &admin, &0, &Some(over_bps));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let mut inverted = base_config(tokens.clone());
+    inverted.min_remittance_amount = 60_000;
+    let result = contract.try_initialize(&admin, &token, &250, &0, &admin, &0, &Some(inverted));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let mut zero_limit = base_config(tokens.clone());
+    zero_limit.default_daily_limit = 0;
+    let result = contract.try_initialize(&admin, &token, &250, &0, &admin, &0, &Some(zero_limit));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let mut long_cooldown = base_config(tokens.clone());
+    long_cooldown.rate_limit_cooldown = 86_401;
+    let result = contract.try_initialize(&admin, &token, &250, &0, &admin, &0, &Some(long_cooldown));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    // An initial token that is neither whitelisted nor listed is rejected too
+    let unlisted = base_config(Vec::new(&env));
+    let result = contract.try_initialize(&admin, &token, &250, &0, &admin, &0, &Some(unlisted));
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    // Nothing from the failed attempts was written, so a valid bundle still succeeds
+    assert!(!contract.is_token_whitelisted(&token));
+    contract.initialize(&admin, &token, &250, &0, &admin, &0, &Some(base_config(tokens)));
+    assert_eq!(contract.get_min_remittance_amount(), 100);
+}
+
+#[test]
+fn test_initialize_without_config_keeps_defaults() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin).address;
+    let contract = create_swiftremit_contract(&env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(&env, &token, true);
+    });
+
+    contract.initialize(&admin, &token, &250, &0, &admin, &0, &None);
+
+    let applied = contract.get_config();
+    assert_eq!(applied.platform_fee_bps, 250);
+    assert_eq!(applied.rate_limit_cooldown, 0);
+    assert_eq!(applied.default_expiry, 0);
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+
+    (contract, admin, token.address)
+}
+
+#[test]
+fn test_get_init_info_before_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin, _token) = setup(&env);
+
+    let result = contract.try_get_init_info();
+    assert_eq!(result, Err(Ok(ContractError::NotInitialized)));
+}
+
+#[test]
+fn test_initialize_records_admin_and_timestamp() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+    let (contract, admin, token) = setup(&env);
+
+    contract.initialize(&admin, &token, &250, &0, &admin, &0, &None);
+
+    assert_eq!(contract.get_init_info(), (admin.clone(), 1_700_000_000));
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (symbol_short!("contract"), Symbol::new(&env, "initialized")).into_val(&env)
+        })
+        .expect("initialized event");
+    let data: Vec<Val> = Vec::try_from_val(&env, &event.2).unwrap();
+    let timestamp: u64 = u64::try_from_val(&env, &data.get(2).unwrap()).unwrap();
+    let initialized_by: Address = Address::try_from_val(&env, &data.get(3).unwrap()).unwrap();
+    assert_eq!(timestamp, 1_700_000_000);
+    assert_eq!(initialized_by, admin);
+}
+
+#[test]
+fn test_reinitialize_fails_and_keeps_init_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, admin, token) = setup(&env);
+    contract.initialize(&admin, &token, &250, &0, &admin, &0, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let intruder = Address::generate(&env);
+    let result = contract.try_initialize(&intruder, &token, &250, &0, &intruder, &0, &None);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
+
+    assert_eq!(contract.get_init_info(), (admin, 1_000));
+}
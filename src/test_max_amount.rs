@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+#[test]
+fn test_max_remittance_amount_defaults_to_unlimited() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, _admin) = setup(&env);
+
+    assert_eq!(contract.get_max_remittance_amount(), i128::MAX);
+}
+
+#[test]
+fn test_create_remittance_above_max_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_max_remittance_amount(&admin, &1000);
+
+    contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &1001, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_create_remittance_non_positive_amount_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &0, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &-5, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_set_max_remittance_amount_rejects_non_positive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, admin) = setup(&env);
+
+    let result = contract.try_set_max_remittance_amount(&admin, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_create_remittance_extreme_amounts_overflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    for amount in [i128::MAX, i128::MAX - 1, i128::MAX / 2, i128::MAX / 250 + 1] {
+        let result = contract.try_create_remittance(&sender, &agent, &token.address, &amount, &None, &None, &None, &None);
+        assert_eq!(result, Err(Ok(ContractError::Overflow)));
+    }
+}
+
+#[test]
+fn test_min_remittance_amount_defaults_to_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, _admin) = setup(&env);
+
+    assert_eq!(contract.get_min_remittance_amount(), 0);
+}
+
+#[test]
+fn test_create_remittance_below_min_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_min_remittance_amount(&admin, &100);
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &99, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    contract.create_remittance(&sender, &agent, &token.address, &100, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_min_and_max_cannot_conflict() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, admin) = setup(&env);
+
+    contract.set_max_remittance_amount(&admin, &1000);
+    let result = contract.try_set_min_remittance_amount(&admin, &1001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    contract.set_min_remittance_amount(&admin, &1000);
+    let result = contract.try_set_max_remittance_amount(&admin, &999);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let result = contract.try_set_min_remittance_amount(&admin, &-1);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
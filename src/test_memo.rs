@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Bytes, Env, IntoVal, Val, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+fn created_event_memo(env: &Env, agent: &Address, token: &Address) -> Option<Bytes> {
+    let topics: Vec<Val> = (symbol_short!("remit"), symbol_short!("created"), agent.clone(), token.clone()).into_val(env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| *t == topics)
+        .unwrap();
+    let (_, _, _, _, _, _, _, _, memo): (u32, u32, u64, u64, Address, Address, i128, i128, Option<Bytes>) =
+        data.into_val(env);
+    memo
+}
+
+#[test]
+fn test_memo_is_stored_and_emitted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let memo = Bytes::from_slice(&env, b"INV-2024-0042");
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &Some(memo.clone()), &None, &None);
+
+    assert_eq!(contract.get_remittance(&remittance_id).memo, Some(memo.clone()));
+    assert_eq!(created_event_memo(&env, &agent, &token.address), Some(memo));
+}
+
+#[test]
+fn test_remittance_without_memo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    assert_eq!(contract.get_remittance(&remittance_id).memo, None);
+    assert_eq!(created_event_memo(&env, &agent, &token.address), None);
+}
+
+#[test]
+fn test_memo_too_long_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let memo = Bytes::from_slice(&env, &[1u8; 65]);
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &1000, &None, &Some(memo), &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::MemoTooLong)));
+}
@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::{ContractError, MigrationBatch, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+/// Returns a populated source contract and an empty migration target.
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, SwiftRemitContractClient<'a>) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let source = create_swiftremit_contract(env);
+    env.as_contract(&source.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    source.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    source.register_agent(&agent, &None);
+
+    for _ in 0..4 {
+        source.create_remittance(&sender, &agent, &token.address, &100, &None, &None, &None, &None);
+    }
+
+    (source, create_swiftremit_contract(env))
+}
+
+fn export_batch(env: &Env, contract: &SwiftRemitContractClient, batch_number: u32) -> MigrationBatch {
+    env.as_contract(&contract.address, || crate::export_batch(env, batch_number, 2).unwrap())
+}
+
+#[test]
+fn test_dry_run_accepts_valid_batch_without_writing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target) = setup(&env);
+
+    let batch = export_batch(&env, &source, 0);
+    target.migration_dry_run(&batch.batch_hash, &batch);
+
+    assert_eq!(target.try_get_remittance(&1), Err(Ok(ContractError::RemittanceNotFound)));
+    env.as_contract(&target.address, || {
+        assert!(!crate::storage::is_migration_in_progress(&env));
+        assert_eq!(crate::storage::get_migration_next_batch(&env), 0);
+    });
+}
+
+#[test]
+fn test_dry_run_rejects_hash_mismatch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target) = setup(&env);
+
+    let batch = export_batch(&env, &source, 0);
+    let result = target.try_migration_dry_run(&BytesN::from_array(&env, &[0u8; 32]), &batch);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationHash)));
+
+    let mut tampered = batch.clone();
+    tampered.remittances.pop_back();
+    let result = target.try_migration_dry_run(&tampered.batch_hash, &tampered);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationHash)));
+}
+
+#[test]
+fn test_dry_run_rejects_out_of_order_and_out_of_bounds_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target) = setup(&env);
+
+    let batch1 = export_batch(&env, &source, 1);
+    let result = target.try_migration_dry_run(&batch1.batch_hash, &batch1);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+
+    let mut out_of_bounds = export_batch(&env, &source, 0);
+    out_of_bounds.total_batches = 0;
+    let result = target.try_migration_dry_run(&out_of_bounds.batch_hash, &out_of_bounds);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+}
+
+#[test]
+fn test_dry_run_follows_real_import_sequence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target) = setup(&env);
+
+    let batch0 = export_batch(&env, &source, 0);
+    let batch1 = export_batch(&env, &source, 1);
+    env.as_contract(&target.address, || {
+        crate::import_batch(&env, batch0.clone()).unwrap();
+        assert!(crate::storage::is_migration_in_progress(&env));
+    });
+
+    let result = target.try_migration_dry_run(&batch0.batch_hash, &batch0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+    target.migration_dry_run(&batch1.batch_hash, &batch1);
+
+    env.as_contract(&target.address, || {
+        crate::import_batch(&env, batch1).unwrap();
+        assert!(!crate::storage::is_migration_in_progress(&env));
+    });
+    assert_eq!(target.get_remittance(&4).amount, 100);
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_scheduled_remittance_escrows_funds_until_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &Some(2_000));
+    assert_eq!(contract.get_remittance(&remittance_id).not_before, Some(2_000));
+    assert_eq!(token.balance(&contract.address), 1000);
+
+    assert_eq!(contract.try_confirm_payout(&remittance_id), Err(Ok(ContractError::NotYetSettleable)));
+    assert_eq!(contract.try_settle_partial(&remittance_id, &100), Err(Ok(ContractError::NotYetSettleable)));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_not_before_must_precede_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let result =
+        contract.try_create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &Some(2_000));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &Some(1_999));
+}
+
+#[test]
+fn test_expiry_still_applies_after_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(3_000), &None, &None, &Some(2_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 3_001);
+    assert_eq!(contract.try_confirm_payout(&remittance_id), Err(Ok(ContractError::SettlementExpired)));
+}
+
+#[test]
+fn test_scheduled_remittance_is_skipped_by_batch_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let scheduled =
+        contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &Some(2_000));
+    let ready = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let outcomes = contract.settle_batch(&soroban_sdk::vec![&env, scheduled, ready]);
+    assert_eq!(outcomes.get_unchecked(0), (scheduled, Err(ContractError::NotYetSettleable)));
+    assert_eq!(outcomes.get_unchecked(1), (ready, Ok(())));
+}
+
+#[test]
+fn test_scheduled_claimable_remittance_cannot_be_claimed_before_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, _agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    // Claimable remittances take no release time at creation, so schedule one directly
+    let remittance_id =
+        contract.create_claimable_remittance(&sender, &recipient, &token.address, &1000, &None, &None);
+    env.as_contract(&contract.address, || {
+        let mut remittance = crate::storage::get_remittance(&env, remittance_id).unwrap();
+        remittance.not_before = Some(2_000);
+        crate::storage::set_remittance(&env, remittance_id, &remittance);
+    });
+
+    assert_eq!(contract.try_claim_remittance(&remittance_id), Err(Ok(ContractError::NotYetSettleable)));
+    assert_eq!(token.balance(&recipient), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.claim_remittance(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+}
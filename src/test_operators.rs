@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let operator = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.add_operator(&admin, &operator);
+
+    (contract, token.address, admin, operator)
+}
+
+#[test]
+fn test_operator_can_pause_set_limits_and_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, operator) = setup(&env);
+
+    assert!(contract.is_operator(&operator));
+
+    contract.pause(&operator);
+    assert!(contract.is_paused());
+    contract.unpause(&operator);
+    assert!(!contract.is_paused());
+
+    contract.set_max_remittance_amount(&operator, &5_000);
+    contract.set_daily_limit(&operator, &token, &10_000);
+    contract.set_default_daily_limit(&operator, &20_000);
+    contract.set_rate_limit(&operator, &60);
+    assert_eq!(contract.get_max_remittance_amount(), 5_000);
+    assert_eq!(contract.get_daily_limit(&token), 10_000);
+
+    let other = create_token_contract(&env, &operator);
+    contract.whitelist_token(&operator, &other.address);
+    assert!(contract.is_token_whitelisted(&other.address));
+    contract.remove_token(&operator, &other.address);
+    assert!(!contract.is_token_whitelisted(&other.address));
+}
+
+#[test]
+fn test_operator_cannot_manage_admins() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, operator) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = contract.try_propose_admin(&operator, &outsider);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = contract.try_remove_admin(&operator, &admin);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = contract.try_add_operator(&operator, &outsider);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let result = contract.try_remove_operator(&operator, &operator);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_non_operator_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, _operator) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(contract.try_pause(&outsider), Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(
+        contract.try_set_daily_limit(&outsider, &token, &1_000),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        contract.try_whitelist_token(&outsider, &Address::generate(&env)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_remove_operator_revokes_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, operator) = setup(&env);
+
+    contract.remove_operator(&admin, &operator);
+    assert!(!contract.is_operator(&operator));
+    assert_eq!(contract.try_pause(&operator), Err(Ok(ContractError::Unauthorized)));
+
+    let result = contract.try_remove_operator(&admin, &operator);
+    assert_eq!(result, Err(Ok(ContractError::OperatorNotFound)));
+}
+
+#[test]
+fn test_operators_do_not_count_towards_last_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _operator) = setup(&env);
+
+    let result = contract.try_remove_admin(&admin, &admin);
+    assert_eq!(result, Err(Ok(ContractError::CannotRemoveLastAdmin)));
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_settle_partial_in_tranches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    contract.settle_partial(&remittance_id, &400);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::PartiallySettled);
+    assert_eq!(remittance.remaining, 600);
+    assert_eq!(token.balance(&agent), 390);
+
+    contract.settle_partial(&remittance_id, &600);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(remittance.remaining, 0);
+
+    // Fees across tranches match a single full settlement
+    assert_eq!(token.balance(&agent), 975);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+}
+
+#[test]
+fn test_settle_partial_exceeding_remaining_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &700);
+
+    let result = contract.try_settle_partial(&remittance_id, &301);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let result = contract.try_settle_partial(&remittance_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+}
+
+#[test]
+fn test_settle_partial_after_completion_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &1000);
+
+    let result = contract.try_settle_partial(&remittance_id, &1);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_confirm_payout_settles_remaining_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &250);
+    contract.confirm_payout(&remittance_id);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Completed);
+    assert_eq!(remittance.remaining, 0);
+    assert_eq!(token.balance(&agent), 975);
+}
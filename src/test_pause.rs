@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent)
+}
+
+#[test]
+fn test_pause_blocks_creation_and_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.pause(&admin);
+    assert!(contract.is_paused());
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+    contract.unpause(&admin);
+    assert!(!contract.is_paused());
+    contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_cancel_and_refund_allowed_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin, sender, agent) = setup(&env);
+
+    let cancel_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let refund_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(100), &None, &None, &None);
+    contract.pause(&admin);
+
+    contract.cancel_remittance(&cancel_id);
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    contract.refund_remittance(&sender, &refund_id);
+
+    assert_eq!(contract.get_remittance(&cancel_id).status, RemittanceStatus::Cancelled);
+    assert_eq!(contract.get_remittance(&refund_id).status, RemittanceStatus::Refunded);
+    assert_eq!(token.balance(&sender), 10_000);
+}
+
+#[test]
+fn test_pause_emits_event_with_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _sender, _agent) = setup(&env);
+
+    contract.pause(&admin);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("admin"), symbol_short!("paused")).into_val(&env));
+    let (_, _, _, paused_by): (u32, u32, u64, Address) = data.into_val(&env);
+    assert_eq!(paused_by, admin);
+}
+
+#[test]
+#[should_panic]
+fn test_pause_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _sender, _agent) = setup(&env);
+
+    env.set_auths(&[]);
+    contract.pause(&admin);
+}
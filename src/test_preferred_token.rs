@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let usdc = create_token_contract(env, &admin);
+    usdc.mint(&sender, &100_000);
+    let eurc = create_token_contract(env, &admin);
+    eurc.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &usdc.address, true);
+    });
+    contract.initialize(&admin, &usdc.address, &250, &0, &admin, &0, &None);
+    contract.whitelist_token(&admin, &eurc.address);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, usdc.address, eurc.address, sender, agent)
+}
+
+#[test]
+fn test_remittance_in_preferred_token_succeeds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent) = setup(&env);
+
+    assert_eq!(contract.get_preferred_token(&agent), None);
+    contract.set_preferred_token(&agent, &Some(eurc.clone()));
+    assert_eq!(contract.get_preferred_token(&agent), Some(eurc.clone()));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &eurc, &1_000, &None, &None, &None, &None);
+    assert_eq!(contract.get_remittance(&remittance_id).token, eurc);
+
+    let result = contract.try_create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenMismatch)));
+}
+
+#[test]
+fn test_preference_applies_to_claimable_and_split_recipients() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent) = setup(&env);
+    let recipient = Address::generate(&env);
+
+    contract.set_preferred_token(&recipient, &Some(usdc.clone()));
+
+    let result = contract.try_create_claimable_remittance(&sender, &recipient, &eurc, &1_000, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenMismatch)));
+    contract.create_claimable_remittance(&sender, &recipient, &usdc, &1_000, &None, &None);
+
+    let recipients = vec![&env, (Address::generate(&env), 500i128), (recipient.clone(), 500i128)];
+    let result = contract.try_create_split_remittance(&sender, &agent, &eurc, &recipients, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenMismatch)));
+    contract.create_split_remittance(&sender, &agent, &usdc, &recipients, &None, &None);
+}
+
+#[test]
+fn test_clearing_preference_accepts_any_whitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent) = setup(&env);
+
+    contract.set_preferred_token(&agent, &Some(eurc.clone()));
+    contract.set_preferred_token(&agent, &None);
+    assert_eq!(contract.get_preferred_token(&agent), None);
+
+    contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    contract.create_remittance(&sender, &agent, &eurc, &1_000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_set_preferred_token_rejects_non_whitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _usdc, _eurc, _sender, agent) = setup(&env);
+
+    let other = create_token_contract(&env, &Address::generate(&env));
+    let result = contract.try_set_preferred_token(&agent, &Some(other.address.clone()));
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+    assert_eq!(contract.get_preferred_token(&agent), None);
+}
+
+#[test]
+fn test_redirect_to_recipient_preferring_other_token_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, eurc, sender, agent) = setup(&env);
+    let recipient = Address::generate(&env);
+    contract.set_preferred_token(&recipient, &Some(eurc.clone()));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    let result = contract.try_update_recipient(&remittance_id, &recipient);
+    assert_eq!(result, Err(Ok(ContractError::TokenMismatch)));
+    assert_eq!(contract.get_remittance(&remittance_id).recipient, agent);
+}
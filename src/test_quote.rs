@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use crate::{ContractError, FeeRounding, Role, SwiftRemitContract, SwiftRemitContractClient, SettlementReceipt};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &admin, &0, &None);
+    contract.set_fee_config(&60, &40);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+/// Quote, then create and fully settle the same amount, returning both receipts.
+fn quote_and_settle(
+    contract: &SwiftRemitContractClient,
+    token: &Address,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+) -> (SettlementReceipt, SettlementReceipt) {
+    let quote = contract.quote(&amount, token, &Some(agent.clone()));
+    let remittance_id = contract.create_remittance(sender, agent, token, &amount, &None, &None, &None, &None);
+    (quote, contract.confirm_payout(&remittance_id))
+}
+
+#[test]
+fn test_quote_matches_settlement_receipt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    for rounding in [FeeRounding::Down, FeeRounding::Up, FeeRounding::Nearest] {
+        contract.set_fee_rounding(&admin, &rounding);
+        for amount in [1i128, 39, 40, 199, 1_000, 12_345, 999_999] {
+            let (quote, receipt) = quote_and_settle(&contract, &token, &sender, &agent, amount);
+            assert_eq!(quote.remittance_id, receipt.remittance_id);
+            assert_eq!(
+                (quote.gross, quote.fee, quote.net, quote.settlement_fee),
+                (receipt.gross, receipt.fee, receipt.net, receipt.settlement_fee)
+            );
+            assert_eq!(quote.settled_at, receipt.settled_at);
+        }
+    }
+}
+
+#[test]
+fn test_quote_does_not_change_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _sender, agent, _admin) = setup(&env);
+
+    let next_id = contract.next_remittance_id();
+    let quote = contract.quote(&1_000, &token, &Some(agent.clone()));
+
+    assert_eq!((quote.gross, quote.fee, quote.net), (1_000, 35, 965));
+    assert_eq!(quote.remittance_id, next_id);
+    assert_eq!(quote.agent, agent);
+    assert_eq!(contract.next_remittance_id(), next_id);
+
+    let unassigned = contract.quote(&1_000, &token, &None);
+    assert_eq!((unassigned.gross, unassigned.fee, unassigned.net), (1_000, 35, 965));
+    assert_eq!(unassigned.agent, contract.address);
+    assert_eq!(contract.get_tvl(&token), 0);
+}
+
+#[test]
+fn test_quote_rejects_what_create_rejects() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _sender, agent, admin) = setup(&env);
+
+    assert_eq!(contract.try_quote(&0, &token, &Some(agent)), Err(Ok(ContractError::InvalidAmount)));
+
+    contract.set_max_remittance_amount(&admin, &5_000);
+    assert_eq!(contract.try_quote(&5_001, &token, &None), Err(Ok(ContractError::InvalidAmount)));
+
+    let other = create_token_contract(&env, &Address::generate(&env));
+    assert_eq!(contract.try_quote(&1_000, &other.address, &None), Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(contract.try_quote(&1_000, &token, &Some(stranger)), Err(Ok(ContractError::AgentNotRegistered)));
+}
+
+#[test]
+fn test_quote_matches_receipt_for_agent_specific_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_token_fee(&admin, &token, &300, &200);
+    contract.set_agent_payout(&agent, &Some(Address::generate(&env)));
+
+    for amount in [1i128, 999, 12_345] {
+        let (quote, receipt) = quote_and_settle(&contract, &token, &sender, &agent, amount);
+        assert_eq!(quote, receipt);
+    }
+}
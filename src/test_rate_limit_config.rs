@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient, MAX_RATE_LIMIT_COOLDOWN};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+#[test]
+fn test_set_rate_limit_enforces_cooldown_at_runtime() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_rate_limit(&admin, &60);
+    assert_eq!(contract.get_rate_limit_cooldown(), 60);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+
+    let result = contract.try_confirm_payout(&second);
+    assert_eq!(result, Err(Ok(ContractError::RateLimitExceeded)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_060);
+    contract.confirm_payout(&second);
+}
+
+#[test]
+fn test_zero_cooldown_disables_rate_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_rate_limit(&admin, &60);
+    contract.set_rate_limit(&admin, &0);
+
+    let first = contract.create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None);
+    contract.confirm_payout(&first);
+    contract.confirm_payout(&second);
+}
+
+#[test]
+fn test_set_rate_limit_rejects_absurd_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, admin) = setup(&env);
+
+    let result = contract.try_set_rate_limit(&admin, &(MAX_RATE_LIMIT_COOLDOWN + 1));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    assert_eq!(contract.get_rate_limit_cooldown(), 0);
+}
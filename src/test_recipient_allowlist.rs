@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent)
+}
+
+#[test]
+fn test_allowlist_disabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    assert!(!contract.is_recipient_allowlist_enabled(&sender));
+    contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_enabled_allowlist_rejects_unlisted_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.set_recipient_allowlist_enabled(&sender, &true);
+
+    let result = contract.try_create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let recipient = Address::generate(&env);
+    let result = contract.try_create_claimable_remittance(&sender, &recipient, &token, &1000, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_enabled_allowlist_accepts_listed_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.add_allowed_recipient(&sender, &agent);
+    contract.set_recipient_allowlist_enabled(&sender, &true);
+    assert!(contract.is_recipient_allowed(&sender, &agent));
+
+    contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+
+    contract.remove_allowed_recipient(&sender, &agent);
+    let result = contract.try_create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+fn test_update_recipient_respects_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.set_recipient_allowlist_enabled(&sender, &true);
+
+    let outsider = Address::generate(&env);
+    let result = contract.try_update_recipient(&remittance_id, &outsider);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    contract.add_allowed_recipient(&sender, &outsider);
+    contract.update_recipient(&remittance_id, &outsider);
+}
+
+#[test]
+fn test_allowlist_is_scoped_to_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let other_sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&other_sender, &10_000);
+
+    contract.set_recipient_allowlist_enabled(&other_sender, &true);
+    contract.add_allowed_recipient(&other_sender, &agent);
+
+    // Neither the other sender's toggle nor their list applies to this sender
+    assert!(!contract.is_recipient_allowlist_enabled(&sender));
+    assert!(!contract.is_recipient_allowed(&sender, &agent));
+    contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+
+    contract.set_recipient_allowlist_enabled(&sender, &true);
+    let result = contract.try_create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent)
+}
+
+#[test]
+fn test_refund_expired_remittance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    assert_eq!(token.balance(&sender), 9_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    contract.refund_remittance(&sender, &remittance_id);
+
+    assert_eq!(token.balance(&sender), 10_000);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::Refunded);
+
+    // Second refund is rejected
+    let result = contract.try_refund_remittance(&sender, &remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_refund_before_expiry_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    let result = contract.try_refund_remittance(&sender, &remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+
+    let no_expiry_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let result = contract.try_refund_remittance(&sender, &no_expiry_id);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+}
+
+#[test]
+fn test_admin_can_refund_on_behalf_of_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, admin, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+
+    contract.refund_remittance(&admin, &remittance_id);
+    assert_eq!(token.balance(&sender), 10_000);
+}
+
+#[test]
+fn test_refund_by_unrelated_caller_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+
+    let stranger = Address::generate(&env);
+    let result = contract.try_refund_remittance(&stranger, &remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
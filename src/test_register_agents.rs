@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> SwiftRemitContractClient<'a> {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    contract
+}
+
+#[test]
+fn test_register_agents_adds_all_new_agents() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract = setup(&env);
+
+    let agent_a = Address::generate(&env);
+    let agent_b = Address::generate(&env);
+    let agent_c = Address::generate(&env);
+
+    let added = contract.register_agents(&vec![&env, agent_a.clone(), agent_b.clone(), agent_c.clone()]);
+    assert_eq!(added, 3);
+    assert!(contract.is_agent_registered(&agent_a));
+    assert!(contract.is_agent_registered(&agent_b));
+    assert!(contract.is_agent_registered(&agent_c));
+}
+
+#[test]
+fn test_register_agents_skips_existing_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract = setup(&env);
+
+    let agent_a = Address::generate(&env);
+    contract.register_agent(&agent_a, &None);
+
+    let agent_b = Address::generate(&env);
+    let added = contract.register_agents(&vec![&env, agent_a, agent_b.clone(), agent_b.clone()]);
+    assert_eq!(added, 1);
+    assert!(contract.is_agent_registered(&agent_b));
+}
+
+#[test]
+#[should_panic]
+fn test_register_agents_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract = setup(&env);
+
+    // Drop the mocked auths so the admin signature is missing
+    env.set_auths(&[]);
+    contract.register_agents(&vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+fn test_register_agents_invalid_entry_rolls_back_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract = setup(&env);
+
+    let agent_a = Address::generate(&env);
+    let result = contract.try_register_agents(&vec![&env, agent_a.clone(), contract.address.clone()]);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+    assert!(!contract.is_agent_registered(&agent_a));
+    assert!(!contract.is_agent_registered(&contract.address));
+}
@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_approved_relayer_creates_for_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let relayer = Address::generate(&env);
+
+    contract.approve_relayer(&sender, &relayer);
+    assert!(contract.is_relayer_approved(&sender, &relayer));
+
+    let remittance_id =
+        contract.create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    // Both the relayer and the funding sender authorized the call
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == relayer));
+    assert!(auths.iter().any(|(address, _)| *address == sender));
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.sender, sender);
+    assert_eq!(token.balance(&sender), 9_000);
+    assert_eq!(token.balance(&relayer), 0);
+}
+
+#[test]
+fn test_unapproved_relayer_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let relayer = Address::generate(&env);
+
+    let result = contract.try_create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(token.balance(&sender), 10_000);
+}
+
+#[test]
+fn test_approve_revoke_cycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let relayer = Address::generate(&env);
+
+    contract.approve_relayer(&sender, &relayer);
+    contract.create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    contract.revoke_relayer(&sender, &relayer);
+    assert!(!contract.is_relayer_approved(&sender, &relayer));
+    let result = contract.try_create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    contract.approve_relayer(&sender, &relayer);
+    contract.create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(token.balance(&sender), 8_000);
+}
+
+#[test]
+fn test_approval_is_scoped_to_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let relayer = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+
+    contract.approve_relayer(&other_sender, &relayer);
+
+    let result = contract.try_create_remittance_for(&relayer, &sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
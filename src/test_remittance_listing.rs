@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use crate::{Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, agent)
+}
+
+fn funded_sender(env: &Env, token: &Address) -> Address {
+    let sender = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&sender, &100_000);
+    sender
+}
+
+#[test]
+fn test_list_remittances_by_sender_in_creation_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, agent) = setup(&env);
+    let alice = funded_sender(&env, &token);
+    let bob = funded_sender(&env, &token);
+
+    let a1 = contract.create_remittance(&alice, &agent, &token, &100, &None, &None, &None, &None);
+    let b1 = contract.create_remittance(&bob, &agent, &token, &100, &None, &None, &None, &None);
+    let a2 = contract.create_remittance(&alice, &agent, &token, &100, &None, &None, &None, &None);
+    let a3 = contract.create_remittance(&alice, &agent, &token, &100, &None, &None, &None, &None);
+
+    assert_eq!(contract.list_remittances_by_sender(&alice, &0, &10), vec![&env, a1, a2, a3]);
+    assert_eq!(contract.list_remittances_by_sender(&bob, &0, &10), vec![&env, b1]);
+
+    // Paging through in slices
+    assert_eq!(contract.list_remittances_by_sender(&alice, &0, &2), vec![&env, a1, a2]);
+    assert_eq!(contract.list_remittances_by_sender(&alice, &2, &2), vec![&env, a3]);
+}
+
+#[test]
+fn test_list_remittances_past_end_is_empty() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, agent) = setup(&env);
+    let alice = funded_sender(&env, &token);
+
+    contract.create_remittance(&alice, &agent, &token, &100, &None, &None, &None, &None);
+
+    assert_eq!(contract.list_remittances_by_sender(&alice, &1, &10).len(), 0);
+    assert_eq!(contract.list_remittances_by_sender(&alice, &u32::MAX, &10).len(), 0);
+    assert_eq!(contract.list_remittances_by_sender(&Address::generate(&env), &0, &10).len(), 0);
+}
+
+#[test]
+fn test_list_remittances_caps_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let (contract, token, agent) = setup(&env);
+    let alice = funded_sender(&env, &token);
+
+    for _ in 0..105 {
+        contract.create_remittance(&alice, &agent, &token, &100, &None, &None, &None, &None);
+    }
+
+    assert_eq!(contract.list_remittances_by_sender(&alice, &0, &u32::MAX).len(), 100);
+    assert_eq!(contract.list_remittances_by_sender(&alice, &100, &u32::MAX).len(), 5);
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_get_remittance_returns_full_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(5_000), &None, &None, &None);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.id, remittance_id);
+    assert_eq!(remittance.sender, sender);
+    assert_eq!(remittance.agent, agent);
+    assert_eq!(remittance.token, token.address);
+    assert_eq!(remittance.amount, 1000);
+    assert_eq!(remittance.fee, 25);
+    assert_eq!(remittance.remaining, 1000);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(remittance.expiry, Some(5_000));
+    assert_eq!(remittance.created_at, 1_000);
+}
+
+#[test]
+fn test_get_remittance_reflects_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &300);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.status, RemittanceStatus::PartiallySettled);
+    assert_eq!(remittance.remaining, 700);
+}
+
+#[test]
+fn test_get_remittance_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent) = setup(&env);
+
+    let result = contract.try_get_remittance(&42);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotFound)));
+}
+
+#[test]
+fn test_remittance_ids_strictly_increase_and_are_never_reused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    assert_eq!(contract.next_remittance_id(), 1);
+
+    let first = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(first, 1);
+    contract.cancel_remittance(&first);
+    assert_eq!(contract.next_remittance_id(), 2);
+
+    let second = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    contract.refund_remittance(&sender, &second);
+
+    let predicted = contract.next_remittance_id();
+    let third = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(third, predicted);
+    assert!(first < second && second < third);
+}
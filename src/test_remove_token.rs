@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent)
+}
+
+#[test]
+fn test_removed_token_grandfathers_in_flight_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, admin, sender, agent) = setup(&env);
+
+    let settled = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let refunded = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+
+    contract.remove_token(&admin, &token.address);
+    assert!(!contract.is_token_whitelisted(&token.address));
+
+    let result = contract.try_create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    contract.confirm_payout(&settled);
+    assert_eq!(contract.get_remittance(&settled).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.refund_remittance(&sender, &refunded);
+    assert_eq!(contract.get_remittance(&refunded).status, RemittanceStatus::Refunded);
+    assert_eq!(token.balance(&sender), 9_000);
+}
+
+#[test]
+fn test_remove_token_not_whitelisted_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin, _sender, _agent) = setup(&env);
+
+    let result = contract.try_remove_token(&admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+}
@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use crate::{Role, SenderStats, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+const EXPIRY: u64 = 2_000;
+const GRACE: u64 = 10_000;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    contract.set_reclaim_grace_period(&admin, &GRACE);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+#[test]
+fn test_sender_without_activity_has_zero_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _sender, _agent, _admin) = setup(&env);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(contract.get_sender_stats(&stranger), SenderStats::default());
+    assert_eq!(contract.get_sender_volume(&stranger, &token), 0);
+}
+
+#[test]
+fn test_sender_stats_reconcile_across_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let other_token = create_token_contract(&env, &admin);
+    other_token.mint(&sender, &10_000);
+    contract.whitelist_token(&admin, &other_token.address);
+
+    let create = |token: &Address, amount: i128, expiry: Option<u64>| {
+        contract.create_remittance(&sender, &agent, token, &amount, &expiry, &None, &None, &None)
+    };
+    let settled = create(&token, 1_000, None);
+    let cancelled = create(&token, 300, None);
+    let refunded = create(&token, 500, Some(EXPIRY));
+    let reclaimed = create(&token, 600, Some(EXPIRY));
+    let open = create(&other_token.address, 700, None);
+
+    // Another sender's activity is counted separately
+    let other_sender = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&other_sender, &1_000);
+    contract.create_remittance(&other_sender, &agent, &token, &900, &None, &None, &None, &None);
+
+    contract.top_up_remittance(&open, &50);
+
+    contract.settle_partial(&settled, &400);
+    assert_eq!(contract.get_sender_stats(&sender).active, 5);
+    contract.confirm_payout(&settled);
+    contract.cancel_remittance(&cancelled);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    contract.sweep_expired(&vec![&env, refunded, reclaimed]);
+    contract.refund_remittance(&sender, &refunded);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+    contract.admin_reclaim(&admin, &reclaimed, &admin);
+
+    let stats = contract.get_sender_stats(&sender);
+    assert_eq!(
+        stats,
+        SenderStats {
+            total_created: 5,
+            total_settled: 1,
+            total_cancelled: 1,
+            total_refunded: 1,
+            total_reclaimed: 1,
+            active: 1,
+        }
+    );
+    assert_eq!(
+        stats.total_created,
+        stats.active + stats.total_settled + stats.total_cancelled + stats.total_refunded + stats.total_reclaimed
+    );
+    assert_eq!(contract.get_remittance(&open).sender, sender);
+
+    assert_eq!(contract.get_sender_volume(&sender, &token), 2_400);
+    assert_eq!(contract.get_sender_volume(&sender, &other_token.address), 750);
+    assert_eq!(contract.get_sender_volume(&other_sender, &token), 900);
+
+    let other = contract.get_sender_stats(&other_sender);
+    assert_eq!((other.total_created, other.active), (1, 1));
+
+    // Per-sender counters add up to the global ones
+    let global = contract.get_global_stats();
+    assert_eq!(global.total_created, stats.total_created + other.total_created);
+    assert_eq!(global.active, stats.active + other.active);
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), admin, sender, agent)
+}
+
+#[test]
+fn test_settle_batch_settles_all() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let first = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let second = contract.create_remittance(&sender, &agent, &token.address, &2000, &None, &None, &None, &None);
+
+    let results = contract.settle_batch(&vec![&env, first, second]);
+    assert_eq!(results, vec![&env, (first, Ok(())), (second, Ok(()))]);
+
+    assert_eq!(contract.get_remittance(&first).status, RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&second).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975 + 1950);
+}
+
+#[test]
+fn test_settle_batch_reports_failures_per_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let expiring = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let valid = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.cancel_remittance(&cancelled);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+
+    let results = contract.settle_batch(&vec![&env, expiring, cancelled, valid, 99]);
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            (expiring, Err(ContractError::SettlementExpired)),
+            (cancelled, Err(ContractError::InvalidStatus)),
+            (valid, Ok(())),
+            (99, Err(ContractError::RemittanceNotFound)),
+        ]
+    );
+
+    // Failed remittances are left untouched; the valid one still pays out
+    let remittance = contract.get_remittance(&expiring);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(remittance.remaining, 1000);
+    assert_eq!(contract.get_remittance(&valid).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+}
+
+#[test]
+fn test_settle_batch_duplicate_id_settles_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let results = contract.settle_batch(&vec![&env, id, id]);
+    assert_eq!(results, vec![&env, (id, Ok(())), (id, Err(ContractError::InvalidStatus))]);
+    assert_eq!(token.balance(&agent), 975);
+}
+
+#[test]
+fn test_settle_batch_size_guard() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _admin, _sender, _agent) = setup(&env);
+
+    let result = contract.try_settle_batch(&Vec::new(&env));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let mut ids = Vec::new(&env);
+    for id in 0..101u64 {
+        ids.push_back(id);
+    }
+    let result = contract.try_settle_batch(&ids);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+}
+
+#[test]
+fn test_settle_batch_rejected_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin, sender, agent) = setup(&env);
+
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.pause(&admin);
+
+    let result = contract.try_settle_batch(&vec![&env, id]);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+}
+
+#[test]
+fn test_check_agent_liquidity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, _admin, sender, agent) = setup(&env);
+
+    assert!(!contract.check_agent_liquidity(&agent, &token.address, &1));
+    assert!(contract.check_agent_liquidity(&sender, &token.address, &10_000));
+    assert!(!contract.check_agent_liquidity(&sender, &token.address, &10_001));
+}
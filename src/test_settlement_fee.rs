@@ -0,0 +1,128 @@
+#![cfg(test)]
+
+use crate::{ContractError, FeeConfig, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_settlement_fee_split_between_treasury_and_agent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.set_fee_config(&100, &50);
+    assert_eq!(contract.get_fee_config(), FeeConfig { treasury_bps: 100, agent_bps: 50 });
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.treasury_fee, 10);
+    assert_eq!(remittance.agent_fee, 5);
+
+    // Settlement fee is reserved on top of the principal
+    assert_eq!(token.balance(&sender), 10_000 - 1015);
+
+    contract.confirm_payout(&remittance_id);
+
+    // Agent receives the payout plus their cut of the settlement fee
+    assert_eq!(token.balance(&agent), 975 + 5);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+    assert_eq!(contract.get_treasury_balance(&token.address), 10);
+    assert_eq!(token.balance(&contract.address), 25 + 10);
+}
+
+#[test]
+fn test_settlement_fee_split_across_tranches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.set_fee_config(&100, &50);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    contract.settle_partial(&remittance_id, &333);
+    contract.settle_partial(&remittance_id, &667);
+
+    assert_eq!(token.balance(&agent), 975 + 5);
+    assert_eq!(contract.get_treasury_balance(&token.address), 10);
+}
+
+#[test]
+fn test_set_fee_config_rejects_shares_over_100_percent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent) = setup(&env);
+
+    let result = contract.try_set_fee_config(&6000, &4001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    contract.set_fee_config(&6000, &4000);
+    assert_eq!(contract.get_fee_config(), FeeConfig { treasury_bps: 6000, agent_bps: 4000 });
+}
+
+#[test]
+fn test_cancel_refunds_reserved_settlement_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    contract.set_fee_config(&100, &50);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.cancel_remittance(&remittance_id);
+
+    assert_eq!(token.balance(&sender), 10_000);
+    assert_eq!(token.balance(&contract.address), 0);
+}
+
+#[test]
+fn test_withdraw_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let treasury = Address::generate(&env);
+
+    contract.set_fee_config(&100, &0);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_treasury_balance(&token.address), 10);
+
+    let result = contract.try_withdraw_treasury(&token.address, &treasury, &11);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    contract.withdraw_treasury(&token.address, &treasury, &4);
+    assert_eq!(token.balance(&treasury), 4);
+    assert_eq!(contract.get_treasury_balance(&token.address), 6);
+
+    // Still withdrawable after the token is de-listed
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, false);
+    });
+    contract.withdraw_treasury(&token.address, &treasury, &6);
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(contract.get_treasury_balance(&token.address), 0);
+}
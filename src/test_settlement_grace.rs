@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const EXPIRY: u64 = 2_000;
+const GRACE: u64 = 30;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+#[test]
+fn test_settlement_succeeds_at_end_of_grace_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_settlement_grace(&admin, &GRACE);
+    assert_eq!(contract.get_settlement_grace(), GRACE);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1_000, &Some(EXPIRY), &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_settlement_fails_one_second_after_grace_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_settlement_grace(&admin, &GRACE);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1_000, &Some(EXPIRY), &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + GRACE + 1);
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+    let result = contract.try_settle_partial(&remittance_id, &500);
+    assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+}
+
+#[test]
+fn test_default_grace_keeps_strict_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    assert_eq!(contract.get_settlement_grace(), 0);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1_000, &Some(EXPIRY), &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::SettlementExpired)));
+}
+
+#[test]
+fn test_refund_uses_strict_expiry_during_grace_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_settlement_grace(&admin, &GRACE);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1_000, &Some(EXPIRY), &None, &None, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY);
+    let result = contract.try_refund_remittance(&sender, &remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::RemittanceNotExpired)));
+
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    contract.refund_remittance(&sender, &remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Refunded);
+}
+
+#[test]
+fn test_set_settlement_grace_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, _admin) = setup(&env);
+
+    let result = contract.try_set_settlement_grace(&Address::generate(&env), &GRACE);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
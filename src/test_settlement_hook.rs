@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, Symbol, TryFromVal,
+};
+
+/// Hook that records the last notification it received.
+#[contract]
+pub struct RecordingHook;
+
+#[contractimpl]
+impl RecordingHook {
+    pub fn on_settlement(env: Env, remittance_id: u64, recipient: Address, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("last"), &(remittance_id, recipient, amount));
+    }
+
+    pub fn last(env: Env) -> Option<(u64, Address, i128)> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+/// Hook that rejects every notification, in its own module so its exported
+/// function does not clash with `RecordingHook::on_settlement`.
+mod failing {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FailingHook;
+
+    #[contractimpl]
+    impl FailingHook {
+        pub fn on_settlement(_env: Env, _remittance_id: u64, _recipient: Address, _amount: i128) {
+            panic!("hook unavailable");
+        }
+    }
+}
+use failing::FailingHook;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+fn hook_failures(env: &Env, contract: &Address) -> u32 {
+    let topics = (Symbol::new(env, "hook"), Symbol::new(env, "failed")).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(address, event_topics, _)| *address == *contract && *event_topics == topics)
+        .count() as u32
+}
+
+#[test]
+fn test_settlement_notifies_hook() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let hook = env.register_contract(None, RecordingHook);
+    contract.set_settlement_hook(&admin, &Some(hook.clone()));
+    assert_eq!(contract.get_settlement_hook(), Some(hook.clone()));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let recipient = contract.get_remittance(&remittance_id).recipient;
+    let last = RecordingHookClient::new(&env, &hook).last();
+    assert_eq!(last, Some((remittance_id, recipient, 975)));
+    assert_eq!(hook_failures(&env, &contract.address), 0);
+}
+
+#[test]
+fn test_failing_hook_does_not_revert_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let hook = env.register_contract(None, FailingHook);
+    contract.set_settlement_hook(&admin, &Some(hook.clone()));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&agent), 975);
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(address, topics, _)| {
+            *address == contract.address
+                && *topics == (Symbol::new(&env, "hook"), Symbol::new(&env, "failed")).into_val(&env)
+        })
+        .expect("hook failure event not emitted");
+    let data = <(u32, u32, u64, u64, Address)>::try_from_val(&env, &event.2).unwrap();
+    assert_eq!(data.3, remittance_id);
+    assert_eq!(data.4, hook);
+}
+
+#[test]
+fn test_hook_that_is_not_a_contract_does_not_revert_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_settlement_hook(&admin, &Some(Address::generate(&env)));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(hook_failures(&env, &contract.address), 1);
+}
+
+#[test]
+fn test_clearing_hook_stops_notifications() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let hook = env.register_contract(None, RecordingHook);
+    contract.set_settlement_hook(&admin, &Some(hook.clone()));
+    contract.set_settlement_hook(&admin, &None);
+    assert_eq!(contract.get_settlement_hook(), None);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    assert_eq!(RecordingHookClient::new(&env, &hook).last(), None);
+}
+
+#[test]
+fn test_set_settlement_hook_rejects_self_and_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, _sender, _agent, admin) = setup(&env);
+
+    let result = contract.try_set_settlement_hook(&admin, &Some(contract.address.clone()));
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+
+    let outsider = Address::generate(&env);
+    let hook = env.register_contract(None, RecordingHook);
+    let result = contract.try_set_settlement_hook(&outsider, &Some(hook));
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use crate::{Role, SettlementReceipt, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+fn last_receipt_event(env: &Env, recipient: &Address, token: &Address) -> SettlementReceipt {
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            *topics
+                == (Symbol::new(env, "settle"), Symbol::new(env, "receipt"), recipient.clone(), token.clone())
+                    .into_val(env)
+        })
+        .last()
+        .expect("receipt event");
+    let data: Vec<Val> = Vec::try_from_val(env, &event.2).unwrap();
+    SettlementReceipt::try_from_val(env, &data.get(3).unwrap()).unwrap()
+}
+
+#[test]
+fn test_confirm_payout_returns_receipt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let receipt = contract.confirm_payout(&remittance_id);
+
+    // 25 platform fee + 10 protocol fee
+    assert_eq!(
+        receipt,
+        SettlementReceipt {
+            remittance_id,
+            gross: 1000,
+            fee: 35,
+            net: 965,
+            settlement_fee: 0,
+            agent: agent.clone(),
+            settled_at: 1_000,
+        }
+    );
+    assert_eq!(token.balance(&agent), 965);
+    assert_eq!(last_receipt_event(&env, &agent, &token.address), receipt);
+}
+
+#[test]
+fn test_receipt_covers_only_the_remaining_tranche() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &300);
+    let first = last_receipt_event(&env, &agent, &token.address);
+    assert_eq!(first.gross, 300);
+
+    let receipt = contract.confirm_payout(&remittance_id);
+    assert_eq!(receipt.gross, 700);
+    assert_eq!(receipt.net, receipt.gross - receipt.fee);
+    assert_eq!(first.fee + receipt.fee, 35);
+    assert_eq!(first.net + receipt.net, token.balance(&agent));
+    assert_eq!(last_receipt_event(&env, &agent, &token.address), receipt);
+}
+
+#[test]
+fn test_creation_and_settlement_events_are_indexed_by_recipient_and_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    for action in ["created", "complete"] {
+        let topics: Vec<Val> =
+            (Symbol::new(&env, "remit"), Symbol::new(&env, action), agent.clone(), token.address.clone()).into_val(&env);
+        assert!(env.events().all().iter().any(|(_, t, _)| t == topics), "missing indexed {} event", action);
+    }
+    let topics: Vec<Val> =
+        (Symbol::new(&env, "settle"), Symbol::new(&env, "complete"), agent.clone(), token.address.clone()).into_val(&env);
+    assert!(env.events().all().iter().any(|(_, t, _)| t == topics));
+}
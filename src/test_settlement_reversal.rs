@@ -0,0 +1,220 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const WINDOW: u64 = 600;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let recipient = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.set_settlement_reversal_window(&admin, &WINDOW);
+
+    (contract, token::Client::new(env, &token.address), sender, recipient, admin)
+}
+
+/// Creates a 1000 remittance and has the recipient claim it, receiving 975 after the platform fee.
+fn claimed(contract: &SwiftRemitContractClient, token: &token::Client, sender: &Address, recipient: &Address) -> u64 {
+    let id = contract.create_claimable_remittance(sender, recipient, &token.address, &1_000, &None, &None);
+    contract.claim_remittance(&id);
+    assert_eq!(token.balance(recipient), 975);
+    id
+}
+
+#[test]
+fn test_reverse_settlement_within_window_restores_pending() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = claimed(&contract, &token, &sender, &recipient);
+    token.approve(&recipient, &contract.address, &975, &env.ledger().sequence().saturating_add(1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + WINDOW);
+    contract.reverse_settlement(&admin, &id);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!((remittance.amount, remittance.remaining, remittance.fee), (975, 975, 0));
+    assert_eq!(token.balance(&recipient), 0);
+    assert_eq!(contract.get_tvl(&token.address), 975);
+    assert_eq!(contract.get_global_stats().total_settled, 0);
+    assert_eq!(contract.get_global_stats().active, 1);
+
+    // The platform fee from the reversed settlement is kept
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+
+    // The restored remittance can be claimed again, with no further fee
+    contract.claim_remittance(&id);
+    assert_eq!(token.balance(&recipient), 975);
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(contract.get_tvl(&token.address), 0);
+}
+
+#[test]
+fn test_reverse_settlement_after_expiry_extends_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1_000, &Some(1_100), &None);
+    contract.claim_remittance(&id);
+    token.approve(&recipient, &contract.address, &975, &env.ledger().sequence().saturating_add(1_000));
+
+    // Past the original expiry but still inside the reversal window
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    contract.reverse_settlement(&admin, &id);
+
+    let remittance = contract.get_remittance(&id);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(remittance.expiry, Some(1_300 + WINDOW));
+    assert_eq!(
+        contract.try_refund_remittance(&sender, &id),
+        Err(Ok(ContractError::RemittanceNotExpired))
+    );
+
+    contract.claim_remittance(&id);
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_reversed_remittance_can_be_refunded_to_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = claimed(&contract, &token, &sender, &recipient);
+    token.approve(&recipient, &contract.address, &975, &env.ledger().sequence().saturating_add(1_000));
+    contract.reverse_settlement(&admin, &id);
+
+    contract.cancel_remittance(&id);
+    assert_eq!(token.balance(&sender), 10_000 - 25);
+    assert_eq!(token.balance(&contract.address), contract.get_accumulated_fees(&token.address));
+}
+
+#[test]
+fn test_reverse_settlement_after_window_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = claimed(&contract, &token, &sender, &recipient);
+    token.approve(&recipient, &contract.address, &975, &env.ledger().sequence().saturating_add(1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + WINDOW + 1);
+    let result = contract.try_reverse_settlement(&admin, &id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(token.balance(&recipient), 975);
+}
+
+#[test]
+fn test_reverse_settlement_requires_payee_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = claimed(&contract, &token, &sender, &recipient);
+    let result = contract.try_reverse_settlement(&admin, &id);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    token.approve(&recipient, &contract.address, &974, &env.ledger().sequence().saturating_add(1_000));
+    let result = contract.try_reverse_settlement(&admin, &id);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(token.balance(&recipient), 975);
+}
+
+#[test]
+fn test_reverse_settlement_rejects_non_admin_and_final_settlements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, recipient, admin) = setup(&env);
+
+    let id = claimed(&contract, &token, &sender, &recipient);
+    token.approve(&recipient, &contract.address, &975, &env.ledger().sequence().saturating_add(1_000));
+    let result = contract.try_reverse_settlement(&Address::generate(&env), &id);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    // Settlements made while reversal is disabled stay final
+    contract.set_settlement_reversal_window(&admin, &0);
+    let final_id = claimed(&contract, &token, &sender, &Address::generate(&env));
+    let result = contract.try_reverse_settlement(&admin, &final_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+
+    let pending = contract.create_claimable_remittance(&sender, &recipient, &token.address, &1_000, &None, &None);
+    let result = contract.try_reverse_settlement(&admin, &pending);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_resettling_reversed_remittance_counts_and_charges_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let asset = create_token_contract(&env, &admin);
+    asset.mint(&sender, &10_000);
+    let token = token::Client::new(&env, &asset.address);
+
+    let contract = create_swiftremit_contract(&env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &treasury, &0, &None);
+    contract.set_settlement_reversal_window(&admin, &WINDOW);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    contract.set_fee_config(&60, &40);
+    contract.set_volume_circuit_breaker(&admin, &1_500, &3_600);
+
+    // 1000 principal: 25 platform fee, 10 protocol fee, 6 + 4 settlement fee on top
+    let id = contract.create_remittance(&sender, &agent, &token.address, &1_000, &None, &None, &None, &None);
+    contract.confirm_payout(&id);
+    assert_eq!(token.balance(&agent), 969);
+    assert_eq!(contract.get_agent_stats(&agent), (1, 1_000));
+
+    token.approve(&agent, &contract.address, &965, &env.ledger().sequence().saturating_add(1_000));
+    contract.reverse_settlement(&admin, &id);
+    assert_eq!(token.balance(&agent), 4);
+    assert_eq!(contract.get_agent_stats(&agent), (0, 35));
+    assert_eq!(contract.get_agent_daily_usage(&agent).0, 35);
+
+    // Settling again pays the clawed-back amount without charging any fee twice
+    contract.confirm_payout(&id);
+    assert_eq!(token.balance(&agent), 969);
+    assert_eq!(contract.get_agent_stats(&agent), (1, 1_000));
+    assert_eq!(contract.get_agent_daily_usage(&agent).0, 1_000);
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(contract.get_treasury_balance(&token.address), 6);
+    assert_eq!(contract.get_accumulated_fees(&token.address), 25);
+    assert!(!contract.is_paused());
+    assert_eq!(contract.get_tvl(&token.address), 0);
+}
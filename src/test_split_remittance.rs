@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &admin, &0, &None);
+    contract.set_fee_config(&60, &40);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_split_remittance_pays_every_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+
+    let recipients = vec![&env, (alice.clone(), 1000), (bob.clone(), 600), (carol.clone(), 400)];
+    let remittance_id = contract.create_split_remittance(&sender, &agent, &token.address, &recipients, &None, &None);
+
+    // 2000 principal + 12 treasury fee + 8 agent fee
+    assert_eq!(token.balance(&contract.address), 2020);
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 2000);
+    assert_eq!(contract.get_remittance_splits(&remittance_id), recipients);
+
+    let receipt = contract.settle_split(&remittance_id);
+
+    // 2.5% platform fee and 1% protocol fee, shared pro rata
+    assert_eq!(token.balance(&alice), 965);
+    assert_eq!(token.balance(&bob), 579);
+    assert_eq!(token.balance(&carol), 386);
+    assert_eq!(token.balance(&agent), 8);
+    assert_eq!(receipt.net, 965 + 579 + 386);
+    assert_eq!(receipt.fee, 70);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Completed);
+    assert_eq!(contract.get_tvl(&token.address), 0);
+}
+
+#[test]
+fn test_split_shares_always_sum_to_net_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let recipients = vec![
+        &env,
+        (Address::generate(&env), 333),
+        (Address::generate(&env), 333),
+        (Address::generate(&env), 334),
+    ];
+
+    let remittance_id = contract.create_split_remittance(&sender, &agent, &token.address, &recipients, &None, &None);
+    let receipt = contract.settle_split(&remittance_id);
+
+    let paid: i128 = recipients.iter().map(|(recipient, _)| token.balance(&recipient)).sum();
+    assert_eq!(paid, receipt.net);
+    assert_eq!(token.balance(&contract.address), contract.get_accumulated_fees(&token.address) + contract.get_treasury_balance(&token.address));
+}
+
+#[test]
+fn test_split_recipients_are_validated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+    let alice = Address::generate(&env);
+
+    let result = contract.try_create_split_remittance(&sender, &agent, &token.address, &Vec::new(&env), &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::EmptyCollection)));
+
+    let result = contract.try_create_split_remittance(
+        &sender, &agent, &token.address, &vec![&env, (alice.clone(), 100), (alice.clone(), 0)], &None, &None,
+    );
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let result = contract.try_create_split_remittance(
+        &sender, &agent, &token.address, &vec![&env, (alice.clone(), i128::MAX), (alice.clone(), 1)], &None, &None,
+    );
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..11 {
+        too_many.push_back((Address::generate(&env), 10));
+    }
+    let result = contract.try_create_split_remittance(&sender, &agent, &token.address, &too_many, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::BatchTooLarge)));
+}
+
+#[test]
+fn test_split_remittance_only_settles_through_settle_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let split_id = contract.create_split_remittance(
+        &sender, &agent, &token.address, &vec![&env, (Address::generate(&env), 1000)], &None, &None,
+    );
+    let plain_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    assert_eq!(contract.try_confirm_payout(&split_id), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.try_settle_partial(&split_id, &100), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.try_top_up_remittance(&split_id, &100), Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(contract.try_settle_split(&plain_id), Err(Ok(ContractError::InvalidStatus)));
+
+    contract.settle_split(&split_id);
+    assert_eq!(contract.try_settle_split(&split_id), Err(Ok(ContractError::InvalidStatus)));
+}
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use crate::{RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+
+type StatusChange = (u64, Option<RemittanceStatus>, RemittanceStatus, u64);
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent)
+}
+
+/// Status changes published by the contract so far, in emission order.
+fn status_changes(env: &Env, contract: &Address) -> soroban_sdk::Vec<StatusChange> {
+    let topic: soroban_sdk::Vec<Val> = (
+        Symbol::new(env, "remittance"),
+        Symbol::new(env, "status_changed"),
+    )
+        .into_val(env);
+    let mut changes = soroban_sdk::Vec::new(env);
+    for (emitter, topics, data) in env.events().all().iter() {
+        if &emitter != contract {
+            continue;
+        }
+        if topics == topic {
+            changes.push_back(StatusChange::try_from_val(env, &data).unwrap());
+        }
+    }
+    changes
+}
+
+#[test]
+fn test_status_changed_on_create_and_partial_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.settle_partial(&remittance_id, &400);
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    contract.confirm_payout(&remittance_id);
+
+    let changes = status_changes(&env, &contract.address);
+    assert_eq!(
+        changes,
+        vec![
+            &env,
+            (remittance_id, None, RemittanceStatus::Pending, 1_000),
+            (remittance_id, Some(RemittanceStatus::Pending), RemittanceStatus::PartiallySettled, 1_000),
+            (remittance_id, Some(RemittanceStatus::PartiallySettled), RemittanceStatus::Completed, 1_500),
+        ]
+    );
+}
+
+#[test]
+fn test_status_changed_on_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    contract.cancel_remittance(&remittance_id);
+
+    let changes = status_changes(&env, &contract.address);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes.get_unchecked(1),
+        (remittance_id, Some(RemittanceStatus::Pending), RemittanceStatus::Cancelled, 0)
+    );
+}
+
+#[test]
+fn test_status_changed_on_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &Some(100), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    contract.refund_remittance(&sender, &remittance_id);
+
+    let changes = status_changes(&env, &contract.address);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes.get_unchecked(1),
+        (remittance_id, Some(RemittanceStatus::Pending), RemittanceStatus::Refunded, 200)
+    );
+}
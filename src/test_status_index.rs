@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use crate::{RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, Vec,
+};
+
+const EXPIRY: u64 = 2_000;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent, admin)
+}
+
+fn list(contract: &SwiftRemitContractClient, status: RemittanceStatus) -> Vec<u64> {
+    contract.list_remittances_by_status(&status, &0, &100)
+}
+
+#[test]
+fn test_status_lists_follow_transitions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    let create = |expiry: Option<u64>| {
+        contract.create_remittance(&sender, &agent, &token, &1_000, &expiry, &None, &None, &None)
+    };
+    let settled = create(None);
+    let partial = create(None);
+    let cancelled = create(None);
+    let expired = create(Some(EXPIRY));
+    let held = create(None);
+    let untouched = create(None);
+
+    assert_eq!(
+        list(&contract, RemittanceStatus::Pending),
+        vec![&env, settled, partial, cancelled, expired, held, untouched]
+    );
+
+    contract.confirm_payout(&settled);
+    contract.settle_partial(&partial, &400);
+    contract.settle_partial(&partial, &100);
+    contract.cancel_remittance(&cancelled);
+    contract.place_hold(&admin, &held);
+    env.ledger().with_mut(|li| li.timestamp = EXPIRY + 1);
+    contract.sweep_expired(&vec![&env, expired]);
+
+    assert_eq!(list(&contract, RemittanceStatus::Pending), vec![&env, held, untouched]);
+    assert_eq!(list(&contract, RemittanceStatus::Completed), vec![&env, settled]);
+    assert_eq!(list(&contract, RemittanceStatus::PartiallySettled), vec![&env, partial]);
+    assert_eq!(list(&contract, RemittanceStatus::Cancelled), vec![&env, cancelled]);
+    assert_eq!(list(&contract, RemittanceStatus::Expired), vec![&env, expired]);
+    assert_eq!(contract.list_held_remittances(&0, &100), vec![&env, held]);
+
+    // Each list matches the stored status of every remittance it names
+    for status in [
+        RemittanceStatus::Pending,
+        RemittanceStatus::PartiallySettled,
+        RemittanceStatus::Completed,
+        RemittanceStatus::Cancelled,
+        RemittanceStatus::Expired,
+    ] {
+        for id in list(&contract, status.clone()).iter() {
+            assert_eq!(contract.get_remittance(&id).status, status);
+        }
+    }
+
+    contract.refund_remittance(&sender, &expired);
+    contract.release_hold(&admin, &held);
+    contract.confirm_payout(&partial);
+
+    assert!(list(&contract, RemittanceStatus::Expired).is_empty());
+    assert!(list(&contract, RemittanceStatus::PartiallySettled).is_empty());
+    assert_eq!(list(&contract, RemittanceStatus::Refunded), vec![&env, expired]);
+    assert_eq!(list(&contract, RemittanceStatus::Completed), vec![&env, settled, partial]);
+    assert!(contract.list_held_remittances(&0, &100).is_empty());
+}
+
+#[test]
+fn test_list_remittances_by_status_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..5 {
+        ids.push_back(contract.create_remittance(&sender, &agent, &token, &100, &None, &None, &None, &None));
+    }
+
+    assert_eq!(contract.list_remittances_by_status(&RemittanceStatus::Pending, &0, &2), ids.slice(0..2));
+    assert_eq!(contract.list_remittances_by_status(&RemittanceStatus::Pending, &2, &2), ids.slice(2..4));
+    assert_eq!(contract.list_remittances_by_status(&RemittanceStatus::Pending, &4, &2), ids.slice(4..5));
+    assert!(contract.list_remittances_by_status(&RemittanceStatus::Pending, &5, &2).is_empty());
+    assert!(contract.list_remittances_by_status(&RemittanceStatus::Reclaimed, &0, &10).is_empty());
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+
+    (contract, token::Client::new(env, &token.address), sender, agent)
+}
+
+#[test]
+fn test_sweep_expires_only_qualifying_remittances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let stale = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+    let fresh = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(5_000), &None, &None, &None);
+    let open_ended = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+    contract.cancel_remittance(&cancelled);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let swept = contract.sweep_expired(&vec![&env, stale, fresh, open_ended, cancelled, 99]);
+
+    assert_eq!(swept, vec![&env, stale]);
+    assert_eq!(contract.get_remittance(&stale).status, RemittanceStatus::Expired);
+    assert_eq!(contract.get_remittance(&fresh).status, RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&open_ended).status, RemittanceStatus::Pending);
+    assert_eq!(contract.get_remittance(&cancelled).status, RemittanceStatus::Cancelled);
+}
+
+#[test]
+fn test_expired_remittance_is_refundable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.sweep_expired(&vec![&env, remittance_id]);
+
+    // Sweeping is idempotent
+    assert_eq!(contract.sweep_expired(&vec![&env, remittance_id]).len(), 0);
+
+    contract.refund_remittance(&sender, &remittance_id);
+    assert_eq!(contract.get_remittance(&remittance_id).status, RemittanceStatus::Refunded);
+    assert_eq!(token.balance(&sender), 10_000);
+}
+
+#[test]
+fn test_expired_remittance_cannot_settle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(1_500), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    contract.sweep_expired(&vec![&env, remittance_id]);
+
+    let result = contract.try_confirm_payout(&remittance_id);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
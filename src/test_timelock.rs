@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use crate::{AdminAction, ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+const DELAY: u64 = 3_600;
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address) {
+    let admin = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &DELAY, &None);
+
+    (contract, token::Client::new(env, &token.address), admin)
+}
+
+#[test]
+fn test_queued_fee_change_runs_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, _token, admin) = setup(&env);
+
+    let action_id = contract.queue_action(&admin, &AdminAction::UpdateFee(500));
+    let queued = contract.get_queued_action(&action_id);
+    assert_eq!(queued.unlock_at, 1_000 + DELAY);
+    assert_eq!(queued.queued_by, admin);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DELAY - 1);
+    let result = contract.try_execute_action(&admin, &action_id);
+    assert_eq!(result, Err(Ok(ContractError::TimelockNotElapsed)));
+    assert_eq!(contract.get_platform_fee_bps(), 250);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DELAY);
+    contract.execute_action(&admin, &action_id);
+    assert_eq!(contract.get_platform_fee_bps(), 500);
+
+    // Executed actions leave the queue and cannot be replayed
+    let result = contract.try_execute_action(&admin, &action_id);
+    assert_eq!(result, Err(Ok(ContractError::ActionNotFound)));
+}
+
+#[test]
+fn test_direct_calls_require_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin) = setup(&env);
+    let other = Address::generate(&env);
+
+    assert_eq!(contract.try_update_fee(&500), Err(Ok(ContractError::TimelockRequired)));
+    assert_eq!(
+        contract.try_withdraw_treasury(&token.address, &other, &1),
+        Err(Ok(ContractError::TimelockRequired))
+    );
+    assert_eq!(
+        contract.try_remove_admin(&admin, &other),
+        Err(Ok(ContractError::TimelockRequired))
+    );
+}
+
+#[test]
+fn test_queued_treasury_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, admin) = setup(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    token::StellarAssetClient::new(&env, &token.address).mint(&sender, &10_000);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+    contract.set_fee_config(&100, &0);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(contract.get_treasury_balance(&token.address), 10);
+
+    let action_id = contract.queue_action(&admin, &AdminAction::WithdrawTreasury(token.address.clone(), to.clone(), 10));
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    contract.execute_action(&admin, &action_id);
+
+    assert_eq!(token.balance(&to), 10);
+    assert_eq!(contract.get_treasury_balance(&token.address), 0);
+}
+
+#[test]
+fn test_queued_admin_removal_keeps_last_admin_invariant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin) = setup(&env);
+    let second = Address::generate(&env);
+    contract.propose_admin(&admin, &second);
+    contract.accept_admin();
+
+    let removal = contract.queue_action(&admin, &AdminAction::RemoveAdmin(second.clone()));
+    let last = contract.queue_action(&admin, &AdminAction::RemoveAdmin(admin.clone()));
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+
+    contract.execute_action(&admin, &removal);
+    assert!(!contract.is_admin(&second));
+
+    let result = contract.try_execute_action(&admin, &last);
+    assert_eq!(result, Err(Ok(ContractError::CannotRemoveLastAdmin)));
+}
+
+#[test]
+fn test_cancel_queued_action() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin) = setup(&env);
+
+    let action_id = contract.queue_action(&admin, &AdminAction::UpdateFee(500));
+    contract.cancel_queued_action(&admin, &action_id);
+
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    let result = contract.try_execute_action(&admin, &action_id);
+    assert_eq!(result, Err(Ok(ContractError::ActionNotFound)));
+
+    let result = contract.try_cancel_queued_action(&admin, &action_id);
+    assert_eq!(result, Err(Ok(ContractError::ActionNotFound)));
+}
+
+#[test]
+fn test_non_admin_cannot_queue_or_execute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _token, admin) = setup(&env);
+    let outsider = Address::generate(&env);
+
+    let result = contract.try_queue_action(&outsider, &AdminAction::UpdateFee(500));
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+    let action_id = contract.queue_action(&admin, &AdminAction::UpdateFee(500));
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    let result = contract.try_execute_action(&outsider, &action_id);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
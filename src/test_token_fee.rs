@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use crate::{ContractError, FeeConfig, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let usdc = create_token_contract(env, &admin);
+    usdc.mint(&sender, &100_000);
+    let exotic = create_token_contract(env, &admin);
+    exotic.mint(&sender, &100_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &usdc.address, true);
+    });
+    contract.initialize(&admin, &usdc.address, &250, &0, &admin, &0, &None);
+    contract.whitelist_token(&admin, &exotic.address);
+    contract.set_fee_config(&60, &40);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, usdc.address, exotic.address, sender, agent, admin)
+}
+
+#[test]
+fn test_token_override_is_charged_instead_of_global_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, usdc, exotic, sender, agent, admin) = setup(&env);
+
+    contract.set_token_fee(&admin, &exotic, &300, &200);
+    assert_eq!(contract.get_token_fee(&exotic), Some(FeeConfig { treasury_bps: 300, agent_bps: 200 }));
+    assert_eq!(contract.get_token_fee(&usdc), None);
+
+    // Global split: 6 treasury + 4 agent on top of the principal
+    let usdc_id = contract.create_remittance(&sender, &agent, &usdc, &1_000, &None, &None, &None, &None);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&sender), 100_000 - 1_010);
+    // Override: 30 treasury + 20 agent
+    let exotic_id = contract.create_remittance(&sender, &agent, &exotic, &1_000, &None, &None, &None, &None);
+    assert_eq!(token::Client::new(&env, &exotic).balance(&sender), 100_000 - 1_050);
+
+    assert_eq!(contract.quote(&1_000, &usdc, &None).settlement_fee, 10);
+    assert_eq!(contract.quote(&1_000, &exotic, &None).settlement_fee, 50);
+
+    let usdc_receipt = contract.confirm_payout(&usdc_id);
+    let exotic_receipt = contract.confirm_payout(&exotic_id);
+    assert_eq!(usdc_receipt.settlement_fee, 10);
+    assert_eq!(exotic_receipt.settlement_fee, 50);
+    assert_eq!(contract.get_treasury_balance(&usdc), 6);
+    assert_eq!(contract.get_treasury_balance(&exotic), 30);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&agent), 975 + 4);
+    assert_eq!(token::Client::new(&env, &exotic).balance(&agent), 975 + 20);
+}
+
+#[test]
+fn test_clear_token_fee_reverts_to_global_split() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _usdc, exotic, sender, agent, admin) = setup(&env);
+
+    contract.set_token_fee(&admin, &exotic, &300, &200);
+    let reserved_id = contract.create_remittance(&sender, &agent, &exotic, &1_000, &None, &None, &None, &None);
+
+    contract.clear_token_fee(&admin, &exotic);
+    assert_eq!(contract.get_token_fee(&exotic), None);
+    assert_eq!(contract.quote(&1_000, &exotic, &None).settlement_fee, 10);
+
+    // Fees reserved under the override are kept by the existing remittance
+    assert_eq!(contract.confirm_payout(&reserved_id).settlement_fee, 50);
+}
+
+#[test]
+fn test_set_token_fee_validates_shares_and_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _usdc, exotic, _sender, agent, admin) = setup(&env);
+
+    let result = contract.try_set_token_fee(&admin, &exotic, &6_000, &4_001);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+    contract.set_token_fee(&admin, &exotic, &6_000, &4_000);
+
+    let result = contract.try_set_token_fee(&agent, &exotic, &0, &0);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    let result = contract.try_clear_token_fee(&agent, &exotic);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(contract.get_token_fee(&exotic), Some(FeeConfig { treasury_bps: 6_000, agent_bps: 4_000 }));
+}
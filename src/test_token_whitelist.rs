@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, admin, token.address)
+}
+
+#[test]
+fn test_whitelist_tokens_adds_all_new_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, _usdc) = setup(&env);
+
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let token_c = Address::generate(&env);
+
+    let added = contract.whitelist_tokens(&admin, &vec![&env, token_a.clone(), token_b.clone(), token_c.clone()]);
+    assert_eq!(added, 3);
+    assert!(contract.is_token_whitelisted(&token_a));
+    assert!(contract.is_token_whitelisted(&token_b));
+    assert!(contract.is_token_whitelisted(&token_c));
+}
+
+#[test]
+fn test_whitelist_tokens_skips_existing_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, usdc) = setup(&env);
+
+    let token_a = Address::generate(&env);
+    contract.whitelist_token(&admin, &token_a);
+
+    let token_b = Address::generate(&env);
+    let added = contract.whitelist_tokens(&admin, &vec![&env, usdc, token_a, token_b.clone()]);
+    assert_eq!(added, 1);
+    assert!(contract.is_token_whitelisted(&token_b));
+}
+
+#[test]
+fn test_whitelist_tokens_invalid_entry_rolls_back_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, _usdc) = setup(&env);
+
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+
+    let result = contract.try_whitelist_tokens(&admin, &vec![&env, token_a.clone(), contract.address.clone()]);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+    assert!(!contract.is_token_whitelisted(&token_a));
+
+    let result = contract.try_whitelist_tokens(&admin, &vec![&env, token_a.clone(), token_b.clone(), token_a.clone()]);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAddress)));
+    assert!(!contract.is_token_whitelisted(&token_a));
+    assert!(!contract.is_token_whitelisted(&token_b));
+}
+
+#[test]
+#[should_panic]
+fn test_whitelist_tokens_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, _usdc) = setup(&env);
+
+    // Drop the mocked auths so the admin signature is missing
+    env.set_auths(&[]);
+    contract.whitelist_tokens(&admin, &vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+fn test_list_whitelisted_tokens_tracks_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin, usdc) = setup(&env);
+
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+    let token_c = Address::generate(&env);
+    contract.whitelist_token(&admin, &token_a);
+    contract.whitelist_token(&admin, &token_b);
+    contract.whitelist_token(&admin, &token_c);
+    assert_eq!(
+        contract.list_whitelisted_tokens(),
+        vec![&env, usdc.clone(), token_a.clone(), token_b.clone(), token_c.clone()]
+    );
+
+    contract.remove_whitelisted_token(&admin, &token_b);
+
+    assert!(contract.is_token_whitelisted(&token_a));
+    assert!(!contract.is_token_whitelisted(&token_b));
+    assert!(contract.is_token_whitelisted(&token_c));
+    assert_eq!(contract.list_whitelisted_tokens(), vec![&env, usdc, token_a, token_c]);
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::{ContractError, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, admin)
+}
+
+#[test]
+fn test_top_up_increases_amount_and_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.top_up_remittance(&remittance_id, &500);
+
+    let remittance = contract.get_remittance(&remittance_id);
+    assert_eq!(remittance.amount, 1500);
+    assert_eq!(remittance.remaining, 1500);
+    assert_eq!(remittance.fee, 37);
+    assert_eq!(token.balance(&contract.address), 1500);
+    assert_eq!(token.balance(&sender), 8500);
+
+    // The topped-up total settles as one remittance
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(token.balance(&agent), 1463);
+}
+
+#[test]
+fn test_top_up_above_max_amount_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, admin) = setup(&env);
+
+    contract.set_max_remittance_amount(&admin, &1200);
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+
+    let result = contract.try_top_up_remittance(&remittance_id, &201);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    let result = contract.try_top_up_remittance(&remittance_id, &0);
+    assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
+
+    contract.top_up_remittance(&remittance_id, &200);
+    assert_eq!(contract.get_remittance(&remittance_id).amount, 1200);
+}
+
+#[test]
+fn test_top_up_settled_remittance_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.confirm_payout(&remittance_id);
+
+    let result = contract.try_top_up_remittance(&remittance_id, &100);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_top_up_expired_remittance_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &Some(2_000), &None, &None, &None);
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+
+    let result = contract.try_top_up_remittance(&remittance_id, &100);
+    assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
+}
+
+#[test]
+fn test_top_up_requires_sender_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _admin) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    contract.top_up_remittance(&remittance_id, &100);
+
+    assert!(env.auths().iter().any(|(address, _)| *address == sender));
+}
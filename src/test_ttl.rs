@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::{Role, SwiftRemitContract, SwiftRemitContractClient, LEDGER_CLOSE_SECONDS, REMITTANCE_TTL_BUFFER_LEDGERS};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &10_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token.address, sender, agent)
+}
+
+fn remittance_ttl(env: &Env, contract: &SwiftRemitContractClient, id: u64) -> u32 {
+    env.as_contract(&contract.address, || crate::storage::get_remittance_ttl(env, id))
+}
+
+/// Keeps the contract instance and token entries alive while the ledger is advanced.
+fn advance_ledgers(env: &Env, contract: &SwiftRemitContractClient, token: &Address, ledgers: u32) {
+    env.as_contract(&contract.address, || {
+        env.storage().instance().extend_ttl(ledgers * 2, ledgers * 2);
+    });
+    env.deployer().extend_ttl(contract.address.clone(), ledgers * 2, ledgers * 2);
+    env.deployer().extend_ttl(token.clone(), ledgers * 2, ledgers * 2);
+    env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_creation_extends_ttl_past_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (contract, token, sender, agent) = setup(&env);
+
+    let window = 30 * 86_400;
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &Some(1_000 + window), &None, &None, &None);
+
+    let expiry_ledgers = (window / LEDGER_CLOSE_SECONDS) as u32;
+    assert!(remittance_ttl(&env, &contract, remittance_id) >= expiry_ledgers + REMITTANCE_TTL_BUFFER_LEDGERS);
+}
+
+#[test]
+fn test_bump_keeps_near_expiry_entry_alive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent) = setup(&env);
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &token, &1000, &None, &None, &None, &None);
+    let initial_ttl = remittance_ttl(&env, &contract, remittance_id);
+
+    // Leave the record a handful of ledgers from archival
+    advance_ledgers(&env, &contract, &token, initial_ttl - 10);
+    assert_eq!(remittance_ttl(&env, &contract, remittance_id), 10);
+
+    contract.bump_remittance(&remittance_id);
+    assert!(remittance_ttl(&env, &contract, remittance_id) >= REMITTANCE_TTL_BUFFER_LEDGERS);
+
+    // The record outlives its original TTL
+    advance_ledgers(&env, &contract, &token, 100);
+    assert_eq!(contract.get_remittance(&remittance_id).id, remittance_id);
+}
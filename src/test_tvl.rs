@@ -0,0 +1,140 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{Role, SwiftRemitContract, SwiftRemitContractClient};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, token::Client<'a>, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+    let recipient = Address::generate(env);
+
+    let token = create_token_contract(env, &admin);
+    token.mint(&sender, &1_000_000_000);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &100, &admin, &0, &None);
+    contract.set_fee_config(&60, &40);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    (contract, token::Client::new(env, &token.address), sender, agent, recipient)
+}
+
+/// Balance the contract holds that is not locked in an open remittance.
+fn accrued(contract: &SwiftRemitContractClient, token: &Address) -> i128 {
+    contract.get_accumulated_fees(token) + contract.get_treasury_balance(token)
+}
+
+#[test]
+fn test_tvl_tracks_create_settle_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _recipient) = setup(&env);
+
+    assert_eq!(contract.get_tvl(&token.address), 0);
+
+    // 1000 principal + 6 treasury fee + 4 agent fee
+    let settled_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    let cancelled_id = contract.create_remittance(&sender, &agent, &token.address, &1000, &None, &None, &None, &None);
+    assert_eq!(contract.get_tvl(&token.address), 2020);
+
+    contract.settle_partial(&settled_id, &500);
+    assert_eq!(contract.get_tvl(&token.address), 1515);
+
+    contract.confirm_payout(&settled_id);
+    assert_eq!(contract.get_tvl(&token.address), 1010);
+
+    contract.cancel_remittance(&cancelled_id);
+    assert_eq!(contract.get_tvl(&token.address), 0);
+    assert_eq!(token.balance(&contract.address), accrued(&contract, &token.address));
+}
+
+#[test]
+fn test_tvl_is_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, token, sender, agent, _recipient) = setup(&env);
+
+    let other = create_token_contract(&env, &Address::generate(&env));
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(&env, &other.address, true);
+    });
+    other.mint(&sender, &10_000);
+
+    contract.create_remittance(&sender, &agent, &other.address, &1000, &None, &None, &None, &None);
+
+    assert_eq!(contract.get_tvl(&token.address), 0);
+    assert_eq!(contract.get_tvl(&other.address), 1010);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(20))]
+
+    /// Property: after any sequence of operations, TVL equals the contract's
+    /// balance minus accrued platform fees and the treasury balance.
+    #[test]
+    fn prop_tvl_reconciles_with_contract_balance(
+        ops in prop::collection::vec((0u8..7, 0usize..8, 1i128..=10_000), 1..25)
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (contract, token, sender, agent, recipient) = setup(&env);
+        let mut ids: std::vec::Vec<u64> = std::vec::Vec::new();
+
+        for (op, pick, amount) in ops {
+            let id = if ids.is_empty() { 0 } else { ids[pick % ids.len()] };
+
+            // Rejected operations must leave the counter untouched, so errors are ignored
+            match op {
+                0 => {
+                    if let Ok(Ok(id)) = contract.try_create_remittance(
+                        &sender, &agent, &token.address, &amount, &None, &None, &None, &None,
+                    ) {
+                        ids.push(id);
+                    }
+                }
+                1 => {
+                    if let Ok(Ok(id)) = contract.try_create_claimable_remittance(
+                        &sender, &recipient, &token.address, &amount, &None, &None,
+                    ) {
+                        ids.push(id);
+                    }
+                }
+                2 => {
+                    let _ = contract.try_settle_partial(&id, &amount);
+                }
+                3 => {
+                    let _ = contract.try_confirm_payout(&id);
+                    let _ = contract.try_claim_remittance(&id);
+                }
+                4 => {
+                    let _ = contract.try_cancel_remittance(&id);
+                }
+                5 => {
+                    let _ = contract.try_top_up_remittance(&id, &amount);
+                }
+                _ => {
+                    let _ = contract.try_withdraw_fees(&token.address, &agent);
+                }
+            }
+
+            let tvl = contract.get_tvl(&token.address);
+            prop_assert!(tvl >= 0);
+            prop_assert_eq!(tvl, token.balance(&contract.address) - accrued(&contract, &token.address));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::{ContractError, SwiftRemitContract, SwiftRemitContractClient, INITIAL_CONTRACT_VERSION};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address) {
+    let admin = Address::generate(env);
+    let token = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+
+    (contract, admin)
+}
+
+#[test]
+fn test_version_set_at_initialization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract = create_swiftremit_contract(&env);
+    assert_eq!(contract.version(), 0);
+
+    let admin = Address::generate(&env);
+    let token = create_token_contract(&env, &admin);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(&env, &token.address, true);
+    });
+    contract.initialize(&admin, &token.address, &250, &0, &admin, &0, &None);
+    assert_eq!(contract.version(), INITIAL_CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_advances_one_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+
+    contract.migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(contract.version(), INITIAL_CONTRACT_VERSION + 1);
+
+    contract.migrate(&admin, &(INITIAL_CONTRACT_VERSION + 2));
+    assert_eq!(contract.version(), INITIAL_CONTRACT_VERSION + 2);
+}
+
+#[test]
+fn test_migrate_rejects_skipped_or_replayed_versions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+
+    let result = contract.try_migrate(&admin, &(INITIAL_CONTRACT_VERSION + 2));
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+
+    let result = contract.try_migrate(&admin, &INITIAL_CONTRACT_VERSION);
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+
+    contract.migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    let result = contract.try_migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(result, Err(Ok(ContractError::InvalidMigrationBatch)));
+    assert_eq!(contract.version(), INITIAL_CONTRACT_VERSION + 1);
+}
+
+#[test]
+fn test_migrate_rejected_during_batched_import() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, admin) = setup(&env);
+
+    env.as_contract(&contract.address, || {
+        crate::storage::set_migration_in_progress(&env, true);
+    });
+
+    let result = contract.try_migrate(&admin, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(result, Err(Ok(ContractError::MigrationInProgress)));
+}
+
+#[test]
+fn test_migrate_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _admin) = setup(&env);
+
+    let outsider = Address::generate(&env);
+    let result = contract.try_migrate(&outsider, &(INITIAL_CONTRACT_VERSION + 1));
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
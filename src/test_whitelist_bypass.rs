@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use crate::{ContractError, RemittanceStatus, Role, SwiftRemitContract, SwiftRemitContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, Symbol, TryFromVal,
+};
+
+fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    token::StellarAssetClient::new(env, &env.register_stellar_asset_contract_v2(admin.clone()).address())
+}
+
+fn create_swiftremit_contract<'a>(env: &Env) -> SwiftRemitContractClient<'a> {
+    SwiftRemitContractClient::new(env, &env.register_contract(None, SwiftRemitContract {}))
+}
+
+fn setup<'a>(env: &'a Env) -> (SwiftRemitContractClient<'a>, Address, Address, Address, Address) {
+    let admin = Address::generate(env);
+    let sender = Address::generate(env);
+    let agent = Address::generate(env);
+
+    let usdc = create_token_contract(env, &admin);
+
+    let contract = create_swiftremit_contract(env);
+    env.as_contract(&contract.address, || {
+        crate::storage::set_token_whitelisted(env, &usdc.address, true);
+    });
+    contract.initialize(&admin, &usdc.address, &250, &0, &admin, &0, &None);
+    contract.register_agent(&agent, &None);
+    contract.assign_role(&admin, &agent, &Role::Settler);
+
+    // A mock token that was never whitelisted
+    let mock = create_token_contract(env, &admin);
+    mock.mint(&sender, &10_000);
+
+    (contract, mock.address, sender, agent, admin)
+}
+
+/// The `enabled` value of the most recent whitelist requirement event, if any.
+fn last_requirement_event(env: &Env, contract: &Address) -> Option<(Address, bool)> {
+    let topics = (Symbol::new(env, "whitelist"), Symbol::new(env, "required")).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(address, event_topics, _)| *address == *contract && *event_topics == topics)
+        .last()
+        .map(|(_, _, data)| {
+            let data = <(u32, u32, u64, Address, bool)>::try_from_val(env, &data).unwrap();
+            (data.3, data.4)
+        })
+}
+
+#[test]
+fn test_whitelist_required_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, mock, sender, agent, _admin) = setup(&env);
+
+    assert!(contract.is_token_whitelist_required());
+    let result = contract.try_create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+}
+
+#[test]
+fn test_disabling_requirement_accepts_any_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, mock, sender, agent, admin) = setup(&env);
+
+    contract.set_require_token_whitelist(&admin, &false);
+    assert!(!contract.is_token_whitelist_required());
+    assert_eq!(last_requirement_event(&env, &contract.address), Some((admin.clone(), false)));
+
+    let remittance_id = contract.create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+    contract.create_claimable_remittance(&sender, &Address::generate(&env), &mock, &1_000, &None, &None);
+
+    // The whitelist itself is untouched
+    assert!(!contract.is_token_whitelisted(&mock));
+    contract.confirm_payout(&remittance_id);
+    assert_eq!(token::Client::new(&env, &mock).balance(&agent), 975);
+}
+
+#[test]
+fn test_reenabling_requirement_keeps_existing_remittances_usable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, mock, sender, agent, admin) = setup(&env);
+
+    contract.set_require_token_whitelist(&admin, &false);
+    let settled = contract.create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+    let cancelled = contract.create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+
+    contract.set_require_token_whitelist(&admin, &true);
+    assert_eq!(last_requirement_event(&env, &contract.address), Some((admin.clone(), true)));
+
+    let result = contract.try_create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+    assert_eq!(result, Err(Ok(ContractError::TokenNotWhitelisted)));
+
+    // Remittances opened while the requirement was off still settle and cancel
+    contract.confirm_payout(&settled);
+    contract.cancel_remittance(&cancelled);
+    assert_eq!(contract.get_remittance(&settled).status, RemittanceStatus::Completed);
+    assert_eq!(contract.get_remittance(&cancelled).status, RemittanceStatus::Cancelled);
+
+    // Whitelisting the token is again the way to accept it
+    contract.whitelist_token(&admin, &mock);
+    contract.create_remittance(&sender, &agent, &mock, &1_000, &None, &None, &None, &None);
+}
+
+#[test]
+fn test_set_require_token_whitelist_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract, _mock, _sender, agent, _admin) = setup(&env);
+
+    let result = contract.try_set_require_token_whitelist(&agent, &false);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert!(contract.is_token_whitelist_required());
+}
@@ -1,60 +1,113 @@
-use crate::types::RemittanceStatus;
 use crate::errors::ContractError;
+use crate::types::{Remittance, RemittanceStatus};
+
+/// Whether a remittance may move from `from` to `to`.
+///
+/// The allowed edges are:
+/// - `Pending` to `PartiallySettled`, `Completed`, `Cancelled`, `Expired`, `Refunded` or `Reclaimed`
+/// - `PartiallySettled` to `PartiallySettled` (a further tranche) or `Completed`
+/// - `Expired` to `Refunded` or `Reclaimed`
+/// - `Completed` back to `Pending` when a settlement is reversed
+///
+/// `Cancelled`, `Refunded` and `Reclaimed` are terminal.
+pub fn is_valid_transition(from: &RemittanceStatus, to: &RemittanceStatus) -> bool {
+    matches!(
+        (from, to),
+        (
+            RemittanceStatus::Pending,
+            RemittanceStatus::PartiallySettled
+                | RemittanceStatus::Completed
+                | RemittanceStatus::Cancelled
+                | RemittanceStatus::Expired
+                | RemittanceStatus::Refunded
+                | RemittanceStatus::Reclaimed
+        ) | (
+            RemittanceStatus::PartiallySettled,
+            RemittanceStatus::PartiallySettled | RemittanceStatus::Completed
+        ) | (
+            RemittanceStatus::Expired,
+            RemittanceStatus::Refunded | RemittanceStatus::Reclaimed
+        ) | (RemittanceStatus::Completed, RemittanceStatus::Pending)
+    )
+}
 
 /// Validates if a state transition is allowed.
 /// Returns Ok(()) if valid, Err(ContractError::InvalidStatus) if invalid.
-pub fn validate_transition(
-    from: &RemittanceStatus,
-    to: &RemittanceStatus,
-) -> Result<(), ContractError> {
-    match (from, to) {
-        // From Pending
-        (RemittanceStatus::Pending, RemittanceStatus::Processing) => Ok(()),
-        (RemittanceStatus::Pending, RemittanceStatus::Cancelled) => Ok(()),
-        
-        // From Processing
-        (RemittanceStatus::Processing, RemittanceStatus::Completed) => Ok(()),
-        (RemittanceStatus::Processing, RemittanceStatus::Failed) => Ok(()),
-        
-        // Terminal states cannot transition
-        (RemittanceStatus::Completed, _) => Err(ContractError::InvalidStatus),
-        (RemittanceStatus::Cancelled, _) => Err(ContractError::InvalidStatus),
-        (RemittanceStatus::Failed, _) => Err(ContractError::InvalidStatus),
-        
-        // All other transitions are invalid
-        _ => Err(ContractError::InvalidStatus),
+pub fn validate_transition(from: &RemittanceStatus, to: &RemittanceStatus) -> Result<(), ContractError> {
+    if !is_valid_transition(from, to) {
+        return Err(ContractError::InvalidStatus);
     }
+    Ok(())
+}
+
+/// Moves a remittance to `to`, rejecting any edge `is_valid_transition` does not allow.
+///
+/// Every status change goes through here; callers still persist the remittance
+/// and record the transition afterwards.
+pub fn transition(remittance: &mut Remittance, to: RemittanceStatus) -> Result<(), ContractError> {
+    validate_transition(&remittance.status, &to)?;
+    remittance.status = to;
+    Ok(())
+}
+
+/// Moves a reversed settlement back to `Pending` through `transition`, keeping it
+/// settleable until at least `settle_by`.
+///
+/// A remittance whose expiry has passed, or falls before `settle_by`, gets
+/// `settle_by` as its new expiry; one without an expiry keeps none.
+pub fn reopen_settlement(remittance: &mut Remittance, settle_by: u64) -> Result<(), ContractError> {
+    transition(remittance, RemittanceStatus::Pending)?;
+    if let Some(expiry) = remittance.expiry {
+        if expiry < settle_by {
+            remittance.expiry = Some(settle_by);
+        }
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_valid_transitions() {
-        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Processing).is_ok());
-        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Cancelled).is_ok());
-        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Completed).is_ok());
-        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Failed).is_ok());
-    }
+    const ALL: [RemittanceStatus; 7] = [
+        RemittanceStatus::Pending,
+        RemittanceStatus::PartiallySettled,
+        RemittanceStatus::Completed,
+        RemittanceStatus::Cancelled,
+        RemittanceStatus::Refunded,
+        RemittanceStatus::Expired,
+        RemittanceStatus::Reclaimed,
+    ];
 
-    #[test]
-    fn test_invalid_transitions_from_pending() {
-        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Completed).is_err());
-        assert!(validate_transition(&RemittanceStatus::Pending, &RemittanceStatus::Failed).is_err());
-    }
+    const LEGAL: [(RemittanceStatus, RemittanceStatus); 11] = [
+        (RemittanceStatus::Pending, RemittanceStatus::PartiallySettled),
+        (RemittanceStatus::Pending, RemittanceStatus::Completed),
+        (RemittanceStatus::Pending, RemittanceStatus::Cancelled),
+        (RemittanceStatus::Pending, RemittanceStatus::Expired),
+        (RemittanceStatus::Pending, RemittanceStatus::Refunded),
+        (RemittanceStatus::Pending, RemittanceStatus::Reclaimed),
+        (RemittanceStatus::PartiallySettled, RemittanceStatus::PartiallySettled),
+        (RemittanceStatus::PartiallySettled, RemittanceStatus::Completed),
+        (RemittanceStatus::Expired, RemittanceStatus::Refunded),
+        (RemittanceStatus::Expired, RemittanceStatus::Reclaimed),
+        (RemittanceStatus::Completed, RemittanceStatus::Pending),
+    ];
 
     #[test]
-    fn test_invalid_transitions_from_processing() {
-        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Pending).is_err());
-        assert!(validate_transition(&RemittanceStatus::Processing, &RemittanceStatus::Cancelled).is_err());
+    fn test_every_status_pair() {
+        for from in ALL.iter() {
+            for to in ALL.iter() {
+                let legal = LEGAL.iter().any(|(f, t)| f == from && t == to);
+                let expected = if legal { Ok(()) } else { Err(ContractError::InvalidStatus) };
+                assert_eq!(validate_transition(from, to), expected, "{:?} -> {:?}", from, to);
+            }
+        }
     }
 
     #[test]
     fn test_terminal_states_cannot_transition() {
-        assert!(validate_transition(&RemittanceStatus::Completed, &RemittanceStatus::Pending).is_err());
-        assert!(validate_transition(&RemittanceStatus::Completed, &RemittanceStatus::Processing).is_err());
-        assert!(validate_transition(&RemittanceStatus::Cancelled, &RemittanceStatus::Pending).is_err());
-        assert!(validate_transition(&RemittanceStatus::Failed, &RemittanceStatus::Processing).is_err());
+        for from in [RemittanceStatus::Cancelled, RemittanceStatus::Refunded, RemittanceStatus::Reclaimed].iter() {
+            assert!(ALL.iter().all(|to| !is_valid_transition(from, to)));
+        }
     }
 }
@@ -3,7 +3,7 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address, Vec, String};
+use soroban_sdk::{contracttype, Address, Bytes, Vec, String};
 
 /// Role types for authorization
 #[contracttype]
@@ -11,6 +11,9 @@ use soroban_sdk::{contracttype, Address, Vec, String};
 pub enum Role {
     Admin,
     Settler,
+    /// Day-to-day operator: may pause, set limits and manage the token whitelist,
+    /// but cannot change the admin set or withdraw treasury funds.
+    Operator,
 }
 
 /// Transfer state for on-chain registry
@@ -48,6 +51,7 @@ impl TransferState {
 ///
 /// Remittances progress through these states:
 /// - `Pending`: Initial state after creation, awaiting agent confirmation
+/// - `PartiallySettled`: Agent has disbursed some, but not all, of the remittance
 /// - `Completed`: Agent has confirmed payout and received funds
 /// - `Cancelled`: Sender has cancelled and received refund
 #[contracttype]
@@ -55,10 +59,18 @@ impl TransferState {
 pub enum RemittanceStatus {
     /// Remittance is awaiting agent confirmation
     Pending,
+    /// Remittance has been paid out in one or more tranches with a balance remaining
+    PartiallySettled,
     /// Remittance has been paid out to the agent
     Completed,
     /// Remittance has been cancelled and refunded to sender
     Cancelled,
+    /// Remittance expired unsettled and its escrow was returned to sender
+    Refunded,
+    /// Remittance passed its expiry unsettled and was swept; escrow is still refundable
+    Expired,
+    /// Remittance was abandoned past its reclaim grace period and its escrow reclaimed by an admin
+    Reclaimed,
 }
 
 /// Escrow status for locked funds
@@ -92,16 +104,239 @@ pub struct Remittance {
     pub id: u64,
     /// Address of the sender who initiated the remittance
     pub sender: Address,
-    /// Address of the agent who will receive the payout
+    /// Address of the agent who settles the remittance and earns the agent fee
     pub agent: Address,
-    /// Total amount sent by the sender (in USDC)
+    /// Payout address recorded at creation, kept for audit after any update
+    pub original_recipient: Address,
+    /// Payout address the remittance currently settles to
+    pub recipient: Address,
+    /// Agent explicitly assigned to settle, taking over from `agent` while set
+    pub assigned_agent: Option<Address>,
+    /// Token contract the remittance is escrowed and paid out in
+    pub token: Address,
+    /// Total amount sent by the sender (in `token` units)
     pub amount: i128,
-    /// Platform fee deducted from the amount (in USDC)
+    /// Platform fee deducted from the amount (in `token` units)
     pub fee: i128,
+    /// Settlement fee reserved on top of the amount for the contract treasury (in `token` units)
+    pub treasury_fee: i128,
+    /// Settlement fee reserved on top of the amount for the settling agent (in `token` units)
+    pub agent_fee: i128,
+    /// Portion of the amount not yet paid out to the agent (in `token` units)
+    pub remaining: i128,
     /// Current status of the remittance
     pub status: RemittanceStatus,
     /// Optional expiry timestamp (seconds since epoch) for settlement
     pub expiry: Option<u64>,
+    /// Optional timestamp (seconds since epoch) before which the remittance cannot be settled
+    pub not_before: Option<u64>,
+    /// Compliance hold blocking settlement, refund and cancellation, independent of status
+    pub on_hold: bool,
+    /// Ledger timestamp (seconds since epoch) at which the remittance was created
+    pub created_at: u64,
+    /// Optional sender-supplied payment reference, fixed at creation
+    pub memo: Option<Bytes>,
+    /// How the remittance is paid out, fixed at creation
+    pub mode: RemittanceMode,
+}
+
+impl Remittance {
+    /// Address allowed to settle: the recipient in claim mode, otherwise the
+    /// assigned agent if any, falling back to the creation agent
+    pub fn settling_agent(&self) -> Address {
+        match self.mode {
+            RemittanceMode::Claim => self.recipient.clone(),
+            RemittanceMode::Agent | RemittanceMode::Split => {
+                self.assigned_agent.clone().unwrap_or_else(|| self.agent.clone())
+            }
+        }
+    }
+}
+
+/// Payout model of a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RemittanceMode {
+    /// A registered agent settles and pushes funds to the recipient
+    Agent,
+    /// No agent; the recipient pulls funds with `claim_remittance`
+    Claim,
+    /// A registered agent settles with `settle_split`, paying several recipients at once
+    Split,
+}
+
+/// Rounding applied when a basis-point fee does not divide evenly.
+///
+/// Recipients receive `gross - fee`, so the mode decides who keeps the fractional stroop.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeRounding {
+    /// Truncate the fee; the recipient keeps the fraction
+    Down,
+    /// Round the fee up; the contract keeps the fraction
+    Up,
+    /// Round to the nearest stroop, with halves rounded up
+    Nearest,
+}
+
+/// Sensitive admin operation that must wait out the timelock delay before it runs.
+///
+/// Each variant carries the parameters of the entrypoint it stands in for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// `update_fee(fee_bps)`
+    UpdateFee(u32),
+    /// `withdraw_treasury(token, to, amount)`
+    WithdrawTreasury(Address, Address, i128),
+    /// `remove_admin(caller, admin)`
+    RemoveAdmin(Address),
+    /// `recover_stray_tokens(caller, token, to, amount)`
+    RecoverStrayTokens(Address, Address, i128),
+}
+
+/// Admin action waiting in the timelock queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    /// Unique ID assigned when the action was queued
+    pub id: u64,
+    /// Operation to run once unlocked
+    pub action: AdminAction,
+    /// Admin who queued the action
+    pub queued_by: Address,
+    /// Timestamp from which the action can be executed
+    pub unlock_at: u64,
+}
+
+/// Snapshot of the contract's runtime parameters, returned by `get_config`.
+///
+/// Values that have never been set are reported as their effective defaults.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Whether the contract is paused
+    pub paused: bool,
+    /// Platform fee in basis points
+    pub platform_fee_bps: u32,
+    /// Protocol fee in basis points
+    pub protocol_fee_bps: u32,
+    /// Treasury share of the settlement fee in basis points
+    pub treasury_bps: u32,
+    /// Agent share of the settlement fee in basis points
+    pub agent_bps: u32,
+    /// Smallest principal a single remittance may carry
+    pub min_remittance_amount: i128,
+    /// Largest principal a single remittance may carry
+    pub max_remittance_amount: i128,
+    /// Daily send limit for tokens without an explicit limit
+    pub default_daily_limit: i128,
+    /// Minimum seconds between settlements for the same sender
+    pub rate_limit_cooldown: u64,
+    /// Expiry window in seconds applied to remittances without an explicit expiry (0 if none)
+    pub default_expiry: u64,
+    /// Seconds a queued sensitive admin action must wait before it can run (0 if disabled)
+    pub timelock_delay: u64,
+    /// Seconds after expiry before an admin may reclaim an abandoned remittance
+    pub reclaim_grace_period: u64,
+    /// Seconds past expiry during which the agent may still settle
+    pub settlement_grace: u64,
+    /// Seconds after settlement during which an admin may reverse it (0 if disabled)
+    pub settlement_reversal_window: u64,
+    /// Whether new remittances must use a whitelisted token
+    pub require_token_whitelist: bool,
+    /// Rounding applied to basis-point fees
+    pub fee_rounding: FeeRounding,
+}
+
+/// Settings `initialize` can apply atomically on a fresh deploy.
+///
+/// Platform fee and protocol fee remain arguments of the initializer; everything
+/// here would otherwise need its own admin call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitConfig {
+    /// Treasury share of the settlement fee in basis points
+    pub treasury_bps: u32,
+    /// Agent share of the settlement fee in basis points
+    pub agent_bps: u32,
+    /// Smallest principal a single remittance may carry
+    pub min_remittance_amount: i128,
+    /// Largest principal a single remittance may carry
+    pub max_remittance_amount: i128,
+    /// Daily send limit for tokens without an explicit limit
+    pub default_daily_limit: i128,
+    /// Minimum seconds between settlements for the same sender
+    pub rate_limit_cooldown: u64,
+    /// Expiry window in seconds applied to remittances without an explicit expiry (0 for none)
+    pub default_expiry: u64,
+    /// Tokens to whitelist, which may include the initial token itself
+    pub whitelisted_tokens: Vec<Address>,
+}
+
+/// Admin proposal awaiting acceptance by the proposed address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    /// Address proposed to join the admin set
+    pub address: Address,
+    /// Admin who made the proposal
+    pub proposed_by: Address,
+    /// Ledger timestamp after which the proposal can no longer be accepted
+    pub expires_at: u64,
+}
+
+/// Operational status of a registered agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgentStatus {
+    /// Agent may settle remittances
+    Active,
+    /// Agent is temporarily barred from settling remittances
+    Suspended,
+}
+
+/// Settlement track record of an agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentStats {
+    /// Number of remittances the agent has fully settled
+    pub settlement_count: u64,
+    /// Total principal the agent has paid out across all tokens
+    pub total_volume: i128,
+}
+
+/// Profile of an agent, as returned by `get_agent`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Agent {
+    /// Agent address
+    pub address: Address,
+    /// Whether the agent is currently registered (false once removed)
+    pub registered: bool,
+    /// Ledger timestamp of the agent's most recent registration (0 if registered before this was tracked)
+    pub registered_at: u64,
+    /// Operational status
+    pub status: AgentStatus,
+    /// Settlement track record
+    pub stats: AgentStats,
+    /// Optional admin-set label, such as a corridor or display name
+    pub label: Option<Bytes>,
+    /// Address the agent's settlement funds are paid to (the agent itself unless set)
+    pub payout: Address,
+}
+
+/// Settlement fee configuration.
+///
+/// The settlement fee is charged to the sender on top of the principal at creation
+/// and split between the contract treasury and the settling agent at settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Share retained by the contract treasury, in basis points
+    pub treasury_bps: u32,
+    /// Share paid to the settling agent, in basis points
+    pub agent_bps: u32,
 }
 
 /// Entry for batch settlement processing.
@@ -113,6 +348,10 @@ pub struct BatchSettlementEntry {
     pub remittance_id: u64,
 }
 
+/// Outcome of settling one remittance in `settle_batch`: the remittance ID and
+/// either `Ok(())` or the error that prevented its settlement.
+pub type SettlementOutcome = (u64, Result<(), crate::ContractError>);
+
 /// Result of a batch settlement operation.
 /// Contains the IDs of successfully settled remittances.
 #[contracttype]
@@ -122,6 +361,38 @@ pub struct BatchSettlementResult {
     pub settled_ids: Vec<u64>,
 }
 
+/// Payout of a completed settlement that an admin may still reverse.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReversibleSettlement {
+    /// Address that received the recipient's share
+    pub payee: Address,
+    /// Recipient's share after fees, clawed back on reversal (in `token` units)
+    pub amount: i128,
+    /// Ledger timestamp of the settlement
+    pub settled_at: u64,
+}
+
+/// Record of the amounts moved by a single settlement call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementReceipt {
+    /// ID of the settled remittance
+    pub remittance_id: u64,
+    /// Principal settled by this call
+    pub gross: i128,
+    /// Platform and protocol fees taken from the principal
+    pub fee: i128,
+    /// Principal delivered after fees (`gross - fee`)
+    pub net: i128,
+    /// Settlement fee reserved at creation and released by this call (agent and treasury shares)
+    pub settlement_fee: i128,
+    /// Agent that settled
+    pub agent: Address,
+    /// Ledger timestamp of the settlement
+    pub settled_at: u64,
+}
+
 /// Result of a settlement simulation.
 /// Predicts the outcome without executing state changes.
 #[contracttype]
@@ -3,9 +3,10 @@
 //! This module provides validation functions for Stellar addresses used in
 //! contract operations.
 
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
-use crate::{ContractError, is_agent_registered, is_paused, get_remittance, RemittanceStatus};
+use crate::math::checked_add_i128;
+use crate::{ContractError, is_agent_registered, is_paused, get_remittance, RemittanceMode, RemittanceStatus};
 
 /// Centralized validation module for all API requests.
 /// Validates required fields before controller logic to prevent invalid data
@@ -42,6 +43,32 @@ pub fn validate_address(_address: &Address) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates every address in a batch, rejecting the contract's own address
+/// with `InvalidAddress`.
+pub fn validate_batch_addresses(env: &Env, addresses: &Vec<Address>) -> Result<(), ContractError> {
+    let contract = env.current_contract_address();
+    for address in addresses.iter() {
+        validate_address(&address)?;
+        if address == contract {
+            return Err(ContractError::InvalidAddress);
+        }
+    }
+    Ok(())
+}
+
+/// Rejects a batch that lists the same address more than once with `InvalidAddress`.
+pub fn validate_no_duplicate_addresses(addresses: &Vec<Address>) -> Result<(), ContractError> {
+    for i in 0..addresses.len() {
+        let address = addresses.get_unchecked(i);
+        for j in (i + 1)..addresses.len() {
+            if addresses.get_unchecked(j) == address {
+                return Err(ContractError::InvalidAddress);
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Validates fee basis points are within acceptable range (0-10000 = 0%-100%).
 pub fn validate_fee_bps(fee_bps: u32) -> Result<(), ContractError> {
     if fee_bps > 10000 {
@@ -50,6 +77,42 @@ pub fn validate_fee_bps(fee_bps: u32) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates that the settlement fee shares sum to at most 10000 basis points (100%).
+pub fn validate_fee_config(treasury_bps: u32, agent_bps: u32) -> Result<(), ContractError> {
+    let total = treasury_bps
+        .checked_add(agent_bps)
+        .ok_or(ContractError::InvalidAmount)?;
+    if total > 10000 {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Largest input accepted by any entrypoint taking a list, keeping a single
+/// call within Soroban's instruction and read budget
+pub const MAX_BATCH_SIZE: u32 = 100;
+
+/// Rejects a batch longer than `MAX_BATCH_SIZE` with `BatchTooLarge`.
+///
+/// Callers run this before reading or writing any state for the batch.
+pub fn ensure_batch_size(len: u32) -> Result<(), ContractError> {
+    if len > MAX_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge);
+    }
+    Ok(())
+}
+
+/// Maximum settlement cooldown in seconds (one day)
+pub const MAX_RATE_LIMIT_COOLDOWN: u64 = 86_400;
+
+/// Validates that a settlement cooldown does not exceed the allowed maximum.
+pub fn validate_rate_limit_cooldown(cooldown_seconds: u64) -> Result<(), ContractError> {
+    if cooldown_seconds > MAX_RATE_LIMIT_COOLDOWN {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
 /// Validates that an amount is positive and non-zero.
 pub fn validate_amount(amount: i128) -> Result<(), ContractError> {
     if amount <= 0 {
@@ -58,6 +121,74 @@ pub fn validate_amount(amount: i128) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates that an amount is positive and within the configured per-remittance floor and ceiling.
+pub fn validate_remittance_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    if amount < crate::get_min_remittance_amount(env) || amount > crate::get_max_remittance_amount(env) {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Validates that a per-remittance floor and ceiling do not conflict.
+pub fn validate_remittance_amount_bounds(min: i128, max: i128) -> Result<(), ContractError> {
+    if min < 0 || min > max {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Resolves the absolute expiry for a new remittance.
+///
+/// An explicit override must lie in the future. Without one, the configured
+/// default window is applied from now, or no expiry if no default is set.
+pub fn resolve_remittance_expiry(env: &Env, expiry_override: Option<u64>) -> Result<Option<u64>, ContractError> {
+    let now = env.ledger().timestamp();
+    match expiry_override {
+        Some(expiry) if expiry <= now => Err(ContractError::SettlementExpired),
+        Some(expiry) => Ok(Some(expiry)),
+        None => match crate::get_default_expiry(env) {
+            0 => Ok(None),
+            window => now.checked_add(window).map(Some).ok_or(ContractError::Overflow),
+        },
+    }
+}
+
+/// Maximum length in bytes of a remittance memo
+pub const MAX_MEMO_LEN: u32 = 64;
+
+/// Validates that an optional remittance memo fits within `MAX_MEMO_LEN`.
+pub fn validate_memo(memo: &Option<soroban_sdk::Bytes>) -> Result<(), ContractError> {
+    if let Some(memo) = memo {
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(ContractError::MemoTooLong);
+        }
+    }
+    Ok(())
+}
+
+/// Maximum length in bytes of an agent label
+pub const MAX_AGENT_LABEL_LEN: u32 = 64;
+
+/// Validates that an optional agent label fits within `MAX_AGENT_LABEL_LEN`,
+/// rejecting a longer one with `InvalidAmount`.
+pub fn validate_agent_label(label: &Option<soroban_sdk::Bytes>) -> Result<(), ContractError> {
+    if let Some(label) = label {
+        if label.len() > MAX_AGENT_LABEL_LEN {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+    Ok(())
+}
+
+/// Validates that no compliance hold has been placed on a remittance.
+pub fn validate_not_on_hold(remittance: &crate::Remittance) -> Result<(), ContractError> {
+    if remittance.on_hold {
+        return Err(ContractError::RemittanceOnHold);
+    }
+    Ok(())
+}
+
 /// Validates that an agent is registered in the system.
 pub fn validate_agent_registered(env: &Env, agent: &Address) -> Result<(), ContractError> {
     if !is_agent_registered(env, agent) {
@@ -66,6 +197,49 @@ pub fn validate_agent_registered(env: &Env, agent: &Address) -> Result<(), Contr
     Ok(())
 }
 
+/// Validates that an agent is not suspended.
+pub fn validate_agent_not_suspended(env: &Env, agent: &Address) -> Result<(), ContractError> {
+    if crate::get_agent_status(env, agent) == crate::AgentStatus::Suspended {
+        return Err(ContractError::AgentSuspended);
+    }
+    Ok(())
+}
+
+/// Validates that a token is whitelisted for remittances.
+pub fn validate_token_whitelisted(env: &Env, token: &Address) -> Result<(), ContractError> {
+    if !crate::is_token_whitelisted(env, token) {
+        return Err(ContractError::TokenNotWhitelisted);
+    }
+    Ok(())
+}
+
+/// Validates that a token may be used for a new remittance.
+///
+/// Same as `validate_token_whitelisted`, except that any token is accepted while
+/// the whitelist requirement is switched off with `set_require_token_whitelist`.
+pub fn validate_token_accepted(env: &Env, token: &Address) -> Result<(), ContractError> {
+    if !crate::is_token_whitelist_required(env) {
+        return Ok(());
+    }
+    validate_token_whitelisted(env, token)
+}
+
+/// Validates that a sender with an active recipient allowlist has approved the recipient.
+pub fn validate_recipient_allowed(env: &Env, sender: &Address, recipient: &Address) -> Result<(), ContractError> {
+    if crate::is_recipient_allowlist_enabled(env, sender) && !crate::is_recipient_allowed(env, sender, recipient) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Validates that a token matches the recipient's preferred token, if they set one.
+pub fn validate_preferred_token(env: &Env, recipient: &Address, token: &Address) -> Result<(), ContractError> {
+    match crate::get_preferred_token(env, recipient) {
+        Some(preferred) if preferred != *token => Err(ContractError::TokenMismatch),
+        _ => Ok(()),
+    }
+}
+
 /// Validates that the contract is not paused.
 pub fn validate_not_paused(env: &Env) -> Result<(), ContractError> {
     if is_paused(env) {
@@ -87,6 +261,31 @@ pub fn validate_remittance_pending(remittance: &crate::Remittance) -> Result<(),
     Ok(())
 }
 
+/// Validates that a remittance is paid out through the given mode.
+///
+/// Agent-mode remittances cannot be claimed and claim-mode remittances cannot be
+/// settled by an agent; either cross-mode attempt fails with `InvalidStatus`.
+pub fn validate_remittance_mode(remittance: &crate::Remittance, mode: RemittanceMode) -> Result<(), ContractError> {
+    if remittance.mode != mode {
+        return Err(ContractError::InvalidStatus);
+    }
+    Ok(())
+}
+
+/// Validates that a remittance can still be paid out (pending or partially settled).
+pub fn validate_remittance_settleable(remittance: &crate::Remittance) -> Result<(), ContractError> {
+    crate::validate_transition(&remittance.status, &RemittanceStatus::Completed)
+}
+
+/// Validates that a tranche is positive and does not exceed the remaining balance.
+pub fn validate_tranche_amount(remittance: &crate::Remittance, amount: i128) -> Result<(), ContractError> {
+    validate_amount(amount)?;
+    if amount > remittance.remaining {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
 /// Validates that a settlement has not expired.
 pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result<(), ContractError> {
     if let Some(expiry_time) = expiry {
@@ -98,6 +297,35 @@ pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result
     Ok(())
 }
 
+/// Validates that an agent settlement falls within the expiry plus the grace window.
+///
+/// Absorbs settlements that land a few seconds late; refunds and cancellation
+/// still use the strict expiry checked by `validate_settlement_not_expired`.
+pub fn validate_agent_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result<(), ContractError> {
+    let grace = crate::get_settlement_grace(env);
+    validate_settlement_not_expired(env, expiry.map(|expiry| expiry.saturating_add(grace)))
+}
+
+/// Validates that a scheduled remittance has reached its release time.
+pub fn validate_settlement_released(env: &Env, not_before: Option<u64>) -> Result<(), ContractError> {
+    if let Some(release_time) = not_before {
+        if env.ledger().timestamp() < release_time {
+            return Err(ContractError::NotYetSettleable);
+        }
+    }
+    Ok(())
+}
+
+/// Validates that a release time falls before the expiry it is paired with.
+pub fn validate_not_before(not_before: Option<u64>, expiry: Option<u64>) -> Result<(), ContractError> {
+    if let (Some(release_time), Some(expiry_time)) = (not_before, expiry) {
+        if release_time >= expiry_time {
+            return Err(ContractError::InvalidAmount);
+        }
+    }
+    Ok(())
+}
+
 /// Validates that a settlement has not been executed before (duplicate check).
 pub fn validate_no_duplicate_settlement(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
     if crate::has_settlement_hash(env, remittance_id) {
@@ -120,6 +348,7 @@ pub fn validate_initialize_request(
     admin: &Address,
     token: &Address,
     fee_bps: u32,
+    initial_tokens: &Vec<Address>,
 ) -> Result<(), ContractError> {
     validate_address(admin)?;
     validate_address(token)?;
@@ -130,28 +359,146 @@ pub fn validate_initialize_request(
         return Err(ContractError::AlreadyInitialized);
     }
     
-    // Check if token is whitelisted
-    if !crate::is_token_whitelisted(env, token) {
+    // Check if token is whitelisted, or about to be by the initial configuration
+    if !crate::is_token_whitelisted(env, token) && !initial_tokens.contains(token) {
         return Err(ContractError::TokenNotWhitelisted);
     }
     
     Ok(())
 }
 
+/// Validates an initial configuration bundle as a whole, so a bad field
+/// rejects initialization before any setting is written.
+pub fn validate_init_config(config: &crate::InitConfig) -> Result<(), ContractError> {
+    validate_fee_config(config.treasury_bps, config.agent_bps)?;
+    validate_amount(config.max_remittance_amount)?;
+    validate_remittance_amount_bounds(config.min_remittance_amount, config.max_remittance_amount)?;
+    validate_amount(config.default_daily_limit)?;
+    validate_rate_limit_cooldown(config.rate_limit_cooldown)?;
+    for token in config.whitelisted_tokens.iter() {
+        validate_address(&token)?;
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for create_remittance request.
 pub fn validate_create_remittance_request(
     env: &Env,
     sender: &Address,
     agent: &Address,
+    token: &Address,
     amount: i128,
 ) -> Result<(), ContractError> {
+    validate_not_paused(env)?;
     validate_address(sender)?;
     validate_address(agent)?;
-    validate_amount(amount)?;
+    validate_address(token)?;
+    validate_remittance_amount(env, amount)?;
     validate_agent_registered(env, agent)?;
+    validate_token_accepted(env, token)?;
+    // Agent-mode payouts go to the agent, so it is the recipient being allowlisted
+    validate_recipient_allowed(env, sender, agent)?;
+    validate_preferred_token(env, agent, token)?;
     Ok(())
 }
 
+/// Comprehensive validation for create_claimable_remittance request.
+///
+/// Same as `validate_create_remittance_request`, except there is no agent to check.
+pub fn validate_create_claimable_remittance_request(
+    env: &Env,
+    sender: &Address,
+    recipient: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    validate_not_paused(env)?;
+    validate_address(sender)?;
+    validate_address(recipient)?;
+    // Funds claimable only by the contract itself would be stranded in escrow
+    if *recipient == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    validate_address(token)?;
+    validate_remittance_amount(env, amount)?;
+    validate_token_accepted(env, token)?;
+    validate_recipient_allowed(env, sender, recipient)?;
+    validate_preferred_token(env, recipient, token)?;
+    Ok(())
+}
+
+/// Largest number of recipients a split remittance may pay.
+pub const MAX_SPLIT_RECIPIENTS: u32 = 10;
+
+/// Validates the recipients of a split remittance and returns their total.
+///
+/// There must be between one and `MAX_SPLIT_RECIPIENTS` recipients (more is
+/// `BatchTooLarge`), each with a positive amount, allowed by the sender's
+/// recipient allowlist and willing to accept `token`.
+pub fn validate_split_recipients(
+    env: &Env,
+    sender: &Address,
+    token: &Address,
+    recipients: &Vec<(Address, i128)>,
+) -> Result<i128, ContractError> {
+    if recipients.is_empty() {
+        return Err(ContractError::EmptyCollection);
+    }
+    if recipients.len() > MAX_SPLIT_RECIPIENTS {
+        return Err(ContractError::BatchTooLarge);
+    }
+
+    let mut total: i128 = 0;
+    for (recipient, amount) in recipients.iter() {
+        validate_address(&recipient)?;
+        // Funds sent to the contract itself would be stranded in escrow
+        if recipient == env.current_contract_address() {
+            return Err(ContractError::InvalidAddress);
+        }
+        validate_recipient_allowed(env, sender, &recipient)?;
+        validate_preferred_token(env, &recipient, token)?;
+        validate_amount(amount)?;
+        total = checked_add_i128(total, amount)?;
+    }
+    Ok(total)
+}
+
+/// Comprehensive validation for settle_split request.
+///
+/// Splits are paid out whole, so only `Pending` remittances can be settled.
+pub fn validate_settle_split_request(
+    env: &Env,
+    remittance_id: u64,
+) -> Result<crate::Remittance, ContractError> {
+    validate_not_paused(env)?;
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    validate_remittance_mode(&remittance, RemittanceMode::Split)?;
+    validate_remittance_pending(&remittance)?;
+    validate_no_duplicate_settlement(env, remittance_id)?;
+    validate_agent_settlement_not_expired(env, remittance.expiry)?;
+    validate_settlement_released(env, remittance.not_before)?;
+    validate_address(&remittance.settling_agent())?;
+    validate_agent_not_suspended(env, &remittance.settling_agent())?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for claim_remittance request.
+pub fn validate_claim_remittance_request(
+    env: &Env,
+    remittance_id: u64,
+) -> Result<crate::Remittance, ContractError> {
+    validate_not_paused(env)?;
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    validate_remittance_mode(&remittance, RemittanceMode::Claim)?;
+    validate_remittance_pending(&remittance)?;
+    validate_no_duplicate_settlement(env, remittance_id)?;
+    validate_settlement_not_expired(env, remittance.expiry)?;
+    validate_settlement_released(env, remittance.not_before)?;
+    Ok(remittance)
+}
+
 /// Comprehensive validation for confirm_payout request.
 pub fn validate_confirm_payout_request(
     env: &Env,
@@ -159,10 +506,49 @@ pub fn validate_confirm_payout_request(
 ) -> Result<crate::Remittance, ContractError> {
     validate_not_paused(env)?;
     let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    validate_remittance_mode(&remittance, RemittanceMode::Agent)?;
+    validate_remittance_settleable(&remittance)?;
+    validate_no_duplicate_settlement(env, remittance_id)?;
+    validate_agent_settlement_not_expired(env, remittance.expiry)?;
+    validate_settlement_released(env, remittance.not_before)?;
+    validate_address(&remittance.settling_agent())?;
+    validate_agent_not_suspended(env, &remittance.settling_agent())?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for force_settle request.
+///
+/// Unlike `validate_confirm_payout_request`, ignores the pause flag and the
+/// agent's status, since the override exists for when the agent cannot act.
+pub fn validate_force_settle_request(
+    env: &Env,
+    remittance_id: u64,
+    to: &Address,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    validate_remittance_mode(&remittance, RemittanceMode::Agent)?;
     validate_remittance_pending(&remittance)?;
     validate_no_duplicate_settlement(env, remittance_id)?;
     validate_settlement_not_expired(env, remittance.expiry)?;
-    validate_address(&remittance.agent)?;
+    validate_settlement_released(env, remittance.not_before)?;
+    validate_address(to)?;
+    // Funds sent to the contract itself would be stranded in escrow
+    if *to == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(remittance)
+}
+
+/// Comprehensive validation for settle_partial request.
+pub fn validate_settle_partial_request(
+    env: &Env,
+    remittance_id: u64,
+    amount: i128,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_confirm_payout_request(env, remittance_id)?;
+    validate_tranche_amount(&remittance, amount)?;
     Ok(remittance)
 }
 
@@ -172,22 +558,176 @@ pub fn validate_cancel_remittance_request(
     remittance_id: u64,
 ) -> Result<crate::Remittance, ContractError> {
     let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
     validate_remittance_pending(&remittance)?;
     validate_address(&remittance.sender)?;
     Ok(remittance)
 }
 
+/// Comprehensive validation for assign_agent request.
+pub fn validate_assign_agent_request(
+    env: &Env,
+    remittance_id: u64,
+    agent: &Address,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_remittance_mode(&remittance, RemittanceMode::Agent)?;
+    validate_remittance_pending(&remittance)?;
+    validate_not_on_hold(&remittance)?;
+    validate_address(agent)?;
+    validate_agent_registered(env, agent)?;
+    validate_agent_not_suspended(env, agent)?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for top_up_remittance request.
+pub fn validate_top_up_remittance_request(
+    env: &Env,
+    remittance_id: u64,
+    additional_amount: i128,
+) -> Result<crate::Remittance, ContractError> {
+    validate_not_paused(env)?;
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_remittance_pending(&remittance)?;
+    validate_not_on_hold(&remittance)?;
+    // Split amounts are fixed per recipient at creation
+    if remittance.mode == RemittanceMode::Split {
+        return Err(ContractError::InvalidStatus);
+    }
+    // A remittance past its expiry is only waiting to be refunded
+    if validate_settlement_not_expired(env, remittance.expiry).is_err() {
+        return Err(ContractError::InvalidStatus);
+    }
+    validate_amount(additional_amount)?;
+    let new_amount = checked_add_i128(remittance.amount, additional_amount)?;
+    validate_remittance_amount(env, new_amount)?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for update_recipient request.
+pub fn validate_update_recipient_request(
+    env: &Env,
+    remittance_id: u64,
+    new_recipient: &Address,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_remittance_pending(&remittance)?;
+    validate_not_on_hold(&remittance)?;
+    // Split recipients are fixed at creation
+    if remittance.mode == RemittanceMode::Split {
+        return Err(ContractError::InvalidStatus);
+    }
+    validate_address(new_recipient)?;
+    // Funds sent to the contract itself would be stranded in escrow
+    if *new_recipient == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    validate_recipient_allowed(env, &remittance.sender, new_recipient)?;
+    validate_preferred_token(env, new_recipient, &remittance.token)?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for refund_remittance request.
+pub fn validate_refund_remittance_request(
+    env: &Env,
+    remittance_id: u64,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    if remittance.status == RemittanceStatus::Expired {
+        validate_address(&remittance.sender)?;
+        return Ok(remittance);
+    }
+    validate_remittance_pending(&remittance)?;
+    match remittance.expiry {
+        Some(expiry) if env.ledger().timestamp() > expiry => {}
+        _ => return Err(ContractError::RemittanceNotExpired),
+    }
+    validate_address(&remittance.sender)?;
+    Ok(remittance)
+}
+
+/// Comprehensive validation for admin_reclaim request.
+///
+/// The remittance must still hold its escrow (`Pending` or `Expired`) and the
+/// ledger must be strictly past its expiry plus the reclaim grace period;
+/// remittances without an expiry can never be reclaimed.
+pub fn validate_admin_reclaim_request(
+    env: &Env,
+    remittance_id: u64,
+    to: &Address,
+) -> Result<crate::Remittance, ContractError> {
+    let remittance = validate_remittance_exists(env, remittance_id)?;
+    validate_not_on_hold(&remittance)?;
+    crate::validate_transition(&remittance.status, &RemittanceStatus::Reclaimed)?;
+    let expiry = remittance.expiry.ok_or(ContractError::RemittanceNotExpired)?;
+    let reclaimable_after = expiry
+        .checked_add(crate::get_reclaim_grace_period(env))
+        .ok_or(ContractError::Overflow)?;
+    if env.ledger().timestamp() <= reclaimable_after {
+        return Err(ContractError::RemittanceNotExpired);
+    }
+    validate_address(to)?;
+    // Funds sent to the contract itself would be stranded in escrow
+    if *to == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(remittance)
+}
+
 /// Comprehensive validation for withdraw_fees request.
 pub fn validate_withdraw_fees_request(
     env: &Env,
+    token: &Address,
     to: &Address,
 ) -> Result<i128, ContractError> {
     validate_address(to)?;
-    let fees = crate::get_accumulated_fees(env)?;
+    let fees = crate::get_accumulated_fees(env, token);
     validate_fees_available(fees)?;
     Ok(fees)
 }
 
+/// Comprehensive validation for withdraw_treasury request.
+///
+/// Deliberately skips the whitelist check so balances accrued in a since
+/// de-listed token can still be withdrawn.
+pub fn validate_withdraw_treasury_request(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    validate_address(to)?;
+    validate_amount(amount)?;
+    let balance = crate::get_treasury_balance(env, token);
+    if amount > balance {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(balance)
+}
+
+/// Comprehensive validation for recover_stray_tokens request.
+///
+/// Only the surplus above escrowed funds, accrued platform fees and the
+/// treasury balance can be recovered; see `crate::get_recoverable_balance`.
+pub fn validate_recover_stray_tokens_request(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    validate_address(to)?;
+    // Recovering to the contract itself would just create more stray tokens
+    if *to == env.current_contract_address() {
+        return Err(ContractError::InvalidAddress);
+    }
+    validate_amount(amount)?;
+    if amount > crate::get_recoverable_balance(env, token)? {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
 /// Comprehensive validation for update_fee request.
 pub fn validate_update_fee_request(fee_bps: u32) -> Result<(), ContractError> {
     validate_fee_bps(fee_bps)
@@ -205,6 +745,20 @@ pub fn validate_admin_operation(
     Ok(())
 }
 
+/// Comprehensive validation for operator-tier operations.
+///
+/// Admins pass as well, since every admin can do anything an operator can.
+pub fn validate_operator_operation(
+    env: &Env,
+    caller: &Address,
+    target: &Address,
+) -> Result<(), ContractError> {
+    validate_address(caller)?;
+    validate_address(target)?;
+    crate::require_operator(env, caller)?;
+    Ok(())
+}
+
 /// Normalizes an asset symbol to uppercase canonical form.
 ///
 /// # Arguments
@@ -248,6 +802,24 @@ mod tests {
         assert_eq!(validate_fee_bps(50000), Err(ContractError::InvalidFeeBps));
     }
 
+    #[test]
+    fn test_validate_fee_config() {
+        assert!(validate_fee_config(0, 0).is_ok());
+        assert!(validate_fee_config(6000, 4000).is_ok());
+        assert_eq!(validate_fee_config(6000, 4001), Err(ContractError::InvalidAmount));
+        assert_eq!(validate_fee_config(u32::MAX, 1), Err(ContractError::InvalidAmount));
+    }
+
+    #[test]
+    fn test_validate_rate_limit_cooldown() {
+        assert!(validate_rate_limit_cooldown(0).is_ok());
+        assert!(validate_rate_limit_cooldown(MAX_RATE_LIMIT_COOLDOWN).is_ok());
+        assert_eq!(
+            validate_rate_limit_cooldown(MAX_RATE_LIMIT_COOLDOWN + 1),
+            Err(ContractError::InvalidAmount)
+        );
+    }
+
     #[test]
     fn test_validate_amount_valid() {
         assert!(validate_amount(1).is_ok());